@@ -1,8 +1,11 @@
 use std::{fmt::Debug, ops::RangeInclusive, sync::Arc};
 
-use egui::{Color32, emath::{remap_clamp, round_to_decimals, Rot2}, epaint::TextShape, Pos2, Rangef, Rect, Response, Sense, TextStyle, Ui, Vec2, WidgetText, RichText};
+use egui::{Color32, Id, emath::{remap_clamp, round_to_decimals, Rot2}, epaint::TextShape, Pos2, Rangef, Rect, Response, Sense, TextStyle, Ui, Vec2, WidgetText, RichText};
 
-use super::{transform::PlotTransform, GridMark};
+use super::{
+    transform::{AxisScale, PlotTransform},
+    GridMark, GridSpacer,
+};
 
 pub(super) type AxisFormatterFn = dyn Fn(GridMark, usize, &RangeInclusive<f64>) -> String;
 
@@ -90,6 +93,118 @@ impl From<Placement> for VPlacement {
     }
 }
 
+/// How a Y axis shows values, e.g. to compare instruments with different prices.
+///
+/// The values are relative to a reference value: the leftmost visible data point of the items on
+/// the axis, so the labels update as the user pans. Items keep using plain values.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum YScaleMode {
+    /// Plain values.
+    #[default]
+    Normal,
+
+    /// Change in percent relative to the reference value, e.g. `12.5%`.
+    Percent,
+
+    /// Values rebased so that the reference value is 100.
+    IndexedTo100,
+}
+
+/// A [`YScaleMode`] with its reference value, mapping plain values to the shown ones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct YRebase {
+    mode: YScaleMode,
+    scale: f64,
+    offset: f64,
+}
+
+impl YRebase {
+    /// `None` for [`YScaleMode::Normal`] and for a reference value that isn't positive.
+    pub(crate) fn new(mode: YScaleMode, reference: f64) -> Option<Self> {
+        if !(reference.is_finite() && reference > 0.0) {
+            return None;
+        }
+        let scale = 100.0 / reference;
+        match mode {
+            YScaleMode::Normal => None,
+            YScaleMode::Percent => Some(Self {
+                mode,
+                scale,
+                offset: -100.0,
+            }),
+            YScaleMode::IndexedTo100 => Some(Self {
+                mode,
+                scale,
+                offset: 0.0,
+            }),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn rebase(&self, value: f64) -> f64 {
+        self.scale * value + self.offset
+    }
+
+    #[inline]
+    fn unrebase(&self, value: f64) -> f64 {
+        (value - self.offset) / self.scale
+    }
+
+    /// The shown value for hover labels.
+    pub(crate) fn format(&self, value: f64) -> String {
+        let value = self.rebase(value);
+        match self.mode {
+            YScaleMode::Percent => format!("{value:+.2}%"),
+            YScaleMode::Normal | YScaleMode::IndexedTo100 => format!("{value:.2}"),
+        }
+    }
+
+    /// Run `fmt` on the shown values.
+    #[allow(clippy::arc_with_non_send_sync)] // `AxisFormatterFn` is not `Send` either
+    pub(crate) fn wrap_axis_formatter(&self, fmt: Arc<AxisFormatterFn>) -> Arc<AxisFormatterFn> {
+        let rebase = *self;
+        Arc::new(move |mark, max_digits, range| {
+            let mark = GridMark {
+                value: rebase.rebase(mark.value),
+                step_size: mark.step_size * rebase.scale,
+            };
+            let range = rebase.rebase(*range.start())..=rebase.rebase(*range.end());
+            let text = fmt(mark, max_digits, &range);
+            match rebase.mode {
+                YScaleMode::Percent if !text.is_empty() => format!("{text}%"),
+                _ => text,
+            }
+        })
+    }
+
+    /// The Y grid marks of `transform`, placed at round shown values.
+    ///
+    /// Falls back to the plain marks for a non-linear [`crate::AxisScale`].
+    pub(crate) fn grid_marks(
+        &self,
+        transform: &PlotTransform,
+        spacer: &GridSpacer,
+        min_spacing: f32,
+    ) -> Vec<GridMark> {
+        if !transform.scale(Axis::Y).is_linear() {
+            return transform.grid_marks(Axis::Y, spacer, min_spacing);
+        }
+        let mut bounds = *transform.bounds();
+        bounds.min[1] = self.rebase(bounds.min[1]);
+        bounds.max[1] = self.rebase(bounds.max[1]);
+        let mut rebased = *transform;
+        rebased.set_bounds(bounds);
+        rebased
+            .grid_marks(Axis::Y, spacer, min_spacing)
+            .into_iter()
+            .map(|mark| GridMark {
+                value: self.unrebase(mark.value),
+                step_size: mark.step_size / self.scale,
+            })
+            .collect()
+    }
+}
+
 /// Axis configuration.
 ///
 /// Used to configure axis label and ticks.
@@ -100,6 +215,9 @@ pub struct AxisHints {
     pub(super) digits: usize,
     pub(super) placement: Placement,
     pub(super) label_spacing: Rangef,
+    pub(super) id: Option<Id>,
+    pub(super) scale_mode: YScaleMode,
+    pub(super) scale: Option<AxisScale>,
 }
 
 // TODO(JohannesProgrammiert): this just a guess. It might cease to work if a user changes font size.
@@ -131,6 +249,9 @@ impl AxisHints {
                 Axis::X => Rangef::new(60.0, 80.0), // labels can get pretty wide
                 Axis::Y => Rangef::new(20.0, 30.0), // text isn't very high
             },
+            id: None,
+            scale_mode: YScaleMode::Normal,
+            scale: None,
         }
     }
 
@@ -205,6 +326,38 @@ impl AxisHints {
         self
     }
 
+    /// Give this Y axis its own scale.
+    ///
+    /// Items bound to the same id (e.g. with [`crate::Line::y_axis`]) are drawn against the bounds
+    /// of this axis, which are auto-fitted, dragged and zoomed independently of the main Y axis.
+    /// Dragging the axis itself pans only this axis. The X range is always shared. Has no effect
+    /// on X axes.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Show the values of this Y axis relative to a reference value, see [`YScaleMode`].
+    ///
+    /// The formatter receives the shown values, e.g. percent changes. For the main Y axis, see
+    /// also [`crate::Plot::y_scale_mode`]. Has no effect on X axes.
+    #[inline]
+    pub fn scale_mode(mut self, mode: YScaleMode) -> Self {
+        self.scale_mode = mode;
+        self
+    }
+
+    /// Set the scale of this Y axis, e.g. [`AxisScale::log10`] for volumes next to linear prices.
+    ///
+    /// Only used together with [`Self::id`]. Default: the scale of the main Y axis, see
+    /// [`crate::Plot::y_axis_scale`].
+    #[inline]
+    pub fn scale(mut self, scale: AxisScale) -> Self {
+        self.scale = Some(scale.sanitized());
+        self
+    }
+
     pub(super) fn thickness(&self, axis: Axis) -> f32 {
         match axis {
             Axis::X => {
@@ -235,6 +388,9 @@ pub(super) struct AxisWidget {
     pub transform: Option<PlotTransform>,
     pub steps: Arc<Vec<GridMark>>,
 
+    /// Value of the crosshair to show in a badge, see [`crate::Plot::crosshair`].
+    pub crosshair: Option<f64>,
+
     // grom
     pub(super) highlights: Vec<(f64, Color32)>,
 }
@@ -248,6 +404,7 @@ impl AxisWidget {
             rect,
             transform: None,
             steps: Default::default(),
+            crosshair: None,
             // grom
             highlights: Vec::new(),
         }
@@ -418,6 +575,114 @@ impl AxisWidget {
             ui.painter().add(TextShape::new(pos, galley, text_color));
         }
 
+        if let Some(value) = self.crosshair {
+            let mark = crosshair_mark(value, &self.steps, transform.scale(axis));
+            let value = mark.value;
+            let text = (self.hints.formatter)(mark, self.hints.digits, &self.range);
+            let text = RichText::new(text)
+                .color(visuals.extreme_bg_color)
+                .background_color(visuals.strong_text_color());
+            let galley =
+                WidgetText::RichText(text).into_galley(ui, Some(false), f32::INFINITY, font_id);
+
+            let pos = match axis {
+                Axis::X => {
+                    let projected_point = super::PlotPoint::new(value, 0.0);
+                    let center_x = transform.position_from_point(&projected_point).x;
+                    let x = (center_x - galley.size().x / 2.0)
+                        .min(self.rect.max.x - galley.size().x)
+                        .max(self.rect.min.x);
+                    let y = match VPlacement::from(self.hints.placement) {
+                        VPlacement::Bottom => self.rect.min.y,
+                        VPlacement::Top => self.rect.max.y - galley.size().y,
+                    };
+                    Pos2::new(x, y)
+                }
+                Axis::Y => {
+                    let projected_point = super::PlotPoint::new(0.0, value);
+                    let center_y = transform.position_from_point(&projected_point).y;
+                    let x = match HPlacement::from(self.hints.placement) {
+                        HPlacement::Left => self.rect.max.x - galley.size().x,
+                        HPlacement::Right => self.rect.min.x,
+                    };
+                    Pos2::new(x, center_y - galley.size().y / 2.0)
+                }
+            };
+            ui.painter()
+                .add(TextShape::new(pos, galley, visuals.strong_text_color()));
+        }
+
         (response, thickness)
     }
 }
+
+/// The mark for a crosshair badge at `value`: rounded to the finest of the grid `steps`, so the
+/// badge shows no more precision than the grid.
+///
+/// On a non-linear axis the grid steps aren't even, so only the step size is passed on.
+fn crosshair_mark(value: f64, steps: &[GridMark], scale: AxisScale) -> GridMark {
+    let Some(finest) = steps
+        .iter()
+        .filter(|mark| mark.step_size > 0.0)
+        .min_by(|a, b| a.step_size.total_cmp(&b.step_size))
+    else {
+        return GridMark {
+            value,
+            step_size: 0.0,
+        };
+    };
+    let value = if scale.is_linear() {
+        // Relative to a mark, as rebased Y axes have marks at an offset.
+        let steps = ((value - finest.value) / finest.step_size).round();
+        finest.value + steps * finest.step_size
+    } else {
+        value
+    };
+    GridMark {
+        value,
+        step_size: finest.step_size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mark(value: f64, step_size: f64) -> GridMark {
+        GridMark { value, step_size }
+    }
+
+    #[test]
+    fn crosshair_mark_rounds_to_the_finest_step() {
+        let steps = [
+            mark(0.0, 1.0),
+            mark(1.0, 1.0),
+            mark(1.1, 0.1),
+            mark(1.2, 0.1),
+        ];
+        let rounded = crosshair_mark(1.234_567, &steps, AxisScale::Linear);
+        assert!((rounded.value - 1.2).abs() < 1e-9);
+        assert_eq!(rounded.step_size, 0.1);
+    }
+
+    #[test]
+    fn crosshair_mark_rounds_relative_to_the_marks() {
+        // E.g. a rebased axis with marks off the multiples of the step.
+        let steps = [mark(0.05, 0.25), mark(0.3, 0.25)];
+        let rounded = crosshair_mark(1.0, &steps, AxisScale::Linear);
+        assert!((rounded.value - 1.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn crosshair_mark_without_steps_keeps_the_value() {
+        let rounded = crosshair_mark(1.234_567, &[], AxisScale::Linear);
+        assert_eq!((rounded.value, rounded.step_size), (1.234_567, 0.0));
+    }
+
+    #[test]
+    fn crosshair_mark_on_a_log_axis_keeps_the_value() {
+        let steps = [mark(10.0, 1.0), mark(100.0, 1.0)];
+        let rounded = crosshair_mark(42.0, &steps, AxisScale::log(10.0));
+        assert_eq!((rounded.value, rounded.step_size), (42.0, 1.0));
+    }
+}
@@ -0,0 +1,316 @@
+//! Mapping values to colors, see [`crate::Heatmap`].
+
+use std::ops::RangeInclusive;
+
+use egui::{
+    emath::NumExt as _, epaint::Mesh, pos2, vec2, Align2, Color32, Rect, Response, Sense, Shape,
+    Stroke, TextStyle, Ui, Widget,
+};
+
+use crate::items::step_decimals;
+
+const VIRIDIS: [Color32; 9] = [
+    Color32::from_rgb(0x44, 0x01, 0x54),
+    Color32::from_rgb(0x47, 0x2c, 0x7a),
+    Color32::from_rgb(0x3b, 0x51, 0x8b),
+    Color32::from_rgb(0x2c, 0x71, 0x8e),
+    Color32::from_rgb(0x21, 0x90, 0x8d),
+    Color32::from_rgb(0x27, 0xad, 0x81),
+    Color32::from_rgb(0x5c, 0xc8, 0x63),
+    Color32::from_rgb(0xaa, 0xdc, 0x32),
+    Color32::from_rgb(0xfd, 0xe7, 0x25),
+];
+
+const MAGMA: [Color32; 9] = [
+    Color32::from_rgb(0x00, 0x00, 0x04),
+    Color32::from_rgb(0x1c, 0x10, 0x44),
+    Color32::from_rgb(0x4f, 0x12, 0x7b),
+    Color32::from_rgb(0x81, 0x25, 0x81),
+    Color32::from_rgb(0xb5, 0x36, 0x7a),
+    Color32::from_rgb(0xe5, 0x50, 0x64),
+    Color32::from_rgb(0xfb, 0x87, 0x61),
+    Color32::from_rgb(0xfe, 0xc2, 0x87),
+    Color32::from_rgb(0xfc, 0xfd, 0xbf),
+];
+
+const DIVERGING: [Color32; 7] = [
+    Color32::from_rgb(0x21, 0x66, 0xac),
+    Color32::from_rgb(0x67, 0xa9, 0xcf),
+    Color32::from_rgb(0xd1, 0xe5, 0xf0),
+    Color32::from_rgb(0xf7, 0xf7, 0xf7),
+    Color32::from_rgb(0xfd, 0xdb, 0xc7),
+    Color32::from_rgb(0xef, 0x8a, 0x62),
+    Color32::from_rgb(0xb2, 0x18, 0x2b),
+];
+
+/// Maps values between 0 and 1 to colors.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Colormap {
+    /// Perceptually uniform from dark purple over teal to yellow.
+    #[default]
+    Viridis,
+
+    /// Perceptually uniform from black over purple and orange to light yellow.
+    Magma,
+
+    /// From blue over white to red, for values around a neutral middle such as correlations.
+    Diverging,
+
+    /// Linear interpolation between evenly spaced colors, the first one for 0.
+    Gradient(Vec<Color32>),
+}
+
+impl Colormap {
+    /// The color of `t`, which is clamped to `0.0..=1.0`.
+    ///
+    /// ```
+    /// # use egui::Color32;
+    /// # use egui_plot::Colormap;
+    /// let colormap = Colormap::Gradient(vec![Color32::BLACK, Color32::WHITE]);
+    /// assert_eq!(colormap.color(0.0), Color32::BLACK);
+    /// assert_eq!(colormap.color(2.0), Color32::WHITE);
+    /// ```
+    pub fn color(&self, t: f64) -> Color32 {
+        let stops: &[Color32] = match self {
+            Self::Viridis => &VIRIDIS,
+            Self::Magma => &MAGMA,
+            Self::Diverging => &DIVERGING,
+            Self::Gradient(colors) => colors,
+        };
+        match stops {
+            [] => Color32::TRANSPARENT,
+            [color] => *color,
+            _ => {
+                let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+                let position = t * (stops.len() - 1) as f64;
+                let index = (position.floor() as usize).at_most(stops.len() - 2);
+                lerp_color(
+                    stops[index],
+                    stops[index + 1],
+                    (position - index as f64) as f32,
+                )
+            }
+        }
+    }
+
+    /// The color of `value` within `range`.
+    pub(crate) fn color_in(&self, value: f64, range: &RangeInclusive<f64>) -> Color32 {
+        let span = range.end() - range.start();
+        let t = if span > 0.0 {
+            (value - range.start()) / span
+        } else {
+            0.5
+        };
+        self.color(t)
+    }
+}
+
+fn lerp_color(a: Color32, b: Color32, t: f32) -> Color32 {
+    let lerp = |a: u8, b: u8| egui::lerp(a as f32..=b as f32, t).round() as u8;
+    Color32::from_rgba_premultiplied(
+        lerp(a.r(), b.r()),
+        lerp(a.g(), b.g()),
+        lerp(a.b(), b.b()),
+        lerp(a.a(), b.a()),
+    )
+}
+
+/// Decimals to show values within `range` with.
+pub(crate) fn value_decimals(range: &RangeInclusive<f64>) -> usize {
+    let span = (range.end() - range.start()).abs();
+    if span > 0.0 {
+        step_decimals(span / 100.0)
+    } else {
+        2
+    }
+}
+
+/// A vertical bar showing the colors of a [`Colormap`] with the values they stand for, to put
+/// next to a plot, e.g. one with a [`crate::Heatmap`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Grid, Heatmap, Plot};
+///
+/// let heatmap = Heatmap::new(Grid::new(vec![0.1, 0.4, 0.7, 1.0], 2));
+/// ui.horizontal(|ui| {
+///     ui.add(heatmap.colorbar().height(200.0));
+///     Plot::new("heatmap")
+///         .height(200.0)
+///         .show(ui, |plot_ui| plot_ui.heatmap(heatmap));
+/// });
+/// # });
+/// ```
+pub struct Colorbar {
+    colormap: Colormap,
+    range: RangeInclusive<f64>,
+    width: f32,
+    height: Option<f32>,
+    ticks: usize,
+}
+
+impl Colorbar {
+    /// A colorbar for values in `range`, the start of which has the color of 0 in `colormap`.
+    pub fn new(colormap: Colormap, range: RangeInclusive<f64>) -> Self {
+        Self {
+            colormap,
+            range,
+            width: 16.0,
+            height: None,
+            ticks: 5,
+        }
+    }
+
+    /// Width of the bar, not including the labels. Default: `16.0`.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Height of the widget. Default: the available height.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Number of labeled values, evenly spaced and including both ends. Default: `5`.
+    #[inline]
+    pub fn ticks(mut self, ticks: usize) -> Self {
+        self.ticks = ticks;
+        self
+    }
+}
+
+impl Widget for Colorbar {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            colormap,
+            range,
+            width,
+            height,
+            ticks,
+        } = self;
+
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let text_color = ui.visuals().text_color();
+        let decimals = value_decimals(&range);
+        let values: Vec<f64> = match ticks {
+            0 => Vec::new(),
+            1 => vec![*range.start()],
+            _ => (0..ticks)
+                .map(|i| egui::lerp(range.clone(), i as f64 / (ticks - 1) as f64))
+                .collect(),
+        };
+        let galleys: Vec<_> = values
+            .iter()
+            .map(|value| {
+                ui.painter().layout_no_wrap(
+                    format!("{value:.decimals$}"),
+                    font_id.clone(),
+                    text_color,
+                )
+            })
+            .collect();
+        let label_width = galleys
+            .iter()
+            .map(|galley| galley.size().x)
+            .fold(0.0, f32::max);
+
+        let tick_length = 4.0;
+        let size = vec2(
+            width + tick_length + 2.0 + label_width,
+            height.unwrap_or_else(|| ui.available_height()),
+        );
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        if !ui.is_rect_visible(rect) {
+            return response;
+        }
+
+        // Leave room for the labels at both ends.
+        let bar = Rect::from_min_max(
+            rect.min + vec2(0.0, font_id.size / 2.0),
+            pos2(rect.left() + width, rect.bottom() - font_id.size / 2.0),
+        );
+        let segments = 64;
+        let mut mesh = Mesh::default();
+        for i in 0..segments {
+            let (t0, t1) = (i as f32 / segments as f32, (i + 1) as f32 / segments as f32);
+            let segment = Rect::from_x_y_ranges(
+                bar.x_range(),
+                egui::lerp(bar.bottom()..=bar.top(), t1)..=egui::lerp(bar.bottom()..=bar.top(), t0),
+            );
+            let color = colormap.color(((t0 + t1) / 2.0) as f64);
+            mesh.add_colored_rect(segment, color);
+        }
+        let painter = ui.painter();
+        painter.add(Shape::mesh(mesh));
+        let stroke = Stroke::new(1.0, ui.visuals().widgets.noninteractive.bg_stroke.color);
+        painter.rect_stroke(bar, 0.0, stroke);
+
+        for (value, galley) in values.iter().zip(galleys) {
+            let t = if range.end() > range.start() {
+                ((value - range.start()) / (range.end() - range.start())) as f32
+            } else {
+                0.5
+            };
+            let y = egui::lerp(bar.bottom()..=bar.top(), t);
+            painter.line_segment(
+                [pos2(bar.right(), y), pos2(bar.right() + tick_length, y)],
+                stroke,
+            );
+            let text_rect = Align2::LEFT_CENTER.anchor_rect(Rect::from_min_size(
+                pos2(bar.right() + tick_length + 2.0, y),
+                galley.size(),
+            ));
+            painter.galley(text_rect.min, galley, text_color);
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_interpolates_between_stops() {
+        let gray = Color32::from_rgb(100, 100, 100);
+        let colormap = Colormap::Gradient(vec![Color32::BLACK, gray, Color32::WHITE]);
+        assert_eq!(colormap.color(0.0), Color32::BLACK);
+        assert_eq!(colormap.color(0.25), Color32::from_rgb(50, 50, 50));
+        assert_eq!(colormap.color(0.5), gray);
+        assert_eq!(colormap.color(0.75), Color32::from_rgb(178, 178, 178));
+        assert_eq!(colormap.color(1.0), Color32::WHITE);
+    }
+
+    #[test]
+    fn color_clamps_out_of_range_values() {
+        let colormap = Colormap::Viridis;
+        assert_eq!(colormap.color(-1.0), VIRIDIS[0]);
+        assert_eq!(colormap.color(f64::NEG_INFINITY), VIRIDIS[0]);
+        assert_eq!(colormap.color(f64::NAN), VIRIDIS[0]);
+        assert_eq!(colormap.color(1.5), VIRIDIS[8]);
+        assert_eq!(colormap.color(f64::INFINITY), VIRIDIS[8]);
+    }
+
+    #[test]
+    fn color_of_short_gradients() {
+        assert_eq!(Colormap::Gradient(vec![]).color(0.5), Color32::TRANSPARENT);
+        let red = Colormap::Gradient(vec![Color32::RED]);
+        assert_eq!(red.color(0.0), Color32::RED);
+        assert_eq!(red.color(1.0), Color32::RED);
+    }
+
+    #[test]
+    fn color_in_maps_the_range_to_the_ends() {
+        let colormap = Colormap::Diverging;
+        assert_eq!(colormap.color_in(-2.0, &(-2.0..=2.0)), DIVERGING[0]);
+        assert_eq!(colormap.color_in(0.0, &(-2.0..=2.0)), DIVERGING[3]);
+        assert_eq!(colormap.color_in(5.0, &(-2.0..=2.0)), DIVERGING[6]);
+        // An empty range gets the middle color.
+        assert_eq!(colormap.color_in(7.0, &(7.0..=7.0)), DIVERGING[3]);
+    }
+}
@@ -0,0 +1,215 @@
+//! A crosshair that follows the pointer, see [`crate::Plot::crosshair`].
+
+use egui::{epaint::util::FloatOrd as _, Pos2};
+
+use crate::{IndexedPoints, PlotItem, PlotPoint, PlotTransform, TimeIndex};
+
+/// What the crosshair of a plot snaps to, see [`crate::Plot::crosshair_snap`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CrosshairSnap {
+    /// Follow the pointer freely.
+    #[default]
+    Free,
+
+    /// Snap to the nearest value of an item, e.g. a series point or the open, high, low or close
+    /// of a candle, when the pointer is close to it.
+    WeakMagnet,
+
+    /// Always snap to the nearest value of an item.
+    StrongMagnet,
+}
+
+/// Where to draw the crosshair for the pointer, in main axis coordinates.
+///
+/// With a time index, the crosshair stays on whole bars even when it doesn't snap to an item.
+pub(crate) fn crosshair_point<'a>(
+    items: &'a [Box<dyn PlotItem>],
+    item_transform: impl Fn(&dyn PlotItem) -> &'a PlotTransform,
+    transform: &PlotTransform,
+    pointer: Pos2,
+    snap: CrosshairSnap,
+    time_index: Option<&TimeIndex>,
+) -> PlotPoint {
+    let max_dist_sq = match snap {
+        CrosshairSnap::Free => None,
+        CrosshairSnap::WeakMagnet => Some(IndexedPoints::HOVER_RADIUS.powi(2)),
+        CrosshairSnap::StrongMagnet => Some(f32::INFINITY),
+    };
+
+    let snapped = max_dist_sq.and_then(|max_dist_sq| {
+        let (item, elem) = items
+            .iter()
+            .filter_map(|item| {
+                let item = &**item;
+                Some(item).zip(item.find_closest(pointer, item_transform(item)))
+            })
+            .min_by_key(|(_, elem)| elem.dist_sq.ord())
+            .filter(|(_, elem)| elem.dist_sq <= max_dist_sq)?;
+
+        // Snap in the item's coordinates, then map the value to the main Y axis.
+        let item_transform = item_transform(item);
+        let value = item.snap_value(&elem, item_transform.value_from_position(pointer))?;
+        let pos = item_transform.position_from_point(&value);
+        Some(PlotPoint::new(
+            value.x,
+            transform.value_from_position(pos).y,
+        ))
+    });
+
+    snapped.unwrap_or_else(|| {
+        let mut value = transform.value_from_position(pointer);
+        if time_index.is_some() {
+            value.x = value.x.round();
+        }
+        value
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{pos2, Id, Rect};
+
+    use super::*;
+    use crate::{Candle, Candlestick, PlotBounds, PlotPoints, Points};
+
+    /// 10 points per unit on both axes, with Y pointing up.
+    fn transform() -> PlotTransform {
+        PlotTransform::new(
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0)),
+            PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]),
+            false,
+            false,
+        )
+    }
+
+    fn points(values: &[[f64; 2]]) -> Box<dyn PlotItem> {
+        Box::new(Points::new(PlotPoints::new(values.to_vec())))
+    }
+
+    fn snap(items: &[Box<dyn PlotItem>], pointer: Pos2, snap: CrosshairSnap) -> PlotPoint {
+        let transform = transform();
+        crosshair_point(items, |_| &transform, &transform, pointer, snap, None)
+    }
+
+    fn assert_point(actual: PlotPoint, x: f64, y: f64) {
+        assert!(
+            (actual.x - x).abs() < 1e-9 && (actual.y - y).abs() < 1e-9,
+            "{actual:?} != ({x}, {y})"
+        );
+    }
+
+    #[test]
+    fn free_follows_the_pointer() {
+        let items = [points(&[[5.0, 5.0]])];
+        assert_point(
+            snap(&items, pos2(53.0, 48.0), CrosshairSnap::Free),
+            5.3,
+            5.2,
+        );
+    }
+
+    #[test]
+    fn weak_magnet_snaps_within_the_hover_radius() {
+        let items = [points(&[[5.0, 5.0]])];
+        // 5 points away.
+        let near = pos2(53.0, 46.0);
+        assert_point(snap(&items, near, CrosshairSnap::WeakMagnet), 5.0, 5.0);
+
+        // Just outside the radius of 16 points.
+        let far = pos2(50.0 + IndexedPoints::HOVER_RADIUS + 1.0, 50.0);
+        assert_point(snap(&items, far, CrosshairSnap::WeakMagnet), 6.7, 5.0);
+    }
+
+    #[test]
+    fn strong_magnet_snaps_to_the_nearest_item() {
+        let items = [points(&[[5.0, 5.0]]), points(&[[8.0, 2.0]])];
+        assert_point(
+            snap(&items, pos2(90.0, 90.0), CrosshairSnap::StrongMagnet),
+            8.0,
+            2.0,
+        );
+        assert_point(
+            snap(&items, pos2(10.0, 10.0), CrosshairSnap::StrongMagnet),
+            5.0,
+            5.0,
+        );
+    }
+
+    #[test]
+    fn magnet_snaps_to_the_nearest_price_of_a_candle() {
+        let items: [Box<dyn PlotItem>; 1] = [Box::new(Candlestick::new(vec![Candle::new(
+            5.0, 4.0, 8.0, 2.0, 6.0,
+        )]))];
+        // Between the close at 6 and the high at 8, nearer to the high.
+        assert_point(
+            snap(&items, pos2(52.0, 27.0), CrosshairSnap::StrongMagnet),
+            5.0,
+            8.0,
+        );
+        assert_point(
+            snap(&items, pos2(52.0, 37.0), CrosshairSnap::StrongMagnet),
+            5.0,
+            6.0,
+        );
+    }
+
+    #[test]
+    fn snapped_values_map_to_the_main_y_axis() {
+        let axis = Id::new("volume");
+        // The other Y axis spans 0..=1000, so a point at 500 is halfway up the plot.
+        let other = PlotTransform::new(
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0)),
+            PlotBounds::from_min_max([0.0, 0.0], [10.0, 1000.0]),
+            false,
+            false,
+        );
+        let main = transform();
+        let items: [Box<dyn PlotItem>; 2] = [
+            Box::new(Points::new(PlotPoints::new(vec![[3.0, 500.0]])).y_axis(axis)),
+            points(&[[8.0, 9.0]]),
+        ];
+        let item_transform = |item: &dyn PlotItem| {
+            if item.y_axis() == Some(axis) {
+                &other
+            } else {
+                &main
+            }
+        };
+
+        let snapped = crosshair_point(
+            &items,
+            item_transform,
+            &main,
+            pos2(32.0, 52.0),
+            CrosshairSnap::WeakMagnet,
+            None,
+        );
+        assert_point(snapped, 3.0, 5.0);
+
+        // Measured in the item's own axis, the point at 500 would be far off screen.
+        let snapped = crosshair_point(
+            &items,
+            |_| &main,
+            &main,
+            pos2(32.0, 52.0),
+            CrosshairSnap::WeakMagnet,
+            None,
+        );
+        assert_point(snapped, 3.2, 4.8);
+    }
+
+    #[test]
+    fn time_index_keeps_whole_bars() {
+        let transform = transform();
+        let time_index = TimeIndex::new(vec![0.0, 60.0, 120.0]);
+        let point = crosshair_point(
+            &[],
+            |_| &transform,
+            &transform,
+            pos2(53.0, 48.0),
+            CrosshairSnap::Free,
+            Some(&time_index),
+        );
+        assert_point(point, 5.0, 5.2);
+    }
+}
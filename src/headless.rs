@@ -0,0 +1,324 @@
+//! Rendering plots without a window or GPU.
+
+use std::collections::HashMap;
+
+use egui::{
+    epaint::{ClippedShape, Primitive, Vertex},
+    CentralPanel, Color32, ColorImage, Context, FontDefinitions, Frame, ImageData, Pos2, RawInput,
+    Rect, TextureId, Ui, Vec2, ViewportId, Visuals,
+};
+
+/// Renders plots (or any other ui) in a headless egui [`Context`], e.g. for CI or reports.
+///
+/// The ui is run for a few frames, so that the plot can settle on its bounds and axis widths,
+/// and the shapes of the last frame are tessellated and rasterized on the CPU.
+///
+/// ```
+/// use egui_plot::{Line, Plot, PlotPoints, PlotRenderer};
+///
+/// let image = PlotRenderer::new(egui::vec2(400.0, 250.0))
+///     .pixels_per_point(2.0)
+///     .render(|ui| {
+///         Plot::new("report").show(ui, |plot_ui| {
+///             plot_ui.line(Line::new(PlotPoints::from_ys_f64(&[1.0, 3.0, 2.0, 5.0])));
+///         });
+///     });
+/// assert_eq!(image.size, [800, 500]);
+/// ```
+///
+/// With the `png` feature, [`crate::write_png`] saves the image.
+#[derive(Clone, Debug)]
+pub struct PlotRenderer {
+    size: Vec2,
+    pixels_per_point: f32,
+    visuals: Visuals,
+    fonts: Option<FontDefinitions>,
+    frames: usize,
+}
+
+impl PlotRenderer {
+    /// Render an area of `size` ui points.
+    pub fn new(size: Vec2) -> Self {
+        Self {
+            size,
+            pixels_per_point: 1.0,
+            visuals: Visuals::light(),
+            fonts: None,
+            frames: 2,
+        }
+    }
+
+    /// Number of pixels per ui point. Default: `1.0`.
+    #[inline]
+    pub fn pixels_per_point(mut self, pixels_per_point: f32) -> Self {
+        self.pixels_per_point = pixels_per_point;
+        self
+    }
+
+    /// The visuals to render with. Default: [`Visuals::light`], which suits most reports.
+    #[inline]
+    pub fn visuals(mut self, visuals: Visuals) -> Self {
+        self.visuals = visuals;
+        self
+    }
+
+    /// The fonts to render text with.
+    ///
+    /// By default egui's own fonts are used, which requires its `default_fonts` feature.
+    #[inline]
+    pub fn fonts(mut self, fonts: FontDefinitions) -> Self {
+        self.fonts = Some(fonts);
+        self
+    }
+
+    /// How many frames to run before capturing the last one. Default: `2`.
+    #[inline]
+    pub fn frames(mut self, frames: usize) -> Self {
+        self.frames = frames.max(1);
+        self
+    }
+
+    /// The shapes painted by `add_contents` in the last frame.
+    pub fn shapes(&self, add_contents: impl FnMut(&mut Ui)) -> Vec<ClippedShape> {
+        self.run(add_contents).1
+    }
+
+    /// Render `add_contents` to an image of `size * pixels_per_point` pixels.
+    ///
+    /// The image is filled with the panel color of the visuals wherever `add_contents` paints
+    /// nothing. [`crate::PlotImage`]s and other user textures are rendered as well.
+    pub fn render(&self, add_contents: impl FnMut(&mut Ui)) -> ColorImage {
+        let (ctx, shapes, textures) = self.run(add_contents);
+        let mut canvas = Canvas::new(
+            (self.size * self.pixels_per_point).round(),
+            self.pixels_per_point,
+        );
+        for clipped in ctx.tessellate(shapes, self.pixels_per_point) {
+            // Paint callbacks need a GPU.
+            if let Primitive::Mesh(mesh) = clipped.primitive {
+                let texture = textures.get(&mesh.texture_id);
+                for triangle in mesh.indices.chunks_exact(3) {
+                    let vertices = [0, 1, 2].map(|i| &mesh.vertices[triangle[i] as usize]);
+                    canvas.fill_triangle(clipped.clip_rect, vertices, texture);
+                }
+            }
+        }
+        canvas.into_image()
+    }
+
+    fn run(
+        &self,
+        mut add_contents: impl FnMut(&mut Ui),
+    ) -> (Context, Vec<ClippedShape>, HashMap<TextureId, Texture>) {
+        let ctx = Context::default();
+        ctx.set_visuals(self.visuals.clone());
+        if let Some(fonts) = &self.fonts {
+            ctx.set_fonts(fonts.clone());
+        }
+
+        let mut shapes = Vec::new();
+        let mut textures = HashMap::new();
+        for _ in 0..self.frames {
+            let mut input = RawInput {
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, self.size)),
+                ..Default::default()
+            };
+            input
+                .viewports
+                .entry(ViewportId::ROOT)
+                .or_default()
+                .native_pixels_per_point = Some(self.pixels_per_point);
+
+            let output = ctx.run(input, |ctx| {
+                let frame = Frame::none().fill(ctx.style().visuals.panel_fill);
+                CentralPanel::default()
+                    .frame(frame)
+                    .show(ctx, |ui| add_contents(ui));
+            });
+
+            for (id, delta) in output.textures_delta.set {
+                let (size, pixels) = match delta.image {
+                    ImageData::Color(image) => (image.size, image.pixels.clone()),
+                    ImageData::Font(image) => (image.size, image.srgba_pixels(None).collect()),
+                };
+                match delta.pos {
+                    None => {
+                        textures.insert(id, Texture { size, pixels });
+                    }
+                    Some(pos) => {
+                        if let Some(texture) = textures.get_mut(&id) {
+                            texture.patch(pos, size, &pixels);
+                        }
+                    }
+                }
+            }
+            for id in output.textures_delta.free {
+                textures.remove(&id);
+            }
+            shapes = output.shapes;
+        }
+        (ctx, shapes, textures)
+    }
+}
+
+/// Encode an image from [`PlotRenderer::render`] as PNG.
+#[cfg(feature = "png")]
+pub fn write_png(image: &ColorImage, writer: impl std::io::Write) -> std::io::Result<()> {
+    let mut encoder = png::Encoder::new(writer, image.size[0] as u32, image.size[1] as u32);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let data: Vec<u8> = image
+        .pixels
+        .iter()
+        .flat_map(|color| color.to_srgba_unmultiplied())
+        .collect();
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&data))
+        .map_err(std::io::Error::other)
+}
+
+// ----------------------------------------------------------------------------
+
+struct Texture {
+    size: [usize; 2],
+    pixels: Vec<Color32>,
+}
+
+impl Texture {
+    fn patch(&mut self, pos: [usize; 2], size: [usize; 2], pixels: &[Color32]) {
+        for y in 0..size[1] {
+            let row = &pixels[y * size[0]..(y + 1) * size[0]];
+            let start = (pos[1] + y) * self.size[0] + pos[0];
+            self.pixels[start..start + size[0]].copy_from_slice(row);
+        }
+    }
+
+    /// Bilinear sample at normalized texture coordinates, premultiplied.
+    fn sample(&self, uv: Pos2) -> [f32; 4] {
+        let [width, height] = self.size;
+        let x = uv.x * width as f32 - 0.5;
+        let y = uv.y * height as f32 - 0.5;
+        let (x0, y0) = (x.floor(), y.floor());
+        let (tx, ty) = (x - x0, y - y0);
+
+        let texel = |x: f32, y: f32| {
+            let x = (x.max(0.0) as usize).min(width - 1);
+            let y = (y.max(0.0) as usize).min(height - 1);
+            rgba(self.pixels[y * width + x])
+        };
+        let [a, b, c, d] = [
+            texel(x0, y0),
+            texel(x0 + 1.0, y0),
+            texel(x0, y0 + 1.0),
+            texel(x0 + 1.0, y0 + 1.0),
+        ];
+        std::array::from_fn(|i| {
+            let top = a[i] + (b[i] - a[i]) * tx;
+            let bottom = c[i] + (d[i] - c[i]) * tx;
+            top + (bottom - top) * ty
+        })
+    }
+}
+
+/// Premultiplied color in `0..=1`.
+fn rgba(color: Color32) -> [f32; 4] {
+    color.to_array().map(|c| c as f32 / 255.0)
+}
+
+/// Premultiplied RGBA pixels, blended like egui's renderers do.
+struct Canvas {
+    width: usize,
+    height: usize,
+    pixels_per_point: f32,
+    pixels: Vec<[f32; 4]>,
+}
+
+impl Canvas {
+    fn new(size: Vec2, pixels_per_point: f32) -> Self {
+        let (width, height) = (size.x as usize, size.y as usize);
+        Self {
+            width,
+            height,
+            pixels_per_point,
+            pixels: vec![[0.0; 4]; width * height],
+        }
+    }
+
+    fn fill_triangle(
+        &mut self,
+        clip_rect: Rect,
+        vertices: [&Vertex; 3],
+        texture: Option<&Texture>,
+    ) {
+        let [mut v0, mut v1, v2] = vertices;
+        let pos = |v: &Vertex| v.pos.to_vec2() * self.pixels_per_point;
+        let edge =
+            |a: Vec2, b: Vec2, p: Vec2| (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+
+        let mut area = edge(pos(v0), pos(v1), pos(v2));
+        if area < 0.0 {
+            std::mem::swap(&mut v0, &mut v1);
+            area = -area;
+        }
+        if area <= f32::EPSILON {
+            return;
+        }
+        let [p0, p1, p2] = [pos(v0), pos(v1), pos(v2)];
+
+        // Pixels exactly on an edge shared by two triangles belong to one of them only, so
+        // feathered edges aren't blended twice.
+        let owns_edge = |a: Vec2, b: Vec2| b.y > a.y || (b.y == a.y && b.x < a.x);
+        let inside = |w: f32, a: Vec2, b: Vec2| w > 0.0 || (w == 0.0 && owns_edge(a, b));
+
+        let clip = clip_rect * self.pixels_per_point;
+        let min = p0.min(p1).min(p2).max(clip.min.to_vec2()).max(Vec2::ZERO);
+        let max = p0.max(p1).max(p2).min(clip.max.to_vec2());
+        let (x_start, y_start) = (min.x.floor() as usize, min.y.floor() as usize);
+        let x_end = (max.x.ceil().max(0.0) as usize).min(self.width);
+        let y_end = (max.y.ceil().max(0.0) as usize).min(self.height);
+
+        let colors = [v0, v1, v2].map(|v| rgba(v.color));
+        for y in y_start..y_end {
+            for x in x_start..x_end {
+                let p = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                if !clip.contains(p.to_pos2()) {
+                    continue;
+                }
+                let (e0, e1, e2) = (edge(p1, p2, p), edge(p2, p0, p), edge(p0, p1, p));
+                if !(inside(e0, p1, p2) && inside(e1, p2, p0) && inside(e2, p0, p1)) {
+                    continue;
+                }
+                let w = [e0 / area, e1 / area, e2 / area];
+
+                let mut src: [f32; 4] = std::array::from_fn(|i| {
+                    w[0] * colors[0][i] + w[1] * colors[1][i] + w[2] * colors[2][i]
+                });
+                if let Some(texture) = texture {
+                    let uv =
+                        (v0.uv.to_vec2() * w[0] + v1.uv.to_vec2() * w[1] + v2.uv.to_vec2() * w[2])
+                            .to_pos2();
+                    let texel = texture.sample(uv);
+                    src = std::array::from_fn(|i| src[i] * texel[i]);
+                }
+
+                let dst = &mut self.pixels[y * self.width + x];
+                *dst = std::array::from_fn(|i| src[i] + dst[i] * (1.0 - src[3]));
+            }
+        }
+    }
+
+    fn into_image(self) -> ColorImage {
+        let to_byte = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        ColorImage {
+            size: [self.width, self.height],
+            pixels: self
+                .pixels
+                .into_iter()
+                .map(|[r, g, b, a]| {
+                    Color32::from_rgba_premultiplied(to_byte(r), to_byte(g), to_byte(b), to_byte(a))
+                })
+                .collect(),
+        }
+    }
+}
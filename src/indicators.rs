@@ -0,0 +1,982 @@
+//! Technical indicators that produce ready-made plot items.
+//!
+//! Indicators are computed incrementally: keep one in your app state and [`push`](Sma::push)
+//! each new bar as it arrives, or [`update_last`](Sma::update_last) while the latest bar is
+//! still forming. Both only take the work of a single bar, so indicators stay cheap on long,
+//! live series. Bars before the indicator has enough data (its warm-up) produce no value, and
+//! neither do bars with a value that isn't finite, e.g. a missing close: they are skipped.
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use egui_plot::{indicators::{BollingerBands, Rsi, Sma}, Candle, Candlestick, Plot};
+//!
+//! let candles: Vec<Candle> = (0..100)
+//!     .map(|i| {
+//!         let close = 100.0 + (i as f64 * 0.3).sin() * 5.0;
+//!         Candle::new(i as f64, close - 1.0, close + 1.5, close - 2.0, close)
+//!     })
+//!     .collect();
+//!
+//! // Typically kept in your app state and fed new bars as they arrive.
+//! let mut sma = Sma::new(20);
+//! let mut bollinger = BollingerBands::new(20, 2.0);
+//! let mut rsi = Rsi::new(14);
+//! for candle in &candles {
+//!     sma.push(candle.time, candle.close);
+//!     bollinger.push(candle.time, candle.close);
+//!     rsi.push(candle.time, candle.close);
+//! }
+//!
+//! Plot::new("prices").show(ui, |plot_ui| {
+//!     plot_ui.band(bollinger.band());
+//!     plot_ui.line(bollinger.upper_line());
+//!     plot_ui.line(bollinger.lower_line());
+//!     plot_ui.line(sma.line());
+//!     plot_ui.candlesticks(Candlestick::new(candles.clone()));
+//! });
+//! Plot::new("rsi").show(ui, |plot_ui| {
+//!     plot_ui.hline(rsi.overbought_line());
+//!     plot_ui.hline(rsi.oversold_line());
+//!     plot_ui.line(rsi.line());
+//! });
+//! # });
+//! ```
+
+use std::{collections::VecDeque, fmt::Debug};
+
+use egui::Color32;
+
+use crate::{Band, Bar, BarChart, Candle, HLine, Line, LineStyle, PlotPoint, PlotPoints};
+
+/// Simple moving average of the last `period` values.
+#[derive(Clone, Debug)]
+pub struct Sma {
+    series: Series<Window>,
+}
+
+impl Sma {
+    /// The average over the last `period` values, e.g. `20`.
+    pub fn new(period: usize) -> Self {
+        Self {
+            series: Series::new(Window::new(period)),
+        }
+    }
+
+    /// Add the value of a new bar at `x`.
+    #[inline]
+    pub fn push(&mut self, x: f64, value: f64) {
+        self.series.push(x, value);
+    }
+
+    /// Replace the value of the latest bar, e.g. while it is still forming.
+    #[inline]
+    pub fn update_last(&mut self, x: f64, value: f64) {
+        self.series.update_last(x, value);
+    }
+
+    /// Add the Y values of `points` as bars.
+    pub fn extend(&mut self, points: &[PlotPoint]) {
+        for point in points {
+            self.push(point.x, point.y);
+        }
+    }
+
+    /// The averages, starting at the `period`-th bar.
+    pub fn values(&self) -> Vec<PlotPoint> {
+        self.series.points(|&average| Some(average))
+    }
+
+    /// The averages as a line named e.g. `SMA 20`.
+    pub fn line(&self) -> Line {
+        Line::new(PlotPoints::Owned(self.values()))
+            .name(format!("SMA {}", self.series.state.period))
+    }
+}
+
+/// Exponential moving average, seeded with the simple average of the first `period` values.
+#[derive(Clone, Debug)]
+pub struct Ema {
+    series: Series<EmaState>,
+}
+
+impl Ema {
+    /// The average with a smoothing factor of `2 / (period + 1)`, e.g. `50`.
+    pub fn new(period: usize) -> Self {
+        Self {
+            series: Series::new(EmaState::new(period)),
+        }
+    }
+
+    /// Add the value of a new bar at `x`.
+    #[inline]
+    pub fn push(&mut self, x: f64, value: f64) {
+        self.series.push(x, value);
+    }
+
+    /// Replace the value of the latest bar, e.g. while it is still forming.
+    #[inline]
+    pub fn update_last(&mut self, x: f64, value: f64) {
+        self.series.update_last(x, value);
+    }
+
+    /// Add the Y values of `points` as bars.
+    pub fn extend(&mut self, points: &[PlotPoint]) {
+        for point in points {
+            self.push(point.x, point.y);
+        }
+    }
+
+    /// The averages, starting at the `period`-th bar.
+    pub fn values(&self) -> Vec<PlotPoint> {
+        self.series.points(|&average| Some(average))
+    }
+
+    /// The averages as a line named e.g. `EMA 50`.
+    pub fn line(&self) -> Line {
+        Line::new(PlotPoints::Owned(self.values()))
+            .name(format!("EMA {}", self.series.state.period))
+    }
+}
+
+/// Bollinger bands: a simple moving average with bands `k` standard deviations above and below.
+#[derive(Clone, Debug)]
+pub struct BollingerBands {
+    series: Series<BollingerState>,
+}
+
+impl BollingerBands {
+    /// Bands over the last `period` values, `k` standard deviations wide. Usually `20` and `2.0`.
+    pub fn new(period: usize, k: f64) -> Self {
+        Self {
+            series: Series::new(BollingerState {
+                window: Window::new(period),
+                k,
+            }),
+        }
+    }
+
+    /// Add the value of a new bar at `x`.
+    #[inline]
+    pub fn push(&mut self, x: f64, value: f64) {
+        self.series.push(x, value);
+    }
+
+    /// Replace the value of the latest bar, e.g. while it is still forming.
+    #[inline]
+    pub fn update_last(&mut self, x: f64, value: f64) {
+        self.series.update_last(x, value);
+    }
+
+    /// Add the Y values of `points` as bars.
+    pub fn extend(&mut self, points: &[PlotPoint]) {
+        for point in points {
+            self.push(point.x, point.y);
+        }
+    }
+
+    /// The moving average in the middle.
+    pub fn middle(&self) -> Vec<PlotPoint> {
+        self.series.points(|bands| Some(bands.middle))
+    }
+
+    /// The upper band, `k` standard deviations above the middle.
+    pub fn upper(&self) -> Vec<PlotPoint> {
+        self.series.points(|bands| Some(bands.upper))
+    }
+
+    /// The lower band, `k` standard deviations below the middle.
+    pub fn lower(&self) -> Vec<PlotPoint> {
+        self.series.points(|bands| Some(bands.lower))
+    }
+
+    fn name(&self) -> String {
+        format!(
+            "BB {} {}",
+            self.series.state.window.period, self.series.state.k
+        )
+    }
+
+    /// The moving average as a line. All items share a name, so they share a legend entry.
+    pub fn middle_line(&self) -> Line {
+        Line::new(PlotPoints::Owned(self.middle())).name(self.name())
+    }
+
+    /// The upper band as a line.
+    pub fn upper_line(&self) -> Line {
+        Line::new(PlotPoints::Owned(self.upper())).name(self.name())
+    }
+
+    /// The lower band as a line.
+    pub fn lower_line(&self) -> Line {
+        Line::new(PlotPoints::Owned(self.lower())).name(self.name())
+    }
+
+    /// The area between the upper and lower band.
+    pub fn band(&self) -> Band {
+        Band::new(
+            PlotPoints::Owned(self.upper()),
+            PlotPoints::Owned(self.lower()),
+        )
+        .name(self.name())
+    }
+}
+
+/// Relative strength index with Wilder's smoothing, between `0` and `100`.
+#[derive(Clone, Debug)]
+pub struct Rsi {
+    series: Series<RsiState>,
+    oversold: f64,
+    overbought: f64,
+}
+
+impl Rsi {
+    /// The RSI over `period` changes, usually `14`.
+    pub fn new(period: usize) -> Self {
+        Self {
+            series: Series::new(RsiState {
+                period: period.max(1),
+                previous: None,
+                changes: 0,
+                gain: 0.0,
+                loss: 0.0,
+            }),
+            oversold: 30.0,
+            overbought: 70.0,
+        }
+    }
+
+    /// Set the levels of [`Self::oversold_line`] and [`Self::overbought_line`].
+    ///
+    /// Default: `30.0` and `70.0`.
+    #[inline]
+    pub fn levels(mut self, oversold: f64, overbought: f64) -> Self {
+        self.oversold = oversold;
+        self.overbought = overbought;
+        self
+    }
+
+    /// Add the value of a new bar at `x`.
+    #[inline]
+    pub fn push(&mut self, x: f64, value: f64) {
+        self.series.push(x, value);
+    }
+
+    /// Replace the value of the latest bar, e.g. while it is still forming.
+    #[inline]
+    pub fn update_last(&mut self, x: f64, value: f64) {
+        self.series.update_last(x, value);
+    }
+
+    /// Add the Y values of `points` as bars.
+    pub fn extend(&mut self, points: &[PlotPoint]) {
+        for point in points {
+            self.push(point.x, point.y);
+        }
+    }
+
+    /// The RSI, starting at the bar after the first `period` changes.
+    pub fn values(&self) -> Vec<PlotPoint> {
+        self.series.points(|&rsi| Some(rsi))
+    }
+
+    /// The RSI as a line named e.g. `RSI 14`.
+    pub fn line(&self) -> Line {
+        Line::new(PlotPoints::Owned(self.values()))
+            .name(format!("RSI {}", self.series.state.period))
+    }
+
+    /// A dashed line at the overbought level.
+    pub fn overbought_line(&self) -> HLine {
+        HLine::new(self.overbought)
+            .style(LineStyle::dashed_loose())
+            .color(Color32::GRAY)
+    }
+
+    /// A dashed line at the oversold level.
+    pub fn oversold_line(&self) -> HLine {
+        HLine::new(self.oversold)
+            .style(LineStyle::dashed_loose())
+            .color(Color32::GRAY)
+    }
+}
+
+/// Moving average convergence divergence: the difference of a fast and a slow [`Ema`], with
+/// a signal line averaging that difference.
+#[derive(Clone, Debug)]
+pub struct Macd {
+    series: Series<MacdState>,
+}
+
+impl Macd {
+    /// Usually `12`, `26` and `9`.
+    pub fn new(fast: usize, slow: usize, signal: usize) -> Self {
+        Self {
+            series: Series::new(MacdState {
+                fast: EmaState::new(fast),
+                slow: EmaState::new(slow),
+                signal: EmaState::new(signal),
+            }),
+        }
+    }
+
+    /// Add the value of a new bar at `x`.
+    #[inline]
+    pub fn push(&mut self, x: f64, value: f64) {
+        self.series.push(x, value);
+    }
+
+    /// Replace the value of the latest bar, e.g. while it is still forming.
+    #[inline]
+    pub fn update_last(&mut self, x: f64, value: f64) {
+        self.series.update_last(x, value);
+    }
+
+    /// Add the Y values of `points` as bars.
+    pub fn extend(&mut self, points: &[PlotPoint]) {
+        for point in points {
+            self.push(point.x, point.y);
+        }
+    }
+
+    /// The difference between the fast and the slow average.
+    pub fn macd(&self) -> Vec<PlotPoint> {
+        self.series.points(|&(macd, _)| Some(macd))
+    }
+
+    /// The average of the MACD.
+    pub fn signal(&self) -> Vec<PlotPoint> {
+        self.series.points(|&(_, signal)| signal)
+    }
+
+    /// The difference between the MACD and its signal.
+    pub fn histogram(&self) -> Vec<PlotPoint> {
+        self.series
+            .points(|&(macd, signal)| signal.map(|signal| macd - signal))
+    }
+
+    fn name(&self) -> String {
+        let state = &self.series.state;
+        format!(
+            "MACD {} {} {}",
+            state.fast.period, state.slow.period, state.signal.period
+        )
+    }
+
+    /// The MACD as a line named e.g. `MACD 12 26 9`.
+    pub fn macd_line(&self) -> Line {
+        Line::new(PlotPoints::Owned(self.macd())).name(self.name())
+    }
+
+    /// The signal as a line named `Signal`.
+    pub fn signal_line(&self) -> Line {
+        Line::new(PlotPoints::Owned(self.signal())).name("Signal")
+    }
+
+    /// The histogram as bars, colored by sign like rising and falling [`Candle`]s.
+    pub fn histogram_chart(&self) -> BarChart {
+        let histogram = self.histogram();
+        let spacing = histogram
+            .windows(2)
+            .map(|w| w[1].x - w[0].x)
+            .filter(|dx| *dx > 0.0)
+            .fold(f64::INFINITY, f64::min);
+        let width = if spacing.is_finite() {
+            spacing * 0.6
+        } else {
+            0.6
+        };
+
+        let bars = histogram
+            .iter()
+            .map(|point| {
+                let color = if point.y >= 0.0 {
+                    Color32::from_rgb(38, 166, 154)
+                } else {
+                    Color32::from_rgb(239, 83, 80)
+                };
+                Bar::new(point.x, point.y)
+                    .width(width)
+                    .fill(color.linear_multiply(0.5))
+                    .stroke((1.0, color))
+            })
+            .collect();
+        BarChart::new(bars).name("Histogram")
+    }
+}
+
+/// Volume weighted average price since the start of the session.
+#[derive(Clone, Debug)]
+pub struct Vwap {
+    series: Series<VwapState>,
+}
+
+impl Default for Vwap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Vwap {
+    /// An empty session.
+    pub fn new() -> Self {
+        Self {
+            series: Series::new(VwapState::default()),
+        }
+    }
+
+    /// Add a new bar at `x` with its `price` (usually the typical price) and `volume`.
+    #[inline]
+    pub fn push(&mut self, x: f64, price: f64, volume: f64) {
+        self.series.push(x, (price, volume));
+    }
+
+    /// Replace the latest bar, e.g. while it is still forming.
+    #[inline]
+    pub fn update_last(&mut self, x: f64, price: f64, volume: f64) {
+        self.series.update_last(x, (price, volume));
+    }
+
+    /// Add a new bar at the typical price (high + low + close) / 3 of `candle`.
+    pub fn push_candle(&mut self, candle: &Candle, volume: f64) {
+        let price = (candle.high + candle.low + candle.close) / 3.0;
+        self.push(candle.time, price, volume);
+    }
+
+    /// Start a new session, e.g. a new trading day, before pushing its first bar.
+    pub fn reset(&mut self) {
+        self.series.state = VwapState::default();
+        self.series.before_last = None;
+    }
+
+    /// The VWAP at every bar with volume so far in the session.
+    pub fn values(&self) -> Vec<PlotPoint> {
+        self.series.points(|&vwap| Some(vwap))
+    }
+
+    /// The VWAP as a line named `VWAP`.
+    pub fn line(&self) -> Line {
+        Line::new(PlotPoints::Owned(self.values())).name("VWAP")
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The state of an indicator, advanced one bar at a time.
+trait Step: Clone + Debug {
+    type Input;
+    type Output: Clone + Debug;
+
+    fn step(&mut self, input: Self::Input) -> Option<Self::Output>;
+}
+
+/// The outputs of an indicator, plus what is needed to redo the latest bar.
+#[derive(Clone, Debug)]
+struct Series<S: Step> {
+    state: S,
+
+    /// The state before the latest bar.
+    before_last: Option<S>,
+
+    /// Whether the latest bar produced an output.
+    last_has_output: bool,
+
+    outputs: Vec<(f64, S::Output)>,
+}
+
+impl<S: Step> Series<S> {
+    fn new(state: S) -> Self {
+        Self {
+            state,
+            before_last: None,
+            last_has_output: false,
+            outputs: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, x: f64, input: S::Input) {
+        self.before_last = Some(self.state.clone());
+        let output = self.state.step(input);
+        self.last_has_output = output.is_some();
+        self.outputs.extend(output.map(|output| (x, output)));
+    }
+
+    fn update_last(&mut self, x: f64, input: S::Input) {
+        if let Some(state) = self.before_last.take() {
+            self.state = state;
+            if self.last_has_output {
+                self.outputs.pop();
+            }
+        }
+        self.push(x, input);
+    }
+
+    fn points(&self, value: impl Fn(&S::Output) -> Option<f64>) -> Vec<PlotPoint> {
+        self.outputs
+            .iter()
+            .filter_map(|(x, output)| Some(PlotPoint::new(*x, value(output)?)))
+            .collect()
+    }
+}
+
+/// The last `period` values.
+#[derive(Clone, Debug)]
+struct Window {
+    period: usize,
+    values: VecDeque<f64>,
+    sum: f64,
+
+    /// Values dropped since `sum` was last recomputed from `values`.
+    dropped: usize,
+}
+
+impl Window {
+    fn new(period: usize) -> Self {
+        let period = period.max(1);
+        Self {
+            period,
+            values: VecDeque::with_capacity(period + 1),
+            sum: 0.0,
+            dropped: 0,
+        }
+    }
+
+    /// Add a finite value and return the mean once the window is full.
+    fn add(&mut self, value: f64) -> Option<f64> {
+        if !value.is_finite() {
+            return None;
+        }
+        self.values.push_back(value);
+        self.sum += value;
+        if self.values.len() > self.period {
+            self.sum -= self.values.pop_front().unwrap_or_default();
+            self.dropped += 1;
+            // Recompute the sum once per full turn of the window, so rounding errors of the
+            // running sum don't build up on long series.
+            if self.dropped == self.period {
+                self.sum = self.values.iter().sum();
+                self.dropped = 0;
+            }
+        }
+        (self.values.len() == self.period).then(|| self.sum / self.period as f64)
+    }
+}
+
+impl Step for Window {
+    type Input = f64;
+    type Output = f64;
+
+    fn step(&mut self, value: f64) -> Option<f64> {
+        self.add(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct EmaState {
+    period: usize,
+    count: usize,
+    sum: f64,
+    value: Option<f64>,
+}
+
+impl EmaState {
+    fn new(period: usize) -> Self {
+        Self {
+            period: period.max(1),
+            count: 0,
+            sum: 0.0,
+            value: None,
+        }
+    }
+}
+
+impl Step for EmaState {
+    type Input = f64;
+    type Output = f64;
+
+    fn step(&mut self, value: f64) -> Option<f64> {
+        if !value.is_finite() {
+            return None;
+        }
+        if let Some(average) = &mut self.value {
+            let alpha = 2.0 / (self.period as f64 + 1.0);
+            *average += alpha * (value - *average);
+        } else {
+            self.count += 1;
+            self.sum += value;
+            if self.count == self.period {
+                self.value = Some(self.sum / self.period as f64);
+            }
+        }
+        self.value
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Bands {
+    middle: f64,
+    upper: f64,
+    lower: f64,
+}
+
+#[derive(Clone, Debug)]
+struct BollingerState {
+    window: Window,
+    k: f64,
+}
+
+impl Step for BollingerState {
+    type Input = f64;
+    type Output = Bands;
+
+    fn step(&mut self, value: f64) -> Option<Bands> {
+        let middle = self.window.add(value)?;
+        let variance = self
+            .window
+            .values
+            .iter()
+            .map(|value| (value - middle).powi(2))
+            .sum::<f64>()
+            / self.window.period as f64;
+        let width = self.k * variance.sqrt();
+        Some(Bands {
+            middle,
+            upper: middle + width,
+            lower: middle - width,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct RsiState {
+    period: usize,
+    previous: Option<f64>,
+    changes: usize,
+
+    /// Sums during the warm-up, averages after it.
+    gain: f64,
+    loss: f64,
+}
+
+impl Step for RsiState {
+    type Input = f64;
+    type Output = f64;
+
+    fn step(&mut self, value: f64) -> Option<f64> {
+        if !value.is_finite() {
+            return None;
+        }
+        let change = value - self.previous.replace(value)?;
+        let (gain, loss) = (change.max(0.0), (-change).max(0.0));
+        let period = self.period as f64;
+
+        self.changes += 1;
+        if self.changes <= self.period {
+            self.gain += gain;
+            self.loss += loss;
+            if self.changes < self.period {
+                return None;
+            }
+            self.gain /= period;
+            self.loss /= period;
+        } else {
+            self.gain = (self.gain * (period - 1.0) + gain) / period;
+            self.loss = (self.loss * (period - 1.0) + loss) / period;
+        }
+
+        Some(if self.loss == 0.0 {
+            if self.gain == 0.0 {
+                50.0
+            } else {
+                100.0
+            }
+        } else {
+            100.0 - 100.0 / (1.0 + self.gain / self.loss)
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct MacdState {
+    fast: EmaState,
+    slow: EmaState,
+    signal: EmaState,
+}
+
+impl Step for MacdState {
+    type Input = f64;
+    type Output = (f64, Option<f64>);
+
+    fn step(&mut self, value: f64) -> Option<(f64, Option<f64>)> {
+        let fast = self.fast.step(value);
+        let slow = self.slow.step(value);
+        let macd = fast? - slow?;
+        Some((macd, self.signal.step(macd)))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct VwapState {
+    price_volume: f64,
+    volume: f64,
+}
+
+impl Step for VwapState {
+    type Input = (f64, f64);
+    type Output = f64;
+
+    fn step(&mut self, (price, volume): (f64, f64)) -> Option<f64> {
+        if !(price * volume).is_finite() {
+            return None;
+        }
+        self.price_volume += price * volume;
+        self.volume += volume;
+        (self.volume > 0.0).then(|| self.price_volume / self.volume)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ys(points: &[PlotPoint]) -> Vec<f64> {
+        points.iter().map(|point| point.y).collect()
+    }
+
+    fn assert_close(actual: &[PlotPoint], expected: &[f64]) {
+        let actual = ys(actual);
+        assert_eq!(actual.len(), expected.len(), "{actual:?} vs {expected:?}");
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-9, "{actual:?} vs {expected:?}");
+        }
+    }
+
+    fn push_all(values: &[f64], mut push: impl FnMut(f64, f64)) {
+        for (i, &value) in values.iter().enumerate() {
+            push(i as f64, value);
+        }
+    }
+
+    #[test]
+    fn sma_warm_up_and_values() {
+        let mut sma = Sma::new(3);
+        push_all(&[1.0, 2.0], |x, y| sma.push(x, y));
+        assert!(sma.values().is_empty());
+        push_all(&[3.0, 4.0, 5.0], |x, y| sma.push(x + 2.0, y));
+        assert_close(&sma.values(), &[2.0, 3.0, 4.0]);
+        assert_eq!(sma.values()[0].x, 2.0);
+    }
+
+    #[test]
+    fn ema_is_seeded_with_the_sma() {
+        let mut ema = Ema::new(3);
+        push_all(&[1.0, 2.0, 3.0, 4.0, 5.0, 1.0], |x, y| ema.push(x, y));
+        // Seed 2, then alpha = 0.5.
+        assert_close(&ema.values(), &[2.0, 3.0, 4.0, 2.5]);
+    }
+
+    #[test]
+    fn bollinger_uses_population_deviation() {
+        let mut bollinger = BollingerBands::new(3, 2.0);
+        push_all(&[1.0, 2.0, 3.0], |x, y| bollinger.push(x, y));
+        let deviation = (2.0_f64 / 3.0).sqrt();
+        assert_close(&bollinger.middle(), &[2.0]);
+        assert_close(&bollinger.upper(), &[2.0 + 2.0 * deviation]);
+        assert_close(&bollinger.lower(), &[2.0 - 2.0 * deviation]);
+    }
+
+    #[test]
+    fn rsi_uses_wilder_smoothing() {
+        let mut rsi = Rsi::new(2);
+        push_all(&[1.0, 2.0, 3.0, 2.0, 5.0], |x, y| rsi.push(x, y));
+        // Averages after the first two changes: gain 1, loss 0. Then Wilder's
+        // (avg * (n - 1) + current) / n: gain 0.5, loss 0.5; gain 1.75, loss 0.25.
+        // A plain moving average of the last two changes would give 75 at the end.
+        assert_close(&rsi.values(), &[100.0, 50.0, 87.5]);
+        assert_eq!(rsi.values()[0].x, 2.0);
+    }
+
+    #[test]
+    fn rsi_of_a_flat_series_is_neutral() {
+        let mut rsi = Rsi::new(2);
+        push_all(&[1.0, 1.0, 1.0], |x, y| rsi.push(x, y));
+        assert_close(&rsi.values(), &[50.0]);
+    }
+
+    #[test]
+    fn macd_is_the_difference_of_two_emas() {
+        let values = [1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0];
+        let mut macd = Macd::new(2, 3, 2);
+        let mut fast = Ema::new(2);
+        let mut slow = Ema::new(3);
+        push_all(&values, |x, y| {
+            macd.push(x, y);
+            fast.push(x, y);
+            slow.push(x, y);
+        });
+
+        let fast = fast.values();
+        let slow = slow.values();
+        let expected: Vec<f64> = slow
+            .iter()
+            .map(|slow| fast.iter().find(|fast| fast.x == slow.x).unwrap().y - slow.y)
+            .collect();
+        assert_close(&macd.macd(), &expected);
+        assert_eq!(macd.macd()[0].x, 2.0);
+
+        // The signal needs `signal` MACD values of its own before it starts.
+        let mut signal = Ema::new(2);
+        for point in macd.macd() {
+            signal.push(point.x, point.y);
+        }
+        assert_close(&macd.signal(), &ys(&signal.values()));
+        assert_eq!(macd.signal()[0].x, 3.0);
+
+        let histogram: Vec<f64> = macd.macd()[1..]
+            .iter()
+            .zip(macd.signal())
+            .map(|(macd, signal)| macd.y - signal.y)
+            .collect();
+        assert_close(&macd.histogram(), &histogram);
+    }
+
+    #[test]
+    fn vwap_weights_by_volume_per_session() {
+        let mut vwap = Vwap::new();
+        vwap.push(0.0, 10.0, 1.0);
+        vwap.push(1.0, 20.0, 3.0);
+        assert_close(&vwap.values(), &[10.0, 17.5]);
+
+        vwap.reset();
+        vwap.push(2.0, 30.0, 2.0);
+        assert_close(&vwap.values(), &[10.0, 17.5, 30.0]);
+    }
+
+    #[test]
+    fn vwap_skips_bars_before_any_volume() {
+        let mut vwap = Vwap::new();
+        vwap.push(0.0, 10.0, 0.0);
+        vwap.push(1.0, 20.0, 2.0);
+        assert_close(&vwap.values(), &[20.0]);
+        assert_eq!(vwap.values()[0].x, 1.0);
+    }
+
+    /// Pushing a wrong last value and then replacing it must match pushing the right one,
+    /// both during the warm-up and after it.
+    fn check_update_last<T>(
+        new: impl Fn() -> T,
+        push: impl Fn(&mut T, f64, f64),
+        update_last: impl Fn(&mut T, f64, f64),
+        outputs: impl Fn(&T) -> Vec<Vec<PlotPoint>>,
+    ) {
+        let values = [5.0, 3.0, 8.0, 6.0, 9.0, 4.0, 7.0, 10.0, 2.0, 6.0];
+        for len in 1..=values.len() {
+            let mut expected = new();
+            let mut updated = new();
+            for (i, &value) in values[..len].iter().enumerate() {
+                push(&mut expected, i as f64, value);
+                if i + 1 < len {
+                    push(&mut updated, i as f64, value);
+                } else {
+                    push(&mut updated, i as f64, value * 3.0 + 1.0);
+                    update_last(&mut updated, i as f64, value - 2.0);
+                    update_last(&mut updated, i as f64, value);
+                }
+            }
+            let expected = outputs(&expected);
+            for (updated, expected) in outputs(&updated).iter().zip(&expected) {
+                assert_close(updated, &ys(expected));
+            }
+        }
+    }
+
+    #[test]
+    fn update_last_replaces_the_latest_bar() {
+        check_update_last(
+            || Sma::new(3),
+            Sma::push,
+            Sma::update_last,
+            |sma| vec![sma.values()],
+        );
+        check_update_last(
+            || Ema::new(3),
+            Ema::push,
+            Ema::update_last,
+            |ema| vec![ema.values()],
+        );
+        check_update_last(
+            || BollingerBands::new(3, 2.0),
+            BollingerBands::push,
+            BollingerBands::update_last,
+            |bands| vec![bands.middle(), bands.upper(), bands.lower()],
+        );
+        check_update_last(
+            || Rsi::new(3),
+            Rsi::push,
+            Rsi::update_last,
+            |rsi| vec![rsi.values()],
+        );
+        check_update_last(
+            || Macd::new(2, 4, 3),
+            Macd::push,
+            Macd::update_last,
+            |macd| vec![macd.macd(), macd.signal(), macd.histogram()],
+        );
+        check_update_last(
+            Vwap::new,
+            |vwap, x, price| vwap.push(x, price, x + 1.0),
+            |vwap, x, price| vwap.update_last(x, price, x + 1.0),
+            |vwap| vec![vwap.values()],
+        );
+    }
+
+    #[test]
+    fn non_finite_values_are_skipped() {
+        let mut sma = Sma::new(2);
+        let mut ema = Ema::new(2);
+        let mut bands = BollingerBands::new(2, 2.0);
+        let mut rsi = Rsi::new(2);
+        let mut macd = Macd::new(2, 3, 2);
+        let mut vwap = Vwap::new();
+        let values = [1.0, 2.0, f64::NAN, 3.0, f64::INFINITY, 4.0, 5.0, 6.0];
+        for (i, &value) in values.iter().enumerate() {
+            let x = i as f64;
+            sma.push(x, value);
+            ema.push(x, value);
+            bands.push(x, value);
+            rsi.push(x, value);
+            macd.push(x, value);
+            vwap.push(x, value, 1.0);
+        }
+
+        // The same as the finite values alone, at the X values of their bars.
+        assert_close(&sma.values(), &[1.5, 2.5, 3.5, 4.5, 5.5]);
+        assert_eq!(
+            sma.values().iter().map(|p| p.x).collect::<Vec<_>>(),
+            [1.0, 3.0, 5.0, 6.0, 7.0]
+        );
+        assert_close(&ema.values(), &[1.5, 2.5, 3.5, 4.5, 5.5]);
+        assert_close(&bands.upper(), &[2.5, 3.5, 4.5, 5.5, 6.5]);
+        assert_close(&rsi.values(), &[100.0; 4]);
+        assert!(macd.histogram().iter().all(|p| p.y.is_finite()));
+        assert_close(&vwap.values(), &[1.0, 1.5, 2.0, 2.5, 3.0, 3.5]);
+    }
+
+    #[test]
+    fn long_series_keep_an_exact_window_sum() {
+        let mut sma = Sma::new(3);
+        for i in 0..10_000 {
+            sma.push(i as f64, if i % 2 == 0 { 1e8 } else { 0.1 });
+        }
+        for i in 0..3 {
+            sma.push(10_000.0 + i as f64, 1.0);
+        }
+        assert_eq!(sma.values().last().unwrap().y, 1.0);
+    }
+
+    #[test]
+    fn update_last_without_bars_pushes() {
+        let mut sma = Sma::new(1);
+        sma.update_last(0.0, 4.0);
+        assert_close(&sma.values(), &[4.0]);
+    }
+}
@@ -0,0 +1,356 @@
+use std::ops::RangeInclusive;
+
+use egui::{
+    emath::NumExt as _,
+    epaint::{util::FloatOrd as _, Mesh},
+    vec2, Align2, Color32, Id, Pos2, Shape, TextStyle, Ui,
+};
+
+use crate::{Cursor, LabelFormatter, PlotBounds, PlotPoint, PlotTransform};
+
+use super::{
+    step_decimals, ClosestElem, PlotConfig, PlotGeometry, PlotItem, PlotPoints, DEFAULT_FILL_ALPHA,
+};
+
+/// A filled area between an upper and a lower series, e.g. a confidence interval, Bollinger bands
+/// or an Ichimoku cloud.
+///
+/// The two series are paired by index, so they should have the same X values. Where the series
+/// cross, the fill switches to [`Self::crossed_color`]. The area doesn't need to be convex.
+///
+/// ```
+/// # use egui_plot::{Band, PlotPoints};
+/// let span_a = PlotPoints::from_ys_f64(&[1.0, 2.0, 3.0, 2.0]);
+/// let span_b = PlotPoints::from_ys_f64(&[2.0, 1.5, 1.0, 2.5]);
+/// let cloud = Band::new(span_a, span_b)
+///     .name("cloud")
+///     .color(egui::Color32::GREEN)
+///     .crossed_color(egui::Color32::RED);
+/// ```
+pub struct Band {
+    pub(crate) upper: PlotPoints,
+    pub(crate) lower: PlotPoints,
+    pub(crate) fill: Color32,
+    pub(super) crossed_fill: Option<Color32>,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    id: Option<Id>,
+    y_axis: Option<Id>,
+}
+
+impl Band {
+    pub fn new(upper: impl Into<PlotPoints>, lower: impl Into<PlotPoints>) -> Self {
+        Self {
+            upper: upper.into(),
+            lower: lower.into(),
+            fill: Color32::TRANSPARENT,
+            crossed_fill: None,
+            name: Default::default(),
+            highlight: false,
+            id: None,
+            y_axis: None,
+        }
+    }
+
+    /// Highlight this band in the plot by reducing the fill transparency.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Base color of the band where the upper series is above the lower one. The fill is this
+    /// color with added transparency.
+    ///
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.fill = color.into();
+        self
+    }
+
+    /// Base color of the band where the series have crossed, i.e. the lower series is above the
+    /// upper one.
+    ///
+    /// Default is the same as [`Self::color`].
+    #[inline]
+    pub fn crossed_color(mut self, color: impl Into<Color32>) -> Self {
+        self.crossed_fill = Some(color.into());
+        self
+    }
+
+    /// Name of this band.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the band's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
+
+    fn pairs(&self) -> impl Iterator<Item = (&PlotPoint, &PlotPoint)> {
+        self.upper.points().iter().zip(self.lower.points())
+    }
+
+    /// The hover label without a custom [`LabelFormatter`].
+    fn default_label(&self, upper: PlotPoint, lower: PlotPoint, plot: &PlotConfig<'_>) -> String {
+        let scale = plot.transform.dvalue_dpos_at(&upper);
+        let mut text = self.name.clone();
+        if plot.show_x {
+            let x_text = plot.format_x(upper.x, step_decimals(scale[0]).at_least(1));
+            text.push_str(&format!("\nx = {x_text}"));
+        }
+        if plot.show_y {
+            let decimals = step_decimals(scale[1]).at_least(1);
+            text.push_str(&format!(
+                "\nupper = {}\nlower = {}",
+                plot.format_y(upper.y, decimals),
+                plot.format_y(lower.y, decimals)
+            ));
+        }
+        text
+    }
+}
+
+impl PlotItem for Band {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let alpha = if self.highlight {
+            2.0 * DEFAULT_FILL_ALPHA
+        } else {
+            DEFAULT_FILL_ALPHA
+        };
+        let fill = self.fill.linear_multiply(alpha);
+        let crossed_fill = self
+            .crossed_fill
+            .unwrap_or(self.fill)
+            .linear_multiply(alpha);
+        let fill_for = |gap: f64| if gap < 0.0 { crossed_fill } else { fill };
+
+        // Two triangles between each pair of neighboring points, so the area doesn't need to be
+        // convex. Vertices aren't shared between quads, so each quad has a single color.
+        let mut mesh = Mesh::default();
+        let mut triangle = |points: [Pos2; 3], color: Color32| {
+            let index = mesh.vertices.len() as u32;
+            for pos in points {
+                mesh.colored_vertex(pos, color);
+            }
+            mesh.add_triangle(index, index + 1, index + 2);
+        };
+
+        let pairs: Vec<_> = self.pairs().collect();
+        for window in pairs.windows(2) {
+            let [(upper_0, lower_0), (upper_1, lower_1)] = [window[0], window[1]];
+            if ![upper_0, lower_0, upper_1, lower_1]
+                .iter()
+                .all(|p| p.x.is_finite() && p.y.is_finite())
+            {
+                continue;
+            }
+            let gap_0 = upper_0.y - lower_0.y;
+            let gap_1 = upper_1.y - lower_1.y;
+            let [u0, l0, u1, l1] = [upper_0, lower_0, upper_1, lower_1]
+                .map(|point| transform.position_from_point(point));
+
+            if gap_0 * gap_1 < 0.0 {
+                // Split the quad where the series cross.
+                let t = (gap_0 / (gap_0 - gap_1)) as f32;
+                let crossing = u0.lerp(u1, t).lerp(l0.lerp(l1, t), 0.5);
+                triangle([u0, l0, crossing], fill_for(gap_0));
+                triangle([crossing, u1, l1], fill_for(gap_1));
+            } else {
+                let color = fill_for(if gap_0 == 0.0 { gap_1 } else { gap_0 });
+                triangle([u0, l0, u1], color);
+                triangle([l0, u1, l1], color);
+            }
+        }
+        shapes.push(Shape::mesh(mesh));
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        self.upper.generate_points(x_range.clone());
+        self.lower.generate_points(x_range);
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.fill
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = self.upper.bounds();
+        bounds.merge(&self.lower.bounds());
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.pairs()
+            .enumerate()
+            .map(|(index, (upper, lower))| {
+                let dist_sq = point
+                    .distance_sq(transform.position_from_point(upper))
+                    .min(point.distance_sq(transform.position_from_point(lower)));
+                ClosestElem { index, dist_sq }
+            })
+            .min_by_key(|e| e.dist_sq.ord())
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        let upper = self.upper.points()[elem.index];
+        let lower = self.lower.points()[elem.index];
+        let line_color = super::rulers_color(plot.ui);
+        let upper_pos = plot.transform.position_from_point(&upper);
+        let lower_pos = plot.transform.position_from_point(&lower);
+        shapes.push(Shape::circle_filled(upper_pos, 3.0, line_color));
+        shapes.push(Shape::circle_filled(lower_pos, 3.0, line_color));
+
+        if plot.show_x {
+            cursors.push(Cursor::Vertical { x: upper.x });
+        }
+        if plot.show_y {
+            cursors.push(Cursor::Horizontal { y: upper.y });
+            cursors.push(Cursor::Horizontal { y: lower.y });
+        }
+
+        let text = match (
+            plot.custom_label(label_formatter, &self.name, upper),
+            plot.custom_label(label_formatter, &self.name, lower),
+        ) {
+            (Some(upper), Some(lower)) => format!("{upper}\n{lower}"),
+            _ => self.default_label(upper, lower, plot),
+        };
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        let top = if upper_pos.y <= lower_pos.y {
+            upper_pos
+        } else {
+            lower_pos
+        };
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                top + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text.trim_start_matches('\n'),
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+
+    fn snap_value(&self, elem: &ClosestElem, pointer: PlotPoint) -> Option<PlotPoint> {
+        let (upper, lower) = self.pairs().nth(elem.index)?;
+        let nearest = if (upper.y - pointer.y).abs() <= (lower.y - pointer.y).abs() {
+            upper
+        } else {
+            lower
+        };
+        Some(*nearest)
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::Rect;
+
+    use super::*;
+
+    fn band() -> Band {
+        Band::new(
+            PlotPoints::new(vec![[0.0, 2.0], [1.0, 3.0], [2.0, 1.0]]),
+            PlotPoints::new(vec![[0.0, -1.0], [1.0, 0.5], [2.0, 2.0]]),
+        )
+    }
+
+    #[test]
+    fn bounds_cover_both_series() {
+        let bounds = band().bounds();
+        assert_eq!(bounds.min(), [0.0, -1.0]);
+        assert_eq!(bounds.max(), [2.0, 3.0]);
+    }
+
+    #[test]
+    fn find_closest_reaches_both_edges() {
+        let frame = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+        let bounds = PlotBounds::from_min_max([0.0, -1.0], [2.0, 3.0]);
+        let transform = PlotTransform::new(frame, bounds, false, false);
+        let band = band();
+
+        let upper = transform.position_from_point(&PlotPoint::new(1.0, 3.0));
+        let closest = band
+            .find_closest(upper + vec2(1.0, 0.0), &transform)
+            .unwrap();
+        assert_eq!(closest.index, 1);
+        assert_eq!(closest.dist_sq, 1.0);
+
+        let lower = transform.position_from_point(&PlotPoint::new(0.0, -1.0));
+        let closest = band
+            .find_closest(lower + vec2(0.0, 2.0), &transform)
+            .unwrap();
+        assert_eq!(closest.index, 0);
+        assert_eq!(closest.dist_sq, 4.0);
+
+        // Crossed: at the last pair the lower series is above the upper one.
+        let lower = transform.position_from_point(&PlotPoint::new(2.0, 2.0));
+        assert_eq!(band.find_closest(lower, &transform).unwrap().index, 2);
+        assert_eq!(
+            band.snap_value(
+                &ClosestElem {
+                    index: 2,
+                    dist_sq: 0.0
+                },
+                PlotPoint::new(2.0, 1.9)
+            ),
+            Some(PlotPoint::new(2.0, 2.0))
+        );
+    }
+}
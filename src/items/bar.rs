@@ -121,6 +121,11 @@ impl Bar {
         }
     }
 
+    /// The end of the bar away from its base.
+    pub(super) fn tip(&self) -> PlotPoint {
+        self.point_at(self.argument, self.base_offset.unwrap_or(0.0) + self.value)
+    }
+
     pub(super) fn add_shapes(
         &self,
         transform: &PlotTransform,
@@ -185,8 +190,8 @@ impl RectElement for Bar {
         self.orientation
     }
 
-    fn default_values_format(&self, transform: &PlotTransform) -> String {
-        let scale = transform.dvalue_dpos();
+    fn default_values_format(&self, plot: &PlotConfig<'_>) -> String {
+        let scale = plot.transform.dvalue_dpos();
         let scale = match self.orientation {
             Orientation::Horizontal => scale[0],
             Orientation::Vertical => scale[1],
@@ -270,8 +270,8 @@ impl RectElement for BoxElem {
         self.point_at(self.argument, self.spread.upper_whisker)
     }
 
-    fn default_values_format(&self, transform: &PlotTransform) -> String {
-        let scale = transform.dvalue_dpos();
+    fn default_values_format(&self, plot: &PlotConfig<'_>) -> String {
+        let scale = plot.transform.dvalue_dpos();
         let scale = match self.orientation {
             Orientation::Horizontal => scale[0],
             Orientation::Vertical => scale[1],
@@ -0,0 +1,232 @@
+use egui::emath::NumExt as _;
+use egui::epaint::{util::FloatOrd as _, Color32, RectShape, Rounding, Shape, Stroke};
+
+use crate::{Candlestick, Cursor, PlotPoint, PlotTransform};
+
+use super::{
+    add_rulers_and_text, highlighted_color, step_decimals, Orientation, PlotConfig, RectElement,
+};
+
+/// How the elements of a [`Candlestick`] series are drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CandleStyle {
+    /// Japanese candlesticks: a body between open and close plus a wick from low to high.
+    #[default]
+    Candles,
+
+    /// OHLC bars: a vertical line from low to high with a tick to the left at the open
+    /// and a tick to the right at the close.
+    OhlcBars,
+}
+
+/// One candle (price bar) in a [`Candlestick`] series.
+///
+/// This is a low level graphical element; colors and drawing style are set by the parent series.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Candle {
+    /// Name of plot element in the diagram (annotated by default formatter).
+    pub name: String,
+
+    /// Position on the X axis, typically the opening time of the bar.
+    pub time: f64,
+
+    /// Opening price.
+    pub open: f64,
+
+    /// Highest price.
+    pub high: f64,
+
+    /// Lowest price.
+    pub low: f64,
+
+    /// Closing price.
+    pub close: f64,
+
+    /// Width of the body (or the ticks of an OHLC bar) in plot units.
+    pub width: f64,
+}
+
+impl Candle {
+    /// Create a candle at `time` with the given prices.
+    pub fn new(time: f64, open: f64, high: f64, low: f64, close: f64) -> Self {
+        Self {
+            name: String::default(),
+            time,
+            open,
+            high,
+            low,
+            close,
+            width: 0.6,
+        }
+    }
+
+    /// Name of this candle.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the width of the body in plot units.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Whether the price closed at or above the open.
+    #[inline]
+    pub fn is_rising(&self) -> bool {
+        self.close >= self.open
+    }
+
+    pub(super) fn add_shapes(
+        &self,
+        parent: &Candlestick,
+        transform: &PlotTransform,
+        highlighted: bool,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let rising = self.is_rising();
+        let color = if rising {
+            parent.up_color
+        } else {
+            parent.down_color
+        };
+        let hollow = rising && parent.hollow;
+
+        let body_stroke = Stroke::new(1.0, color);
+        let body_fill = if hollow { Color32::TRANSPARENT } else { color };
+        let (body_stroke, body_fill) = if highlighted {
+            highlighted_color(body_stroke, body_fill)
+        } else {
+            (body_stroke, body_fill)
+        };
+        let mut wick_stroke = Stroke::new(parent.wick_width, color);
+        if highlighted {
+            wick_stroke.width *= 2.0;
+        }
+
+        let line_between = |v1: PlotPoint, v2: PlotPoint| {
+            Shape::line_segment(
+                [
+                    transform.position_from_point(&v1),
+                    transform.position_from_point(&v2),
+                ],
+                wick_stroke,
+            )
+        };
+        let half_width = self.width / 2.0;
+
+        match parent.style {
+            CandleStyle::Candles => {
+                let body_top = self.open.max(self.close);
+                let body_bottom = self.open.min(self.close);
+
+                if self.high > body_top {
+                    shapes.push(line_between(
+                        PlotPoint::new(self.time, body_top),
+                        PlotPoint::new(self.time, self.high),
+                    ));
+                }
+                if self.low < body_bottom {
+                    shapes.push(line_between(
+                        PlotPoint::new(self.time, body_bottom),
+                        PlotPoint::new(self.time, self.low),
+                    ));
+                }
+
+                let rect = transform.rect_from_values(
+                    &PlotPoint::new(self.time - half_width, body_bottom),
+                    &PlotPoint::new(self.time + half_width, body_top),
+                );
+                shapes.push(Shape::Rect(RectShape::new(
+                    rect,
+                    Rounding::ZERO,
+                    body_fill,
+                    body_stroke,
+                )));
+            }
+            CandleStyle::OhlcBars => {
+                shapes.push(line_between(
+                    PlotPoint::new(self.time, self.low),
+                    PlotPoint::new(self.time, self.high),
+                ));
+                shapes.push(line_between(
+                    PlotPoint::new(self.time - half_width, self.open),
+                    PlotPoint::new(self.time, self.open),
+                ));
+                shapes.push(line_between(
+                    PlotPoint::new(self.time, self.close),
+                    PlotPoint::new(self.time + half_width, self.close),
+                ));
+            }
+        }
+    }
+
+    /// The open, high, low or close price, whichever is nearest to `price`.
+    pub(super) fn nearest_price(&self, price: f64) -> f64 {
+        [self.open, self.high, self.low, self.close]
+            .into_iter()
+            .min_by_key(|value| (value - price).abs().ord())
+            .unwrap_or(self.close)
+    }
+
+    pub(super) fn add_rulers_and_text(
+        &self,
+        parent: &Candlestick,
+        plot: &PlotConfig<'_>,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+    ) {
+        let text: Option<String> = parent
+            .element_formatter
+            .as_ref()
+            .map(|fmt| fmt(self, parent));
+
+        add_rulers_and_text(self, plot, text, shapes, cursors);
+    }
+}
+
+impl RectElement for Candle {
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn bounds_min(&self) -> PlotPoint {
+        PlotPoint::new(self.time - self.width / 2.0, self.low)
+    }
+
+    fn bounds_max(&self) -> PlotPoint {
+        PlotPoint::new(self.time + self.width / 2.0, self.high)
+    }
+
+    fn arguments_with_ruler(&self) -> Vec<PlotPoint> {
+        vec![PlotPoint::new(self.time, self.close)]
+    }
+
+    fn values_with_ruler(&self) -> Vec<PlotPoint> {
+        vec![PlotPoint::new(self.time, self.close)]
+    }
+
+    fn orientation(&self) -> Orientation {
+        Orientation::Vertical
+    }
+
+    fn corner_value(&self) -> PlotPoint {
+        PlotPoint::new(self.time + self.width / 2.0, self.high)
+    }
+
+    fn default_values_format(&self, plot: &PlotConfig<'_>) -> String {
+        let scale = plot.transform.dvalue_dpos()[1];
+        let decimals = step_decimals(scale).at_least(1);
+        format!(
+            "Open = {open}\nHigh = {high}\nLow = {low}\nClose = {close}",
+            open = plot.format_y(self.open, decimals),
+            high = plot.format_y(self.high, decimals),
+            low = plot.format_y(self.low, decimals),
+            close = plot.format_y(self.close, decimals),
+        )
+    }
+}
@@ -0,0 +1,234 @@
+use std::ops::RangeInclusive;
+
+use egui::{emath::NumExt as _, vec2, Align2, Color32, Id, Pos2, Shape, TextStyle, Ui};
+
+use crate::{Cursor, LabelFormatter, PlotBounds, PlotPoint, PlotTransform};
+
+use super::{step_decimals, ClosestElem, Line, PlotConfig, PlotGeometry, PlotItem};
+
+/// Several series of very different magnitudes overlaid on one plot, each as the change in
+/// percent from its value at a common anchor X.
+///
+/// Add it with [`crate::PlotUi::comparison`]. Each series gets its own legend entry and, unless
+/// its line has a color, the next color of the plot's palette. The hover label shows the original
+/// value next to the change in percent.
+///
+/// The anchor is the left edge of the view unless set with [`Self::anchor`], so the series are
+/// normalized again as the user pans. Generated series (e.g.
+/// [`crate::PlotPoints::from_explicit_callback`]) can't be normalized and are skipped. Series
+/// are drawn from their own points, so [`crate::IndexedPoints`] and streaming series keep their
+/// fast hit-testing.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Comparison, Line, Plot, PlotPoints};
+///
+/// let spx = PlotPoints::from_ys_f64(&[4800.0, 4850.0, 4790.0, 4900.0]);
+/// let btc = PlotPoints::from_ys_f64(&[42_000.0, 44_500.0, 43_000.0, 47_000.0]);
+/// Plot::new("compare")
+///     .y_axis_formatter(|mark, _, _| format!("{:+}%", mark.value))
+///     .show(ui, |plot_ui| {
+///         plot_ui.comparison(
+///             Comparison::new()
+///                 .series(Line::new(spx).name("SPX"))
+///                 .series(Line::new(btc).name("BTC")),
+///         );
+///     });
+/// # });
+/// ```
+#[derive(Default)]
+pub struct Comparison {
+    pub(crate) series: Vec<Line>,
+    pub(crate) anchor: Option<f64>,
+}
+
+impl Comparison {
+    /// An empty comparison. Add series with [`Self::series`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a series. Its name, color and style are those of the line.
+    #[inline]
+    pub fn series(mut self, line: Line) -> Self {
+        self.series.push(line);
+        self
+    }
+
+    /// Normalize the series to their values at this X instead of at the left edge of the view.
+    ///
+    /// The value of a series at `x` is that of its first point at or after `x`, or of its last
+    /// point if there is none.
+    #[inline]
+    pub fn anchor(mut self, x: f64) -> Self {
+        self.anchor = Some(x);
+        self
+    }
+}
+
+/// A series of a [`Comparison`], as the change in percent from its reference value.
+pub(crate) struct ComparedLine {
+    line: Line,
+    reference: f64,
+}
+
+impl ComparedLine {
+    /// Normalize `line` to its value at `anchor`. `None` if that value is zero or missing.
+    pub(crate) fn new(line: Line, anchor: f64) -> Option<Self> {
+        let points = line.series.points();
+        let finite = || points.iter().filter(|point| point.y.is_finite());
+        let reference = finite()
+            .find(|point| point.x >= anchor)
+            .or_else(|| finite().next_back())?
+            .y;
+        if reference == 0.0 {
+            return None;
+        }
+        Some(Self { line, reference })
+    }
+
+    /// The change in percent of an original value.
+    fn normalized(&self, value: f64) -> f64 {
+        100.0 * (value - self.reference) / self.reference.abs()
+    }
+
+    fn normalized_point(&self, point: PlotPoint) -> PlotPoint {
+        PlotPoint::new(point.x, self.normalized(point.y))
+    }
+
+    /// The original value of a normalized one.
+    fn original(&self, percent: f64) -> f64 {
+        self.reference + percent * self.reference.abs() / 100.0
+    }
+
+    /// `transform` with Y bounds in original values, to draw and hit-test the line as it is.
+    ///
+    /// Normalizing is linear, so this places every point where its normalized value goes.
+    fn original_transform(&self, transform: &PlotTransform) -> PlotTransform {
+        let mut bounds = *transform.bounds();
+        bounds.min[1] = self.original(bounds.min[1]);
+        bounds.max[1] = self.original(bounds.max[1]);
+        let mut original = *transform;
+        original.set_bounds(bounds);
+        original
+    }
+
+    /// The hover label without a custom [`LabelFormatter`].
+    fn default_label(&self, value: PlotPoint, plot: &PlotConfig<'_>) -> String {
+        let scale = plot.transform.dvalue_dpos_at(&value);
+        let mut text = self.line.name.clone();
+        if plot.show_x {
+            let x_text = plot.format_x(value.x, step_decimals(scale[0]).at_least(1));
+            text.push_str(&format!("\nx = {x_text}"));
+        }
+        if plot.show_y {
+            // Decimals for the original value, whose scale differs from the plot's.
+            let decimals = step_decimals(scale[1] * self.reference.abs() / 100.0).at_least(1);
+            text.push_str(&format!(
+                "\nvalue = {:.*} ({:+.2}%)",
+                decimals,
+                self.original(value.y),
+                value.y
+            ));
+        }
+        text
+    }
+}
+
+impl PlotItem for ComparedLine {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        PlotItem::shapes(&self.line, ui, &self.original_transform(transform), shapes);
+    }
+
+    fn initialize(&mut self, x_range: RangeInclusive<f64>) {
+        PlotItem::initialize(&mut self.line, x_range);
+    }
+
+    fn name(&self) -> &str {
+        PlotItem::name(&self.line)
+    }
+
+    fn color(&self) -> Color32 {
+        PlotItem::color(&self.line)
+    }
+
+    fn highlight(&mut self) {
+        PlotItem::highlight(&mut self.line);
+    }
+
+    fn highlighted(&self) -> bool {
+        PlotItem::highlighted(&self.line)
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotItem::geometry(&self.line)
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotItem::bounds(&self.line);
+        bounds.min[1] = self.normalized(bounds.min[1]);
+        bounds.max[1] = self.normalized(bounds.max[1]);
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        PlotItem::find_closest(&self.line, point, &self.original_transform(transform))
+    }
+
+    fn first_value(&self, x_range: &RangeInclusive<f64>) -> Option<PlotPoint> {
+        PlotItem::first_value(&self.line, x_range).map(|point| self.normalized_point(point))
+    }
+
+    fn snap_value(&self, elem: &ClosestElem, _: PlotPoint) -> Option<PlotPoint> {
+        let point = *self.line.series.points().get(elem.index)?;
+        Some(self.normalized_point(point))
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        let value = self.normalized_point(self.line.series.points()[elem.index]);
+        let pointer = plot.transform.position_from_point(&value);
+        shapes.push(Shape::circle_filled(
+            pointer,
+            3.0,
+            super::rulers_color(plot.ui),
+        ));
+
+        if plot.show_x {
+            cursors.push(Cursor::Vertical { x: value.x });
+        }
+        if plot.show_y {
+            cursors.push(Cursor::Horizontal { y: value.y });
+        }
+
+        let text = plot
+            .custom_label(label_formatter, &self.line.name, value)
+            .unwrap_or_else(|| self.default_label(value, plot));
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                pointer + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text.trim_start_matches('\n'),
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+
+    fn id(&self) -> Option<Id> {
+        PlotItem::id(&self.line)
+    }
+
+    fn y_axis(&self) -> Option<Id> {
+        PlotItem::y_axis(&self.line)
+    }
+}
@@ -0,0 +1,840 @@
+use std::ops::RangeInclusive;
+
+use egui::{
+    ahash::HashMap, epaint::util::FloatOrd as _, epaint::Mesh, vec2, Align2, Color32, Id, Pos2,
+    Rect, Shape, Stroke, TextStyle, Ui,
+};
+
+use crate::{Colormap, Cursor, LabelFormatter, LineStyle, PlotBounds, PlotPoint, PlotTransform};
+
+use super::{step_decimals, ClosestElem, Grid, PlotConfig, PlotGeometry, PlotItem};
+
+/// Alpha of the fill of the highest band between levels, see [`Contour::fill`]. Lower bands are
+/// more transparent.
+const FILL_ALPHA: f32 = 0.5;
+
+/// Polylines shorter than this, in ui points, get no label, see [`Contour::labels`].
+const MIN_LABELED_LENGTH: f32 = 60.0;
+
+/// Lines of equal value (isolines) of a [`Grid`], computed with marching squares.
+///
+/// The values are sampled at the centers of the grid's cells, so the lines match a
+/// [`crate::Heatmap`] of the same grid. Cells with a missing value at a corner get no lines.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Contour, Grid, Plot};
+///
+/// let pnl = Grid::from_fn(40, 40, |row, col| {
+///     let (x, y) = (col as f64 / 20.0 - 1.0, row as f64 / 20.0 - 1.0);
+///     100.0 * (1.0 - x * x - y * y)
+/// })
+/// .x_range(10.0..=50.0)
+/// .y_range(0.5..=2.5);
+/// Plot::new("pnl").show(ui, |plot_ui| {
+///     plot_ui.contour(Contour::new(pnl).level_count(5).labels(true).fill(true));
+/// });
+/// # });
+/// ```
+pub struct Contour {
+    pub(crate) grid: Grid,
+    pub(super) levels: Option<Vec<f64>>,
+    pub(super) level_count: usize,
+    pub(crate) stroke: Stroke,
+    pub(super) colormap: Option<Colormap>,
+    pub(super) style: LineStyle,
+    pub(super) fill: bool,
+    pub(super) labels: bool,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    id: Option<Id>,
+    y_axis: Option<Id>,
+
+    /// The range of the grid's values, the levels to draw, lowest first, and their isolines,
+    /// computed once per frame in [`PlotItem::initialize`].
+    value_range: Option<RangeInclusive<f64>>,
+    level_values: Vec<f64>,
+    lines: Vec<Isoline>,
+}
+
+/// A polyline of one level.
+struct Isoline {
+    level: usize,
+    points: Vec<PlotPoint>,
+}
+
+impl Contour {
+    pub fn new(grid: Grid) -> Self {
+        Self {
+            grid,
+            levels: None,
+            level_count: 8,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            colormap: None,
+            style: LineStyle::Solid,
+            fill: false,
+            labels: false,
+            name: Default::default(),
+            highlight: false,
+            id: None,
+            y_axis: None,
+            value_range: None,
+            level_values: Vec::new(),
+            lines: Vec::new(),
+        }
+    }
+
+    /// Draw the lines at these values instead of at automatic levels.
+    #[inline]
+    pub fn levels(mut self, levels: impl Into<Vec<f64>>) -> Self {
+        self.levels = Some(levels.into());
+        self
+    }
+
+    /// About how many automatic levels to draw. They are round values between the smallest and
+    /// the largest value of the grid. Default: `8`.
+    #[inline]
+    pub fn level_count(mut self, count: usize) -> Self {
+        self.level_count = count;
+        self
+    }
+
+    /// Color each level by its value instead of with [`Self::color`].
+    #[inline]
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = Some(colormap);
+        self
+    }
+
+    /// Shade the bands between levels. Default: `false`.
+    ///
+    /// With a [`Self::colormap`], each band has the color of its middle value. Otherwise it is a
+    /// shade of [`Self::color`] that is stronger for higher values.
+    #[inline]
+    pub fn fill(mut self, fill: bool) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Label the lines with their values. Default: `false`.
+    #[inline]
+    pub fn labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Highlight the lines in the plot by scaling them up.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Set the lines' style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Name of this item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the item's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
+
+    /// The levels to draw, lowest first.
+    fn compute_levels(&self) -> Vec<f64> {
+        let mut levels = match &self.levels {
+            Some(levels) => levels.clone(),
+            None => self
+                .value_range
+                .as_ref()
+                .map(|range| auto_levels(range, self.level_count))
+                .unwrap_or_default(),
+        };
+        levels.retain(|level| level.is_finite());
+        levels.sort_by_key(|level| level.ord());
+        levels.dedup();
+        levels
+    }
+
+    /// The range of values the colormap spans.
+    fn color_range(&self) -> RangeInclusive<f64> {
+        match (&self.value_range, &self.level_values[..]) {
+            (Some(range), _) => range.clone(),
+            (None, [first, .., last]) => *first..=*last,
+            _ => 0.0..=1.0,
+        }
+    }
+
+    fn level_color(&self, level: f64, range: &RangeInclusive<f64>) -> Color32 {
+        match &self.colormap {
+            Some(colormap) => colormap.color_in(level, range),
+            None => self.stroke.color,
+        }
+    }
+
+    /// Decimals to label the levels with.
+    fn level_decimals(levels: &[f64]) -> usize {
+        let step = levels
+            .windows(2)
+            .map(|pair| pair[1] - pair[0])
+            .min_by_key(|step| step.ord())
+            .unwrap_or(1.0);
+        step_decimals(step)
+    }
+
+    /// The line of a point index counting the points of all lines, and the index within it.
+    fn line_at(&self, mut index: usize) -> Option<(&Isoline, usize)> {
+        for line in &self.lines {
+            if index < line.points.len() {
+                return Some((line, index));
+            }
+            index -= line.points.len();
+        }
+        None
+    }
+
+    /// Shade the bands between the levels, two triangles per cell.
+    fn fill_shapes(&self, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let (grid, levels) = (&self.grid, &self.level_values[..]);
+        let Some(value_range) = &self.value_range else {
+            return;
+        };
+        let color_range = self.color_range();
+        let band_count = levels.len() + 1;
+        let band_colors: Vec<Color32> = (0..band_count)
+            .map(|band| {
+                let low = if band == 0 {
+                    *value_range.start()
+                } else {
+                    levels[band - 1]
+                };
+                let high = levels.get(band).copied().unwrap_or(*value_range.end());
+                match &self.colormap {
+                    Some(colormap) => colormap.color_in((low + high) / 2.0, &color_range),
+                    None => self
+                        .stroke
+                        .color
+                        .linear_multiply(FILL_ALPHA * (band + 1) as f32 / band_count as f32),
+                }
+            })
+            .collect();
+
+        let mut mesh = Mesh::default();
+        let bounds = transform.bounds();
+        let (rows, cols) = grid.cells_in(
+            bounds.min()[0]..=bounds.max()[0],
+            bounds.min()[1]..=bounds.max()[1],
+        );
+        let node = |row: usize, col: usize| {
+            let value = grid.values[row * grid.cols + col];
+            (
+                transform.position_from_point(&grid.cell_center(row, col)),
+                value,
+            )
+        };
+        for row in rows.start.saturating_sub(1)..rows.end.min(grid.rows() - 1) {
+            for col in cols.start.saturating_sub(1)..cols.end.min(grid.cols - 1) {
+                let [bl, br, tr, tl] = [
+                    node(row, col),
+                    node(row, col + 1),
+                    node(row + 1, col + 1),
+                    node(row + 1, col),
+                ];
+                if ![bl, br, tr, tl].iter().all(|(_, value)| value.is_finite()) {
+                    continue;
+                }
+                for triangle in [[bl, br, tr], [bl, tr, tl]] {
+                    fill_triangle(&mut mesh, triangle, levels, &band_colors);
+                }
+            }
+        }
+        shapes.push(Shape::mesh(mesh));
+    }
+}
+
+impl PlotItem for Contour {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.fill {
+            self.fill_shapes(transform, shapes);
+        }
+
+        let color_range = self.color_range();
+        let decimals = Self::level_decimals(&self.level_values);
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let mut labels = Vec::new();
+        for line in &self.lines {
+            let level = self.level_values[line.level];
+            let color = self.level_color(level, &color_range);
+            let points: Vec<Pos2> = line
+                .points
+                .iter()
+                .map(|point| transform.position_from_point(point))
+                .collect();
+            if self.labels {
+                if let Some(pos) = label_position(&points) {
+                    labels.push((pos, format!("{level:.decimals$}"), color));
+                }
+            }
+            self.style.style_line(
+                points,
+                Stroke::new(self.stroke.width, color),
+                self.highlight,
+                shapes,
+            );
+        }
+
+        // Labels on top of the lines, on the plot's background.
+        let background = ui.visuals().extreme_bg_color;
+        for (pos, text, color) in labels {
+            ui.fonts(|f| {
+                let galley = f.layout_no_wrap(text, font_id.clone(), color);
+                let rect =
+                    Align2::CENTER_CENTER.anchor_rect(Rect::from_min_size(pos, galley.size()));
+                shapes.push(Shape::rect_filled(rect.expand(1.0), 2.0, background));
+                shapes.push(Shape::galley(rect.min, galley, color));
+            });
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        self.value_range = self.grid.value_range();
+        self.level_values = self.compute_levels();
+        self.lines = isolines(&self.grid, &self.level_values)
+            .into_iter()
+            .enumerate()
+            .flat_map(|(level, lines)| {
+                lines
+                    .into_iter()
+                    .map(move |points| Isoline { level, points })
+            })
+            .collect();
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        match &self.colormap {
+            Some(colormap) => colormap.color(0.5),
+            None => self.stroke.color,
+        }
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        if !self.grid.is_empty() {
+            let (x, y) = (self.grid.x_extent(), self.grid.y_extent());
+            bounds.extend_with(&PlotPoint::new(*x.start(), *y.start()));
+            bounds.extend_with(&PlotPoint::new(*x.end(), *y.end()));
+        }
+        bounds
+    }
+
+    /// The index of the closest element is that of the point starting the closest segment,
+    /// counting the points of all lines.
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let mut offset = 0;
+        let mut closest: Option<ClosestElem> = None;
+        for line in &self.lines {
+            let points: Vec<Pos2> = line
+                .points
+                .iter()
+                .map(|point| transform.position_from_point(point))
+                .collect();
+            for (index, segment) in points.windows(2).enumerate() {
+                let dist_sq = distance_sq_to_segment(point, segment[0], segment[1]);
+                if closest
+                    .as_ref()
+                    .is_none_or(|closest| dist_sq < closest.dist_sq)
+                {
+                    closest = Some(ClosestElem {
+                        index: offset + index,
+                        dist_sq,
+                    });
+                }
+            }
+            offset += line.points.len();
+        }
+        closest
+    }
+
+    fn snap_value(&self, elem: &ClosestElem, _: PlotPoint) -> Option<PlotPoint> {
+        self.line_at(elem.index)
+            .map(|(line, index)| line.points[index])
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        _cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        let Some((line, index)) = self.line_at(elem.index) else {
+            return;
+        };
+        let level = self.level_values[line.level];
+        let color = self.level_color(level, &self.color_range());
+        let points: Vec<Pos2> = line
+            .points
+            .iter()
+            .map(|point| plot.transform.position_from_point(point))
+            .collect();
+        let pos = points[index];
+        LineStyle::Solid.style_line(points, Stroke::new(self.stroke.width, color), true, shapes);
+
+        let mut text = plot
+            .custom_label(label_formatter, &self.name, line.points[index])
+            .unwrap_or_else(|| self.name.clone());
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&format!(
+            "level = {:.*}",
+            Self::level_decimals(&self.level_values),
+            level
+        ));
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                pos + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
+}
+
+/// About `count` round values strictly between the ends of `range`.
+fn auto_levels(range: &RangeInclusive<f64>, count: usize) -> Vec<f64> {
+    let span = range.end() - range.start();
+    if count == 0 || span <= 0.0 {
+        return Vec::new();
+    }
+    let rough_step = span / count as f64;
+    let magnitude = 10f64.powf(rough_step.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .min_by_key(|step| (step / rough_step).ln().abs().ord())
+        .unwrap_or(magnitude);
+    let first = (range.start() / step).floor() as i64 + 1;
+    (first..)
+        .map(|i| i as f64 * step)
+        .take_while(|level| level < range.end())
+        .collect()
+}
+
+/// An edge between two neighboring grid nodes: the row and column of its lower left node and
+/// whether it is horizontal.
+type Edge = (usize, usize, bool);
+
+/// The polylines where the values of `grid` cross each of the sorted `levels`, found with
+/// marching squares in one pass over the cells.
+///
+/// Closed lines end with their first point.
+fn isolines(grid: &Grid, levels: &[f64]) -> Vec<Vec<Vec<PlotPoint>>> {
+    let (rows, cols) = (grid.rows(), grid.cols);
+    if rows < 2 || cols < 2 {
+        return vec![Vec::new(); levels.len()];
+    }
+    let value = |row: usize, col: usize| grid.values[row * cols + col];
+
+    // Segments between the crossed edges of each cell, per level.
+    let mut segments: Vec<Vec<[Edge; 2]>> = vec![Vec::new(); levels.len()];
+    for row in 0..rows - 1 {
+        for col in 0..cols - 1 {
+            let corners = [
+                value(row, col),
+                value(row, col + 1),
+                value(row + 1, col + 1),
+                value(row + 1, col),
+            ];
+            if !corners.iter().all(|value| value.is_finite()) {
+                continue;
+            }
+            let (min, max) = corners
+                .iter()
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                    (min.min(*value), max.max(*value))
+                });
+
+            // Bottom, right, top and left edge, each from corner `i` to corner `i + 1`.
+            let edges = [
+                (row, col, true),
+                (row, col + 1, false),
+                (row + 1, col, true),
+                (row, col, false),
+            ];
+            // Only the levels in `(min, max]` cross this cell.
+            let crossing_levels =
+                levels.partition_point(|&l| l <= min)..levels.partition_point(|&l| l <= max);
+            for index in crossing_levels {
+                let level = levels[index];
+                let above = corners.map(|value| value >= level);
+                let crossed: Vec<Edge> = (0..4)
+                    .filter(|&i| above[i] != above[(i + 1) % 4])
+                    .map(|i| edges[i])
+                    .collect();
+                match crossed[..] {
+                    [a, b] => segments[index].push([a, b]),
+                    [bottom, right, top, left] => {
+                        // A saddle: the center decides which corners are connected.
+                        let center = corners.iter().sum::<f64>() / 4.0;
+                        if (center >= level) == above[0] {
+                            segments[index].push([bottom, right]);
+                            segments[index].push([top, left]);
+                        } else {
+                            segments[index].push([left, bottom]);
+                            segments[index].push([right, top]);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    levels
+        .iter()
+        .zip(segments)
+        .map(|(&level, segments)| {
+            let crossing = |(row, col, horizontal): Edge| {
+                let (end_row, end_col) = if horizontal {
+                    (row, col + 1)
+                } else {
+                    (row + 1, col)
+                };
+                let (a, b) = (value(row, col), value(end_row, end_col));
+                let t = ((level - a) / (b - a)).clamp(0.0, 1.0);
+                let (pa, pb) = (
+                    grid.cell_center(row, col),
+                    grid.cell_center(end_row, end_col),
+                );
+                PlotPoint::new(pa.x + t * (pb.x - pa.x), pa.y + t * (pb.y - pa.y))
+            };
+            join_segments(&segments)
+                .into_iter()
+                .map(|edges| edges.into_iter().map(crossing).collect())
+                .collect()
+        })
+        .collect()
+}
+
+/// Join segments into polylines of edges at their shared edges.
+///
+/// Closed lines end with their first edge.
+fn join_segments(segments: &[[Edge; 2]]) -> Vec<Vec<Edge>> {
+    let mut by_edge: HashMap<Edge, Vec<usize>> = HashMap::default();
+    for (index, segment) in segments.iter().enumerate() {
+        for edge in segment {
+            by_edge.entry(*edge).or_default().push(index);
+        }
+    }
+    let mut used = vec![false; segments.len()];
+    let next = |edge: Edge, used: &mut Vec<bool>| {
+        let index = *by_edge.get(&edge)?.iter().find(|&&index| !used[index])?;
+        used[index] = true;
+        let [a, b] = segments[index];
+        Some(if a == edge { b } else { a })
+    };
+    let mut lines = Vec::new();
+    for start in 0..segments.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+        let [first, second] = segments[start];
+        let mut forward = vec![first, second];
+        while let Some(edge) = next(*forward.last().unwrap(), &mut used) {
+            forward.push(edge);
+        }
+        let mut backward = Vec::new();
+        while let Some(edge) = next(*backward.last().unwrap_or(&first), &mut used) {
+            backward.push(edge);
+        }
+        backward.reverse();
+        backward.extend(forward);
+        lines.push(backward);
+    }
+    lines
+}
+
+/// Add the parts of a triangle in each band between `levels` to `mesh`, with values linearly
+/// interpolated between the corners.
+fn fill_triangle(
+    mesh: &mut Mesh,
+    triangle: [(Pos2, f64); 3],
+    levels: &[f64],
+    band_colors: &[Color32],
+) {
+    let (min, max) = triangle.iter().fold(
+        (f64::INFINITY, f64::NEG_INFINITY),
+        |(min, max), (_, value)| (min.min(*value), max.max(*value)),
+    );
+    let first_band = levels.partition_point(|&level| level <= min);
+    let last_band = levels.partition_point(|&level| level < max);
+    for band in first_band..=last_band {
+        let low = if band == 0 {
+            f64::NEG_INFINITY
+        } else {
+            levels[band - 1]
+        };
+        let high = levels.get(band).copied().unwrap_or(f64::INFINITY);
+        let polygon = clip(&clip(&triangle, low, true), high, false);
+        if polygon.len() < 3 {
+            continue;
+        }
+        let index = mesh.vertices.len() as u32;
+        for (pos, _) in &polygon {
+            mesh.colored_vertex(*pos, band_colors[band]);
+        }
+        for i in 1..polygon.len() as u32 - 1 {
+            mesh.add_triangle(index, index + i, index + i + 1);
+        }
+    }
+}
+
+/// The part of a convex polygon where the value is above (or below) `level`.
+fn clip(polygon: &[(Pos2, f64)], level: f64, keep_above: bool) -> Vec<(Pos2, f64)> {
+    if !level.is_finite() {
+        return polygon.to_vec();
+    }
+    let inside = |value: f64| {
+        if keep_above {
+            value >= level
+        } else {
+            value <= level
+        }
+    };
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, &(pos, value)) in polygon.iter().enumerate() {
+        let (next_pos, next_value) = polygon[(i + 1) % polygon.len()];
+        if inside(value) {
+            clipped.push((pos, value));
+        }
+        if inside(value) != inside(next_value) {
+            let t = ((level - value) / (next_value - value)) as f32;
+            clipped.push((pos.lerp(next_pos, t), level));
+        }
+    }
+    clipped
+}
+
+/// Where to label a polyline: halfway along it, if it is long enough.
+fn label_position(points: &[Pos2]) -> Option<Pos2> {
+    let length: f32 = points.windows(2).map(|w| w[0].distance(w[1])).sum();
+    if length < MIN_LABELED_LENGTH {
+        return None;
+    }
+    let mut remaining = length / 2.0;
+    for w in points.windows(2) {
+        let segment = w[0].distance(w[1]);
+        if remaining <= segment && segment > 0.0 {
+            return Some(w[0].lerp(w[1], remaining / segment));
+        }
+        remaining -= segment;
+    }
+    points.last().copied()
+}
+
+fn distance_sq_to_segment(point: Pos2, a: Pos2, b: Pos2) -> f32 {
+    let ab = b - a;
+    let length_sq = ab.length_sq();
+    let t = if length_sq > 0.0 {
+        ((point - a).dot(ab) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    point.distance_sq(a + t * ab)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contains(line: &[PlotPoint], x: f64, y: f64) -> bool {
+        line.iter()
+            .any(|point| (point.x - x).abs() < 1e-9 && (point.y - y).abs() < 1e-9)
+    }
+
+    #[test]
+    fn auto_levels_are_round_and_strictly_inside() {
+        assert_eq!(auto_levels(&(0.0..=100.0), 5), [20.0, 40.0, 60.0, 80.0]);
+        assert_eq!(
+            auto_levels(&(-50.0..=50.0), 4),
+            [-40.0, -20.0, 0.0, 20.0, 40.0]
+        );
+        let levels = auto_levels(&(0.0..=1.0), 8);
+        assert_eq!(levels.len(), 9);
+        assert!((levels[0] - 0.1).abs() < 1e-12);
+        assert!(auto_levels(&(3.0..=3.0), 5).is_empty());
+        assert!(auto_levels(&(0.0..=1.0), 0).is_empty());
+    }
+
+    #[test]
+    fn a_peak_gives_a_closed_line() {
+        let grid = Grid::from_fn(3, 3, |row, col| f64::from(row == 1 && col == 1));
+        let lines = isolines(&grid, &[0.5]);
+        assert_eq!(lines.len(), 1);
+        let [line] = &lines[0][..] else {
+            panic!("expected one line, got {:?}", lines[0].len());
+        };
+        // Four crossings around the center, closed by repeating the first.
+        assert_eq!(line.len(), 5);
+        assert_eq!(line.first(), line.last());
+        for (x, y) in [(1.0, 1.5), (1.5, 1.0), (2.0, 1.5), (1.5, 2.0)] {
+            assert!(contains(line, x, y), "missing ({x}, {y}) in {line:?}");
+        }
+    }
+
+    #[test]
+    fn a_ridge_gives_an_open_line() {
+        let grid = Grid::new([0.0, 1.0, 0.0, 1.0], 2);
+        let lines = isolines(&grid, &[0.5]);
+        let [line] = &lines[0][..] else {
+            panic!("expected one line, got {:?}", lines[0].len());
+        };
+        assert_eq!(line.len(), 2);
+        assert!(contains(line, 1.0, 0.5) && contains(line, 1.0, 1.5));
+    }
+
+    #[test]
+    fn saddles_are_decided_by_the_center() {
+        // High bottom left and top right corners, with a center value of 0.5.
+        let grid = Grid::new([1.0, 0.0, 0.0, 1.0], 2);
+        let lines = isolines(&grid, &[0.5, 0.6]);
+
+        // At or below the center, the high corners connect and the low ones are cut off.
+        assert_eq!(lines[0].len(), 2);
+        assert!(lines[0]
+            .iter()
+            .any(|line| contains(line, 1.0, 0.5) && contains(line, 1.5, 1.0)));
+        assert!(lines[0]
+            .iter()
+            .any(|line| contains(line, 1.0, 1.5) && contains(line, 0.5, 1.0)));
+
+        // Above the center, the high corners are cut off.
+        assert_eq!(lines[1].len(), 2);
+        assert!(lines[1]
+            .iter()
+            .any(|line| contains(line, 0.5, 0.9) && contains(line, 0.9, 0.5)));
+        assert!(lines[1]
+            .iter()
+            .any(|line| contains(line, 1.5, 1.1) && contains(line, 1.1, 1.5)));
+    }
+
+    #[test]
+    fn cells_with_a_missing_corner_get_no_lines() {
+        let mut grid = Grid::from_fn(3, 3, |row, col| f64::from(row == 1 && col == 1));
+        grid.values[0] = f64::NAN;
+        let lines = isolines(&grid, &[0.5]);
+        let [line] = &lines[0][..] else {
+            panic!("expected one line, got {:?}", lines[0].len());
+        };
+        // The loop around the peak is open where the bottom left cell was skipped.
+        assert_eq!(line.len(), 4);
+        assert_ne!(line.first(), line.last());
+        let ends = [line[0], line[3]];
+        assert!(contains(&ends, 1.0, 1.5) && contains(&ends, 1.5, 1.0));
+
+        let grid = Grid::new([f64::NAN, 1.0, 0.0, 1.0], 2);
+        assert!(isolines(&grid, &[0.5])[0].is_empty());
+    }
+
+    #[test]
+    fn levels_outside_the_values_give_no_lines() {
+        let grid = Grid::from_fn(3, 3, |row, col| (row + col) as f64);
+        let lines = isolines(&grid, &[-1.0, 1.5, 10.0]);
+        assert!(lines[0].is_empty() && lines[2].is_empty());
+        assert_eq!(lines[1].len(), 1);
+    }
+
+    #[test]
+    fn clip_keeps_the_part_beyond_the_level() {
+        let triangle = [
+            (Pos2::new(0.0, 0.0), 0.0),
+            (Pos2::new(2.0, 0.0), 1.0),
+            (Pos2::new(2.0, 2.0), 2.0),
+        ];
+
+        let above = clip(&triangle, 1.0, true);
+        assert!(above.iter().all(|&(_, value)| value >= 1.0));
+        assert!(above.contains(&(Pos2::new(2.0, 2.0), 2.0)));
+        assert!(above.contains(&(Pos2::new(1.0, 1.0), 1.0)));
+
+        let below = clip(&triangle, 0.5, false);
+        assert_eq!(below.len(), 3);
+        assert!(below.iter().all(|&(_, value)| value <= 0.5));
+        assert!(below.contains(&(Pos2::new(1.0, 0.0), 0.5)));
+        assert!(below.contains(&(Pos2::new(0.5, 0.5), 0.5)));
+
+        assert_eq!(clip(&triangle, f64::NEG_INFINITY, true), triangle);
+        assert!(clip(&triangle, 3.0, true).is_empty());
+    }
+}
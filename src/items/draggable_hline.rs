@@ -0,0 +1,307 @@
+use std::ops::RangeInclusive;
+
+use egui::{pos2, Color32, CursorIcon, Id, PointerButton, Pos2, Response, Shape, Stroke, Ui};
+
+use crate::{PlotBounds, PlotMemory, PlotTransform};
+
+use super::{LineStyle, PlotGeometry, PlotItem};
+
+/// Distance in ui points within which a [`DraggableHLine`] can be grabbed.
+const GRAB_RADIUS: f32 = 5.0;
+
+/// A horizontal line the user can drag up and down, e.g. the price of a pending order.
+///
+/// Like [`TrendLine`](crate::TrendLine)s, these lines are owned by the application: add them
+/// every frame with [`crate::PlotUi::draggable_hline`] and read the new value from
+/// [`crate::PlotResponse::dragged_hline`]. While dragging, the plot already draws the line at its
+/// new value and moves its [`Self::y_highlight`] badge along with it.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{DraggableHLine, Plot};
+///
+/// let order_id = egui::Id::new("order");
+/// let mut order_price = 101.25;
+///
+/// let response = Plot::new("chart")
+///     .y_highlights(vec![(order_price, egui::Color32::DARK_GREEN)])
+///     .show(ui, |plot_ui| {
+///         plot_ui.draggable_hline(
+///             DraggableHLine::new(order_id, order_price)
+///                 .snap(0.25)
+///                 .y_highlight(0),
+///         );
+///     });
+///
+/// if let Some(drag) = response.dragged_hline {
+///     if drag.id == order_id && drag.stopped {
+///         order_price = drag.y; // modify the order
+///     }
+/// }
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DraggableHLine {
+    pub(super) id: Id,
+    pub(super) y: f64,
+    pub(super) from_x: Option<f64>,
+    pub(crate) stroke: Stroke,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) style: LineStyle,
+    pub(super) snap: Option<f64>,
+    pub(super) draggable: bool,
+    pub(super) y_highlight: Option<usize>,
+}
+
+impl DraggableHLine {
+    /// Create a line at `y`. The `id` identifies it in [`crate::PlotResponse::dragged_hline`].
+    pub fn new(id: Id, y: impl Into<f64>) -> Self {
+        Self {
+            id,
+            y: y.into(),
+            from_x: None,
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            name: String::default(),
+            highlight: false,
+            style: LineStyle::Solid,
+            snap: None,
+            draggable: true,
+            y_highlight: None,
+        }
+    }
+
+    /// Only draw the line to the right of `x`, like an [`HRay`](crate::HRay).
+    ///
+    /// By default the line fills the full width of the plot.
+    #[inline]
+    pub fn from_x(mut self, x: impl Into<f64>) -> Self {
+        self.from_x = Some(x.into());
+        self
+    }
+
+    /// Round dragged values to a multiple of `tick_size`, e.g. the tick size of a market.
+    #[inline]
+    pub fn snap(mut self, tick_size: f64) -> Self {
+        self.snap = (tick_size > 0.0).then_some(tick_size);
+        self
+    }
+
+    /// Whether the user can drag this line. Default is `true`.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
+    /// Move the badge at `index` in [`crate::Plot::y_highlights`] along with this line while it
+    /// is dragged, e.g. the badge showing the price of the order.
+    #[inline]
+    pub fn y_highlight(mut self, index: usize) -> Self {
+        self.y_highlight = Some(index);
+        self
+    }
+
+    /// Highlight this line in the plot by scaling up the line.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Set the line's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Name of this line.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// The left end of the drawn line on screen.
+    fn left(&self, transform: &PlotTransform) -> f32 {
+        let left = transform.frame().left();
+        self.from_x
+            .map_or(left, |x| transform.position_from_point_x(x).max(left))
+    }
+
+    fn hit_test(&self, transform: &PlotTransform, pos: Pos2) -> bool {
+        self.draggable
+            && pos.x >= self.left(transform)
+            && (transform.position_from_point_y(self.y) - pos.y).abs() <= GRAB_RADIUS
+    }
+}
+
+impl PlotItem for DraggableHLine {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let y = transform.position_from_point_y(self.y);
+
+        // Round to minimize aliasing:
+        let points = vec![
+            ui.painter()
+                .round_pos_to_pixels(pos2(self.left(transform), y)),
+            ui.painter()
+                .round_pos_to_pixels(pos2(transform.frame().right(), y)),
+        ];
+        self.style
+            .style_line(points, self.stroke, self.highlight, shapes);
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        // Don't rescale the plot while the line is dragged.
+        PlotBounds::NOTHING
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id)
+    }
+}
+
+/// A [`DraggableHLine`] moved by the user, see [`crate::PlotResponse::dragged_hline`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HLineDrag {
+    /// The id of the dragged line.
+    pub id: Id,
+
+    /// The new value of the line, snapped if the line has a tick size.
+    pub y: f64,
+
+    /// The value of the line when the drag started.
+    pub start_y: f64,
+
+    /// Whether the drag ended this frame, e.g. the moment to modify an order.
+    pub stopped: bool,
+}
+
+/// Ongoing drag of a [`DraggableHLine`], kept in [`PlotMemory`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct HLineDragState {
+    id: Id,
+    start_y: f64,
+    y: f64,
+
+    /// Where the drag started on screen.
+    origin: Pos2,
+}
+
+/// Drag the [`DraggableHLine`]s with the pointer.
+///
+/// Updates `lines` and the [`DraggableHLine::y_highlight`] badge of the dragged line to its new
+/// value.
+/// Returns the drag to report and the cursor icon to show, if any.
+pub(crate) fn interact_draggable_hlines(
+    ui: &Ui,
+    response: &Response,
+    mem: &mut PlotMemory,
+    lines: &mut [DraggableHLine],
+    highlights: &mut [(f64, Color32)],
+) -> (Option<HLineDrag>, Option<CursorIcon>) {
+    let transform = mem.transform;
+
+    if response.drag_started_by(PointerButton::Primary) && mem.trend_line_drag.is_none() {
+        let origin = ui.input(|i| i.pointer.press_origin());
+        mem.hline_drag = origin.and_then(|origin| {
+            // Lines added later are drawn on top, so they are grabbed first.
+            let line = lines
+                .iter()
+                .rev()
+                .find(|line| line.hit_test(&transform, origin))?;
+            Some(HLineDragState {
+                id: line.id,
+                start_y: line.y,
+                y: line.y,
+                origin,
+            })
+        });
+    }
+
+    let Some(mut state) = mem.hline_drag else {
+        let hovered = response
+            .hover_pos()
+            .is_some_and(|pos| lines.iter().any(|line| line.hit_test(&transform, pos)));
+        return (None, hovered.then_some(CursorIcon::Grab));
+    };
+    let Some(line) = lines.iter_mut().find(|line| line.id == state.id) else {
+        mem.hline_drag = None;
+        return (None, None);
+    };
+
+    let dragging = response.dragged_by(PointerButton::Primary);
+    if dragging {
+        if let Some(pointer) = response.interact_pointer_pos() {
+            let pos_y = transform.position_from_point_y(state.start_y) + pointer.y - state.origin.y;
+            let mut y = transform.value_from_position(pos2(pointer.x, pos_y)).y;
+            if let Some(tick_size) = line.snap {
+                y = (y / tick_size).round() * tick_size;
+            }
+            state.y = y;
+        }
+        mem.hline_drag = Some(state);
+    } else {
+        mem.hline_drag = None;
+    }
+
+    if let Some(highlight) = line.y_highlight.and_then(|index| highlights.get_mut(index)) {
+        highlight.0 = state.y;
+    }
+    line.y = state.y;
+
+    let drag = HLineDrag {
+        id: state.id,
+        y: state.y,
+        start_y: state.start_y,
+        stopped: !dragging,
+    };
+    (Some(drag), dragging.then_some(CursorIcon::Grabbing))
+}
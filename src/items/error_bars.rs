@@ -0,0 +1,304 @@
+use egui::{emath::NumExt as _, pos2, vec2, Color32, Shape, Stroke};
+
+use crate::{PlotBounds, PlotPoint, PlotTransform};
+
+use super::PlotConfig;
+
+/// The errors of a series in one direction, one entry per point.
+#[derive(Clone, Debug, PartialEq)]
+enum Errors {
+    Symmetric(Vec<f64>),
+    Asymmetric { minus: Vec<f64>, plus: Vec<f64> },
+}
+
+impl Errors {
+    /// How far the error bar of the point with the given index extends below and above it.
+    fn at(&self, index: usize) -> Option<(f64, f64)> {
+        let (minus, plus) = match self {
+            Self::Symmetric(errors) => {
+                let error = *errors.get(index)?;
+                (error, error)
+            }
+            Self::Asymmetric { minus, plus } => (*minus.get(index)?, *plus.get(index)?),
+        };
+        (minus.is_finite() && plus.is_finite()).then(|| (minus.abs(), plus.abs()))
+    }
+}
+
+/// Error bars of the points of a [`crate::Points`] or [`crate::Line`], e.g. confidence
+/// intervals.
+///
+/// The errors are given per point, in the order of the series. Points without an error (because
+/// the list is shorter or the error isn't finite) get no error bar. The error bars count towards
+/// the bounds of the item, and the hover label shows the value ± its error.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{ErrorBars, Plot, Points};
+///
+/// let returns = Points::new(vec![[1.0, 0.8], [2.0, 1.4], [3.0, 1.1]]).error_bars(
+///     ErrorBars::new()
+///         .y(vec![0.3, 0.2, 0.4])
+///         .x_asymmetric(vec![0.1, 0.1, 0.1], vec![0.2, 0.2, 0.2]),
+/// );
+/// Plot::new("returns").show(ui, |plot_ui| plot_ui.points(returns));
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorBars {
+    x: Option<Errors>,
+    y: Option<Errors>,
+    cap_width: f32,
+    width: f32,
+    color: Color32,
+}
+
+impl Default for ErrorBars {
+    fn default() -> Self {
+        Self {
+            x: None,
+            y: None,
+            cap_width: 6.0,
+            width: 1.0,
+            color: Color32::TRANSPARENT,
+        }
+    }
+}
+
+impl ErrorBars {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Symmetric errors in Y: the bar of each point extends by its error below and above it.
+    #[inline]
+    pub fn y(mut self, errors: impl Into<Vec<f64>>) -> Self {
+        self.y = Some(Errors::Symmetric(errors.into()));
+        self
+    }
+
+    /// Asymmetric errors in Y: the bar of each point extends by `below` below it and by `above`
+    /// above it.
+    #[inline]
+    pub fn y_asymmetric(mut self, below: impl Into<Vec<f64>>, above: impl Into<Vec<f64>>) -> Self {
+        self.y = Some(Errors::Asymmetric {
+            minus: below.into(),
+            plus: above.into(),
+        });
+        self
+    }
+
+    /// Symmetric errors in X: the bar of each point extends by its error left and right of it.
+    #[inline]
+    pub fn x(mut self, errors: impl Into<Vec<f64>>) -> Self {
+        self.x = Some(Errors::Symmetric(errors.into()));
+        self
+    }
+
+    /// Asymmetric errors in X: the bar of each point extends by `left` to the left of it and by
+    /// `right` to the right of it.
+    #[inline]
+    pub fn x_asymmetric(mut self, left: impl Into<Vec<f64>>, right: impl Into<Vec<f64>>) -> Self {
+        self.x = Some(Errors::Asymmetric {
+            minus: left.into(),
+            plus: right.into(),
+        });
+        self
+    }
+
+    /// Length of the caps at the ends of the bars, in ui points. Zero draws no caps.
+    /// Default: `6.0`.
+    #[inline]
+    pub fn cap_width(mut self, cap_width: f32) -> Self {
+        self.cap_width = cap_width;
+        self
+    }
+
+    /// Stroke width of the bars. Default: `1.0`.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Color of the bars. Default is `Color32::TRANSPARENT` which means the color of the item.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// The errors of the point with the given index, in X and Y.
+    pub(super) fn at(&self, index: usize) -> PointErrors {
+        PointErrors {
+            x: self.x.as_ref().and_then(|errors| errors.at(index)),
+            y: self.y.as_ref().and_then(|errors| errors.at(index)),
+        }
+    }
+
+    /// Draw the bars of the points with the given `indices` (e.g. those left after decimation),
+    /// or of all points. Bars outside the plot's bounds are skipped.
+    pub(super) fn shapes(
+        &self,
+        points: &[PlotPoint],
+        indices: Option<&[usize]>,
+        transform: &PlotTransform,
+        item_color: Color32,
+        highlight: bool,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let color = if self.color == Color32::TRANSPARENT {
+            item_color
+        } else {
+            self.color
+        };
+        let width = if highlight {
+            2.0 * self.width
+        } else {
+            self.width
+        };
+        let stroke = Stroke::new(width, color);
+        let half_cap = self.cap_width / 2.0;
+        let bounds = transform.bounds();
+        let indices: Box<dyn Iterator<Item = usize>> = match indices {
+            Some(indices) => Box::new(indices.iter().copied()),
+            None => Box::new(0..points.len()),
+        };
+        for index in indices {
+            let point = &points[index];
+            let errors = self.at(index);
+            let (below, above) = errors.y.unwrap_or_default();
+            let (left, right) = errors.x.unwrap_or_default();
+            if point.x + right < bounds.min()[0]
+                || point.x - left > bounds.max()[0]
+                || point.y + above < bounds.min()[1]
+                || point.y - below > bounds.max()[1]
+            {
+                continue;
+            }
+            if let Some((below, above)) = errors.y {
+                let low = transform.position_from_point(&PlotPoint::new(point.x, point.y - below));
+                let high = transform.position_from_point(&PlotPoint::new(point.x, point.y + above));
+                shapes.push(Shape::line_segment([low, high], stroke));
+                if half_cap > 0.0 {
+                    for end in [low, high] {
+                        shapes.push(Shape::line_segment(
+                            [end - vec2(half_cap, 0.0), end + vec2(half_cap, 0.0)],
+                            stroke,
+                        ));
+                    }
+                }
+            }
+            if let Some((left, right)) = errors.x {
+                let y = transform.position_from_point_y(point.y);
+                let left = pos2(transform.position_from_point_x(point.x - left), y);
+                let right = pos2(transform.position_from_point_x(point.x + right), y);
+                shapes.push(Shape::line_segment([left, right], stroke));
+                if half_cap > 0.0 {
+                    for end in [left, right] {
+                        shapes.push(Shape::line_segment(
+                            [end - vec2(0.0, half_cap), end + vec2(0.0, half_cap)],
+                            stroke,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Extend `bounds` with the ends of the bars.
+    pub(super) fn extend_bounds(&self, points: &[PlotPoint], bounds: &mut PlotBounds) {
+        for (index, point) in points.iter().enumerate() {
+            let errors = self.at(index);
+            if let Some((below, above)) = errors.y {
+                bounds.extend_with_y(point.y - below);
+                bounds.extend_with_y(point.y + above);
+            }
+            if let Some((left, right)) = errors.x {
+                bounds.extend_with_x(point.x - left);
+                bounds.extend_with_x(point.x + right);
+            }
+        }
+    }
+}
+
+/// The errors of one point, each as the distance below and above (or left and right of) it.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct PointErrors {
+    pub(super) x: Option<(f64, f64)>,
+    pub(super) y: Option<(f64, f64)>,
+}
+
+impl PointErrors {
+    /// The X error for a hover label, e.g. ` ± 0.5` or ` −0.2/+0.5`.
+    pub(super) fn format_x(&self, decimals: usize) -> String {
+        format_error(self.x, decimals)
+    }
+
+    /// The Y error for a hover label, in the units of the main Y axis (which may be rebased).
+    pub(super) fn format_y(&self, value: f64, decimals: usize, plot: &PlotConfig<'_>) -> String {
+        let rebased = self.y.map(|(below, above)| match &plot.y_rebase {
+            Some(rebase) => (
+                rebase.rebase(value) - rebase.rebase(value - below),
+                rebase.rebase(value + above) - rebase.rebase(value),
+            ),
+            None => (below, above),
+        });
+        format_error(rebased, decimals)
+    }
+}
+
+fn format_error(error: Option<(f64, f64)>, decimals: usize) -> String {
+    match error {
+        None => String::new(),
+        Some((minus, plus)) if minus == plus => format!(" ± {minus:.decimals$}"),
+        Some((minus, plus)) => {
+            let decimals = decimals.at_least(1);
+            format!(" −{minus:.decimals$}/+{plus:.decimals$}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::{Pos2, Rect};
+
+    use super::*;
+
+    fn bar_count(error_bars: &ErrorBars, points: &[PlotPoint], indices: Option<&[usize]>) -> usize {
+        let frame = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+        let bounds = PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]);
+        let transform = PlotTransform::new(frame, bounds, false, false);
+        let mut shapes = Vec::new();
+        error_bars.shapes(
+            points,
+            indices,
+            &transform,
+            Color32::RED,
+            false,
+            &mut shapes,
+        );
+        shapes.len()
+    }
+
+    #[test]
+    fn bars_outside_the_bounds_are_skipped() {
+        let error_bars = ErrorBars::new().y(vec![1.0; 4]).cap_width(0.0);
+        let points = [
+            PlotPoint::new(5.0, 5.0),
+            PlotPoint::new(20.0, 5.0),
+            PlotPoint::new(5.0, -3.0),
+            // Outside, but its bar reaches into the bounds.
+            PlotPoint::new(5.0, 10.5),
+        ];
+        assert_eq!(bar_count(&error_bars, &points, None), 2);
+    }
+
+    #[test]
+    fn bars_follow_the_given_indices() {
+        let error_bars = ErrorBars::new().x(vec![0.5; 5]).cap_width(0.0);
+        let points: Vec<PlotPoint> = (0..5).map(|i| PlotPoint::new(i as f64, 5.0)).collect();
+        assert_eq!(bar_count(&error_bars, &points, None), 5);
+        assert_eq!(bar_count(&error_bars, &points, Some(&[0, 4])), 2);
+    }
+}
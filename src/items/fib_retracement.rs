@@ -0,0 +1,439 @@
+use std::ops::RangeInclusive;
+
+use egui::{
+    emath::NumExt as _, epaint::util::FloatOrd as _, pos2, vec2, Align2, Color32, Id, Pos2, Rect,
+    Shape, Stroke, TextStyle, Ui,
+};
+
+use crate::{Cursor, LabelFormatter, LineStyle, PlotBounds, PlotPoint, PlotTransform};
+
+use super::{step_decimals, ClosestElem, PlotConfig, PlotGeometry, PlotItem};
+
+/// Alpha of the zones between levels, see [`FibRetracement::fill_zones`].
+const ZONE_ALPHA: f32 = 0.15;
+
+/// Fibonacci retracement levels of a move from `start` to `end`.
+///
+/// Level 0 is at `end` and level 1 at `start`, so the 0.618 level is where the price has given
+/// back 61.8% of the move. Extension levels above 1 lie beyond `start`, and negative levels beyond
+/// `end`. Each level is a horizontal segment between the X values of the two points, labeled
+/// with its ratio and price.
+///
+/// ```
+/// # use egui_plot::FibRetracement;
+/// let fib = FibRetracement::new([10.0, 100.0], [40.0, 160.0])
+///     .name("fib")
+///     .fill_zones(true)
+///     .extensions(true);
+/// assert_eq!(fib.price_at(0.5), 130.0);
+/// ```
+pub struct FibRetracement {
+    pub(super) start: PlotPoint,
+    pub(super) end: PlotPoint,
+    pub(crate) stroke: Stroke,
+    pub(super) levels: Vec<f64>,
+    pub(super) extension_levels: Vec<f64>,
+    pub(super) show_extensions: bool,
+    pub(super) level_colors: Vec<Color32>,
+    pub(super) fill_zones: bool,
+    pub(super) show_labels: bool,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    id: Option<Id>,
+    y_axis: Option<Id>,
+}
+
+impl FibRetracement {
+    /// The retracement levels drawn by default.
+    pub const DEFAULT_LEVELS: [f64; 7] = [0.0, 0.236, 0.382, 0.5, 0.618, 0.786, 1.0];
+
+    /// The extension levels drawn with [`Self::extensions`] by default.
+    pub const DEFAULT_EXTENSION_LEVELS: [f64; 3] = [1.272, 1.618, 2.618];
+
+    /// Levels of the move from `start` to `end`, with [`Self::DEFAULT_LEVELS`] and without
+    /// extensions.
+    pub fn new(start: impl Into<PlotPoint>, end: impl Into<PlotPoint>) -> Self {
+        Self {
+            start: start.into(),
+            end: end.into(),
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            levels: Self::DEFAULT_LEVELS.to_vec(),
+            extension_levels: Self::DEFAULT_EXTENSION_LEVELS.to_vec(),
+            show_extensions: false,
+            level_colors: Vec::new(),
+            fill_zones: false,
+            show_labels: true,
+            name: Default::default(),
+            highlight: false,
+            id: None,
+            y_axis: None,
+        }
+    }
+
+    /// The retracement levels to draw as ratios of the move. Default: [`Self::DEFAULT_LEVELS`].
+    #[inline]
+    pub fn levels(mut self, levels: impl Into<Vec<f64>>) -> Self {
+        self.levels = levels.into();
+        self
+    }
+
+    /// Whether to also draw the extension levels. Default: `false`.
+    #[inline]
+    pub fn extensions(mut self, show: bool) -> Self {
+        self.show_extensions = show;
+        self
+    }
+
+    /// The extension levels to draw with [`Self::extensions`].
+    /// Default: [`Self::DEFAULT_EXTENSION_LEVELS`].
+    #[inline]
+    pub fn extension_levels(mut self, levels: impl Into<Vec<f64>>) -> Self {
+        self.extension_levels = levels.into();
+        self
+    }
+
+    /// Whether to shade the zones between neighboring levels. Default: `false`.
+    #[inline]
+    pub fn fill_zones(mut self, fill: bool) -> Self {
+        self.fill_zones = fill;
+        self
+    }
+
+    /// Whether to label the levels with their ratio and price. Default: `true`.
+    #[inline]
+    pub fn show_labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Colors of the levels from the lowest ratio up, repeated if there are more levels. The zone
+    /// between two levels has the color of the higher ratio.
+    ///
+    /// Default: all levels have [`Self::color`].
+    #[inline]
+    pub fn level_colors(mut self, colors: impl Into<Vec<Color32>>) -> Self {
+        self.level_colors = colors.into();
+        self
+    }
+
+    /// Highlight this item in the plot by scaling up the lines.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Name of this item.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the item's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
+
+    /// The price of the level with the given ratio.
+    pub fn price_at(&self, ratio: f64) -> f64 {
+        self.end.y - ratio * (self.end.y - self.start.y)
+    }
+
+    /// The ratios of all drawn levels, lowest first.
+    fn ratios(&self) -> Vec<f64> {
+        let mut ratios = self.levels.clone();
+        if self.show_extensions {
+            ratios.extend_from_slice(&self.extension_levels);
+        }
+        ratios.retain(|ratio| ratio.is_finite());
+        ratios.sort_by_key(|ratio| ratio.ord());
+        ratios.dedup();
+        ratios
+    }
+
+    fn level_color(&self, index: usize) -> Color32 {
+        if self.level_colors.is_empty() {
+            self.stroke.color
+        } else {
+            self.level_colors[index % self.level_colors.len()]
+        }
+    }
+
+    fn x_range(&self) -> RangeInclusive<f64> {
+        self.start.x.min(self.end.x)..=self.start.x.max(self.end.x)
+    }
+}
+
+impl PlotItem for FibRetracement {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let ratios = self.ratios();
+        let x_range = self.x_range();
+        let left = transform.position_from_point_x(*x_range.start());
+        let right = transform.position_from_point_x(*x_range.end());
+        let y_of = |ratio: f64| transform.position_from_point_y(self.price_at(ratio));
+
+        if self.fill_zones {
+            let alpha = if self.highlight {
+                2.0 * ZONE_ALPHA
+            } else {
+                ZONE_ALPHA
+            };
+            for (index, pair) in ratios.windows(2).enumerate() {
+                let rect =
+                    Rect::from_two_pos(pos2(left, y_of(pair[0])), pos2(right, y_of(pair[1])));
+                let color = self.level_color(index + 1).linear_multiply(alpha);
+                shapes.push(Shape::rect_filled(rect, 0.0, color));
+            }
+        }
+
+        // The move itself.
+        LineStyle::dashed_dense().style_line(
+            vec![
+                transform.position_from_point(&self.start),
+                transform.position_from_point(&self.end),
+            ],
+            Stroke::new(self.stroke.width, self.stroke.color.gamma_multiply(0.5)),
+            self.highlight,
+            shapes,
+        );
+
+        let price_decimals = step_decimals(transform.dvalue_dpos()[1]);
+        let font_id = TextStyle::Small.resolve(ui.style());
+        for (index, &ratio) in ratios.iter().enumerate() {
+            let y = y_of(ratio);
+            let stroke = Stroke::new(self.stroke.width, self.level_color(index));
+            LineStyle::Solid.style_line(
+                vec![pos2(left, y), pos2(right, y)],
+                stroke,
+                self.highlight,
+                shapes,
+            );
+
+            if self.show_labels {
+                let text = format!("{ratio} ({:.*})", price_decimals, self.price_at(ratio));
+                ui.fonts(|f| {
+                    shapes.push(Shape::text(
+                        f,
+                        pos2(left, y) + vec2(2.0, -1.0),
+                        Align2::LEFT_BOTTOM,
+                        text,
+                        font_id.clone(),
+                        stroke.color,
+                    ));
+                });
+            }
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        let x_range = self.x_range();
+        for ratio in self.ratios() {
+            let price = self.price_at(ratio);
+            bounds.extend_with(&PlotPoint::new(*x_range.start(), price));
+            bounds.extend_with(&PlotPoint::new(*x_range.end(), price));
+        }
+        bounds.extend_with(&self.start);
+        bounds.extend_with(&self.end);
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let x_range = self.x_range();
+        let left = transform.position_from_point_x(*x_range.start());
+        let right = transform.position_from_point_x(*x_range.end());
+        let x = point.x.clamp(left.min(right), left.max(right));
+        self.ratios()
+            .into_iter()
+            .enumerate()
+            .map(|(index, ratio)| {
+                let y = transform.position_from_point_y(self.price_at(ratio));
+                ClosestElem {
+                    index,
+                    dist_sq: point.distance_sq(pos2(x, y)),
+                }
+            })
+            .min_by_key(|e| e.dist_sq.ord())
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        let ratio = self.ratios()[elem.index];
+        let price = self.price_at(ratio);
+        let x_range = self.x_range();
+        let left = plot.transform.position_from_point_x(*x_range.start());
+        let right = plot.transform.position_from_point_x(*x_range.end());
+        let y = plot.transform.position_from_point_y(price);
+        LineStyle::Solid.style_line(
+            vec![pos2(left, y), pos2(right, y)],
+            Stroke::new(self.stroke.width, self.level_color(elem.index)),
+            true,
+            shapes,
+        );
+
+        if plot.show_y {
+            cursors.push(Cursor::Horizontal { y: price });
+        }
+
+        let right_x = x_range.start().max(*x_range.end());
+        let text = plot
+            .custom_label(label_formatter, &self.name, PlotPoint::new(right_x, price))
+            .unwrap_or_else(|| {
+                let decimals = step_decimals(plot.transform.dvalue_dpos()[1]).at_least(1);
+                let mut text = self.name.clone();
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str(&format!("{ratio} = {}", plot.format_y(price, decimals)));
+                text
+            });
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                pos2(left.max(right), y) + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_prices(fib: &FibRetracement, expected: &[(f64, f64)]) {
+        for &(ratio, price) in expected {
+            let actual = fib.price_at(ratio);
+            assert!(
+                (actual - price).abs() < 1e-9,
+                "{ratio}: {actual} != {price}"
+            );
+        }
+    }
+
+    #[test]
+    fn level_prices_of_an_up_swing() {
+        let fib = FibRetracement::new([0.0, 100.0], [10.0, 200.0]);
+        assert_prices(
+            &fib,
+            &[
+                (0.0, 200.0),
+                (0.236, 176.4),
+                (0.5, 150.0),
+                (0.618, 138.2),
+                (1.0, 100.0),
+                // Extensions lie below the start, negative levels above the end.
+                (1.618, 38.2),
+                (2.618, -61.8),
+                (-0.272, 227.2),
+            ],
+        );
+    }
+
+    #[test]
+    fn level_prices_of_a_down_swing() {
+        let fib = FibRetracement::new([0.0, 200.0], [10.0, 100.0]);
+        assert_prices(
+            &fib,
+            &[
+                (0.0, 100.0),
+                (0.382, 138.2),
+                (0.786, 178.6),
+                (1.0, 200.0),
+                (1.272, 227.2),
+                (2.618, 361.8),
+                (-0.5, 50.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn custom_levels_and_extensions() {
+        let fib = FibRetracement::new([5.0, 100.0], [0.0, 200.0])
+            .levels([0.5, 0.0, f64::NAN, 1.0, 0.5])
+            .extension_levels([1.5, 3.0]);
+        assert_eq!(fib.ratios(), [0.0, 0.5, 1.0]);
+        let bounds = fib.bounds();
+        assert_eq!(bounds.min(), [0.0, 100.0]);
+        assert_eq!(bounds.max(), [5.0, 200.0]);
+
+        let fib = fib.extensions(true);
+        assert_eq!(fib.ratios(), [0.0, 0.5, 1.0, 1.5, 3.0]);
+        assert_prices(&fib, &[(1.5, 50.0), (3.0, -100.0)]);
+        // The plot makes room for the extensions beyond the start.
+        assert_eq!(fib.bounds().min(), [0.0, -100.0]);
+    }
+}
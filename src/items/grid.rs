@@ -0,0 +1,221 @@
+use std::ops::{Range, RangeInclusive};
+
+use egui::emath::NumExt as _;
+
+use super::PlotPoint;
+
+/// A row-major grid of values over a rectangle of the plot, the input of 2D items such as
+/// [`crate::Heatmap`] and [`crate::Contour`].
+///
+/// The first `cols` values are the bottom row, from left to right. Each value belongs to a cell
+/// of the grid and is sampled at the cell's center. The grid covers `0..=cols` on the X axis and
+/// `0..=rows` on the Y axis unless placed with [`Self::x_range`] and [`Self::y_range`]. Values
+/// that aren't finite are missing.
+///
+/// ```
+/// # use egui_plot::Grid;
+/// let grid = Grid::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3).x_range(10.0..=16.0);
+/// assert_eq!(grid.rows(), 2);
+/// assert_eq!(grid.value(1, 0), Some(4.0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid {
+    pub(super) values: Vec<f64>,
+    pub(super) cols: usize,
+    pub(super) x_range: Option<RangeInclusive<f64>>,
+    pub(super) y_range: Option<RangeInclusive<f64>>,
+}
+
+impl Grid {
+    /// A grid of `values` with `cols` values per row. Values beyond the last full row are
+    /// ignored.
+    pub fn new(values: impl Into<Vec<f64>>, cols: usize) -> Self {
+        Self {
+            values: values.into(),
+            cols,
+            x_range: None,
+            y_range: None,
+        }
+    }
+
+    /// A grid of `rows` by `cols` values computed from the row and column.
+    pub fn from_fn(rows: usize, cols: usize, f: impl Fn(usize, usize) -> f64) -> Self {
+        let values = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (row, col)))
+            .map(|(row, col)| f(row, col))
+            .collect::<Vec<_>>();
+        Self::new(values, cols)
+    }
+
+    /// The X values the grid spans. Default: `0.0..=cols`.
+    #[inline]
+    pub fn x_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.x_range = Some(range.start().min(*range.end())..=range.start().max(*range.end()));
+        self
+    }
+
+    /// The Y values the grid spans. Default: `0.0..=rows`.
+    #[inline]
+    pub fn y_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.y_range = Some(range.start().min(*range.end())..=range.start().max(*range.end()));
+        self
+    }
+
+    /// Number of full rows.
+    pub fn rows(&self) -> usize {
+        self.values.len().checked_div(self.cols).unwrap_or(0)
+    }
+
+    /// Number of values per row.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The value in `row` and `col`, if it is in the grid.
+    pub fn value(&self, row: usize, col: usize) -> Option<f64> {
+        (row < self.rows() && col < self.cols).then(|| self.values[row * self.cols + col])
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.rows() == 0
+    }
+
+    pub(super) fn x_extent(&self) -> RangeInclusive<f64> {
+        self.x_range.clone().unwrap_or(0.0..=self.cols as f64)
+    }
+
+    pub(super) fn y_extent(&self) -> RangeInclusive<f64> {
+        self.y_range.clone().unwrap_or(0.0..=self.rows() as f64)
+    }
+
+    /// The smallest and largest finite value, or `None` if there is none.
+    pub(super) fn value_range(&self) -> Option<RangeInclusive<f64>> {
+        let (min, max) = self.values[..self.rows() * self.cols]
+            .iter()
+            .filter(|value| value.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &value| {
+                (min.min(value), max.max(value))
+            });
+        (min <= max).then_some(min..=max)
+    }
+
+    /// The lower left and upper right corner of a cell.
+    pub(super) fn cell_corners(&self, row: usize, col: usize) -> [PlotPoint; 2] {
+        let (x, y) = (self.x_extent(), self.y_extent());
+        let width = (x.end() - x.start()) / self.cols as f64;
+        let height = (y.end() - y.start()) / self.rows() as f64;
+        let min = PlotPoint::new(
+            x.start() + col as f64 * width,
+            y.start() + row as f64 * height,
+        );
+        [min, PlotPoint::new(min.x + width, min.y + height)]
+    }
+
+    /// The center of a cell, where its value is sampled.
+    pub(super) fn cell_center(&self, row: usize, col: usize) -> PlotPoint {
+        let [min, max] = self.cell_corners(row, col);
+        PlotPoint::new((min.x + max.x) / 2.0, (min.y + max.y) / 2.0)
+    }
+
+    /// The cell at `value`, as row and column.
+    pub(super) fn cell_at(&self, value: PlotPoint) -> Option<(usize, usize)> {
+        let (x, y) = (self.x_extent(), self.y_extent());
+        if self.is_empty() || !x.contains(&value.x) || !y.contains(&value.y) {
+            return None;
+        }
+        let cell = |value: f64, extent: &RangeInclusive<f64>, count: usize| {
+            let size = (extent.end() - extent.start()) / count as f64;
+            (((value - extent.start()) / size) as usize).at_most(count - 1)
+        };
+        Some((cell(value.y, &y, self.rows()), cell(value.x, &x, self.cols)))
+    }
+
+    /// The rows and columns of the cells that overlap the given X and Y ranges.
+    pub(super) fn cells_in(
+        &self,
+        x: RangeInclusive<f64>,
+        y: RangeInclusive<f64>,
+    ) -> (Range<usize>, Range<usize>) {
+        (
+            Self::cells_overlapping(&self.y_extent(), self.rows(), y),
+            Self::cells_overlapping(&self.x_extent(), self.cols, x),
+        )
+    }
+
+    /// The rows or columns that overlap `visible`, out of `count` spanning `extent`.
+    fn cells_overlapping(
+        extent: &RangeInclusive<f64>,
+        count: usize,
+        visible: RangeInclusive<f64>,
+    ) -> Range<usize> {
+        let size = (extent.end() - extent.start()) / count as f64;
+        if size <= 0.0 || !size.is_finite() {
+            return 0..0;
+        }
+        let cell = |value: f64| ((value - extent.start()) / size).clamp(0.0, count as f64);
+        (cell(*visible.start()).floor() as usize)..(cell(*visible.end()).ceil() as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 3 columns of width 2 over `10..=16` and 2 rows of height 0.5 over `-1..=0`.
+    fn grid() -> Grid {
+        Grid::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 3)
+            .x_range(16.0..=10.0)
+            .y_range(-1.0..=0.0)
+    }
+
+    #[test]
+    fn cell_at_boundaries() {
+        let grid = grid();
+        assert_eq!(grid.cell_at(PlotPoint::new(10.0, -1.0)), Some((0, 0)));
+        assert_eq!(grid.cell_at(PlotPoint::new(11.9, -0.6)), Some((0, 0)));
+        // An edge between two cells belongs to the upper one.
+        assert_eq!(grid.cell_at(PlotPoint::new(12.0, -0.5)), Some((1, 1)));
+        // The outer edges belong to the last cells.
+        assert_eq!(grid.cell_at(PlotPoint::new(16.0, 0.0)), Some((1, 2)));
+        assert_eq!(grid.cell_at(PlotPoint::new(16.0, -1.0)), Some((0, 2)));
+    }
+
+    #[test]
+    fn cell_at_outside_the_grid() {
+        let grid = grid();
+        for (x, y) in [
+            (9.99, -0.5),
+            (16.01, -0.5),
+            (13.0, -1.01),
+            (13.0, 0.01),
+            (f64::NAN, -0.5),
+            (13.0, f64::INFINITY),
+        ] {
+            assert_eq!(grid.cell_at(PlotPoint::new(x, y)), None, "({x}, {y})");
+        }
+        assert_eq!(
+            Grid::new(vec![1.0, 2.0], 3).cell_at(PlotPoint::new(0.5, 0.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn cells_overlapping_ranges() {
+        let extent = 10.0..=16.0;
+        let cells = |visible| Grid::cells_overlapping(&extent, 3, visible);
+        assert_eq!(cells(11.0..=13.0), 0..2);
+        // Touching a cell edge doesn't include the neighbor.
+        assert_eq!(cells(12.0..=14.0), 1..2);
+        assert_eq!(cells(0.0..=100.0), 0..3);
+        assert_eq!(cells(0.0..=5.0), 0..0);
+        assert_eq!(cells(20.0..=30.0), 3..3);
+        assert_eq!(Grid::cells_overlapping(&(1.0..=1.0), 3, 0.0..=2.0), 0..0);
+    }
+
+    #[test]
+    fn cells_in_uses_the_extent_of_each_axis() {
+        let grid = grid();
+        assert_eq!(grid.cells_in(13.0..=20.0, -0.25..=0.0), (1..2, 1..3));
+        assert_eq!(grid.cells_in(10.0..=16.0, -1.0..=0.0), (0..2, 0..3));
+    }
+}
@@ -0,0 +1,283 @@
+use std::ops::RangeInclusive;
+
+use egui::{epaint::Mesh, vec2, Align2, Color32, Id, Pos2, Shape, Stroke, TextStyle, Ui};
+
+use crate::{
+    colormap::value_decimals, Colorbar, Colormap, Cursor, IndexedPoints, LabelFormatter,
+    PlotBounds, PlotPoint, PlotTransform,
+};
+
+use super::{step_decimals, ClosestElem, Grid, PlotConfig, PlotGeometry, PlotItem};
+
+/// A grid of values drawn as colored cells, e.g. order book depth over time or a correlation
+/// matrix.
+///
+/// Each value of the [`Grid`] fills its cell. Values that aren't finite leave their cell empty.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Colormap, Grid, Heatmap, Plot};
+///
+/// let correlations = vec![
+///     1.0, 0.3, -0.5, //
+///     0.3, 1.0, 0.1, //
+///     -0.5, 0.1, 1.0,
+/// ];
+/// Plot::new("correlations").show(ui, |plot_ui| {
+///     plot_ui.heatmap(
+///         Heatmap::new(Grid::new(correlations, 3))
+///             .colormap(Colormap::Diverging)
+///             .value_range(-1.0..=1.0),
+///     );
+/// });
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct Heatmap {
+    pub(crate) grid: Grid,
+    pub(super) value_range: Option<RangeInclusive<f64>>,
+    pub(super) colormap: Colormap,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    id: Option<Id>,
+    y_axis: Option<Id>,
+}
+
+impl Heatmap {
+    /// A heatmap of the values of `grid`, colored with [`Colormap::Viridis`] over their range.
+    pub fn new(grid: Grid) -> Self {
+        Self {
+            grid,
+            value_range: None,
+            colormap: Colormap::default(),
+            name: Default::default(),
+            highlight: false,
+            id: None,
+            y_axis: None,
+        }
+    }
+
+    /// The values mapped to the ends of the colormap. Values outside get the color of the
+    /// nearest end. Default: the smallest and largest finite value.
+    #[inline]
+    pub fn value_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.value_range = Some(range);
+        self
+    }
+
+    /// The colormap. Default: [`Colormap::Viridis`].
+    #[inline]
+    pub fn colormap(mut self, colormap: Colormap) -> Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// Highlight this heatmap in the plot by outlining it.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Name of this heatmap.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Set the heatmap's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
+
+    /// A [`Colorbar`] with the colormap and value range of this heatmap, to show next to the plot.
+    pub fn colorbar(&self) -> Colorbar {
+        Colorbar::new(self.colormap.clone(), self.value_range_or_default())
+    }
+
+    fn value_range_or_default(&self) -> RangeInclusive<f64> {
+        self.value_range
+            .clone()
+            .or_else(|| self.grid.value_range())
+            .unwrap_or(0.0..=1.0)
+    }
+}
+
+impl PlotItem for Heatmap {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let grid = &self.grid;
+        if grid.is_empty() {
+            return;
+        }
+
+        // Only the visible cells, as a single mesh.
+        let bounds = transform.bounds();
+        let (rows, cols) = grid.cells_in(
+            bounds.min()[0]..=bounds.max()[0],
+            bounds.min()[1]..=bounds.max()[1],
+        );
+        let value_range = self.value_range_or_default();
+        let mut mesh = Mesh::default();
+        for row in rows {
+            for col in cols.clone() {
+                let value = grid.values[row * grid.cols + col];
+                if !value.is_finite() {
+                    continue;
+                }
+                let [min, max] = grid.cell_corners(row, col);
+                mesh.add_colored_rect(
+                    transform.rect_from_values(&min, &max),
+                    self.colormap.color_in(value, &value_range),
+                );
+            }
+        }
+        shapes.push(Shape::mesh(mesh));
+
+        if self.highlight {
+            let (x, y) = (grid.x_extent(), grid.y_extent());
+            let rect = transform.rect_from_values(
+                &PlotPoint::new(*x.start(), *y.start()),
+                &PlotPoint::new(*x.end(), *y.end()),
+            );
+            shapes.push(Shape::rect_stroke(
+                rect,
+                0.0,
+                Stroke::new(2.0, self.colormap.color(1.0)),
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.colormap.color(0.5)
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        if !self.grid.is_empty() {
+            let (x, y) = (self.grid.x_extent(), self.grid.y_extent());
+            bounds.extend_with(&PlotPoint::new(*x.start(), *y.start()));
+            bounds.extend_with(&PlotPoint::new(*x.end(), *y.end()));
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let (row, col) = self.grid.cell_at(transform.value_from_position(point))?;
+        let index = row * self.grid.cols + col;
+        // Anywhere inside a cell, the heatmap is just within hover distance, so that it is
+        // reported unless another item is closer.
+        self.grid.values[index].is_finite().then_some(ClosestElem {
+            index,
+            dist_sq: IndexedPoints::HOVER_RADIUS.powi(2),
+        })
+    }
+
+    fn snap_value(&self, elem: &ClosestElem, _: PlotPoint) -> Option<PlotPoint> {
+        let cols = self.grid.cols;
+        Some(self.grid.cell_center(elem.index / cols, elem.index % cols))
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        let (row, col) = (elem.index / self.grid.cols, elem.index % self.grid.cols);
+        let [min, max] = self.grid.cell_corners(row, col);
+        let rect = plot.transform.rect_from_values(&min, &max);
+        shapes.push(Shape::rect_stroke(
+            rect,
+            0.0,
+            Stroke::new(1.0, plot.ui.visuals().text_color()),
+        ));
+
+        let center = self.grid.cell_center(row, col);
+        if plot.show_x {
+            cursors.push(Cursor::Vertical { x: center.x });
+        }
+        if plot.show_y {
+            cursors.push(Cursor::Horizontal { y: center.y });
+        }
+
+        let mut text = plot
+            .custom_label(label_formatter, &self.name, center)
+            .unwrap_or_else(|| {
+                // Enough decimals to show the centers of the cells.
+                let mut text = self.name.clone();
+                if plot.show_x {
+                    let decimals = step_decimals((max.x - min.x) / 2.0);
+                    text.push_str(&format!("\nx = {}", plot.format_x(center.x, decimals)));
+                }
+                if plot.show_y {
+                    let decimals = step_decimals((max.y - min.y) / 2.0);
+                    text.push_str(&format!("\ny = {}", plot.format_y(center.y, decimals)));
+                }
+                text
+            });
+        let value = self.grid.values[elem.index];
+        text.push_str(&format!(
+            "\nvalue = {:.*}",
+            value_decimals(&self.value_range_or_default()),
+            value
+        ));
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                rect.right_top() + vec2(3.0, -2.0),
+                Align2::LEFT_BOTTOM,
+                text.trim_start_matches('\n'),
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
+}
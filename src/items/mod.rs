@@ -7,21 +7,53 @@ use epaint::{emath::Rot2, util::FloatOrd, Mesh};
 
 use crate::*;
 
-use super::{Cursor, LabelFormatter, PlotBounds, PlotTransform};
+use super::{axis::YRebase, Cursor, LabelFormatter, PlotBounds, PlotTransform};
+use error_bars::PointErrors;
 use rect_elem::*;
 use values::ClosestElem;
 
+pub use band::Band;
 pub use bar::Bar;
 pub use box_elem::{BoxElem, BoxSpread};
+pub use candle::{Candle, CandleStyle};
+pub(crate) use comparison::ComparedLine;
+pub use comparison::Comparison;
+pub use contour::Contour;
+pub(crate) use draggable_hline::{interact_draggable_hlines, HLineDragState};
+pub use draggable_hline::{DraggableHLine, HLineDrag};
+pub use error_bars::ErrorBars;
+pub use fib_retracement::FibRetracement;
+pub use grid::Grid;
+pub use heatmap::Heatmap;
+pub use ring_buffer::RingBuffer;
+pub use span::{HSpan, VSpan};
+pub use spatial_index::IndexedPoints;
+pub(crate) use trend_line::{interact_trend_lines, TrendLineDrag};
+pub use trend_line::{TrendLine, TrendLineEvent, TrendLineKind};
 pub use values::{LineStyle, MarkerShape, Orientation, PlotGeometry, PlotPoint, PlotPoints};
+pub use volume_profile::{VolumeLevel, VolumeProfile};
 
 // grom
 pub use new_items::{HRay, LinkedYHRay, LinkedYPolygon, LinkedYText};
 
+mod band;
 mod bar;
 mod box_elem;
+mod candle;
+mod comparison;
+mod contour;
+mod draggable_hline;
+mod error_bars;
+mod fib_retracement;
+mod grid;
+mod heatmap;
+mod trend_line;
 mod rect_elem;
+mod ring_buffer;
+mod span;
+mod spatial_index;
 mod values;
+mod volume_profile;
 mod new_items;
 
 
@@ -33,6 +65,54 @@ pub struct PlotConfig<'a> {
     pub transform: &'a PlotTransform,
     pub show_x: bool,
     pub show_y: bool,
+
+    /// Maps X values (bar indices) to time, see [`crate::Plot::x_time_index`].
+    pub x_time_index: Option<&'a TimeIndex>,
+
+    /// Maps Y values to the shown ones, see [`crate::YScaleMode`].
+    pub y_rebase: Option<YRebase>,
+}
+
+impl PlotConfig<'_> {
+    /// Format a Y value for a hover label, rebased if the axis has a [`crate::YScaleMode`].
+    pub(crate) fn format_y(&self, value: f64, decimals: usize) -> String {
+        match &self.y_rebase {
+            Some(rebase) => rebase.format(value),
+            None => format!("{value:.decimals$}"),
+        }
+    }
+
+    /// Format an X value for a hover label, as a timestamp if the X axis has a time index.
+    pub(crate) fn format_x(&self, value: f64, decimals: usize) -> String {
+        match self.x_time_index {
+            Some(time_index) if value.is_finite() => time_index.format_index(value),
+            _ => format!("{value:.decimals$}"),
+        }
+    }
+
+    /// The label of a custom [`LabelFormatter`] for `value`, if there is one.
+    ///
+    /// The formatter sees timestamps rather than indices on a time axis, and rebased Y values.
+    pub(crate) fn custom_label(
+        &self,
+        label_formatter: &LabelFormatter,
+        name: &str,
+        mut value: PlotPoint,
+    ) -> Option<String> {
+        let custom_label = label_formatter.as_ref()?;
+        if let Some(time_index) = self.x_time_index {
+            value.x = time_index.time_from_index(value.x);
+        }
+        if let Some(rebase) = &self.y_rebase {
+            value.y = rebase.rebase(value.y);
+        }
+        Some(custom_label(name, &value))
+    }
+}
+
+/// Decimals that tell apart values `step` apart, at most 6.
+pub(crate) fn step_decimals(step: f64) -> usize {
+    ((-step.abs().log10()).ceil().at_least(0.0) as usize).at_most(6)
 }
 
 /// Trait shared by things that can be drawn in the plot.
@@ -56,19 +136,18 @@ pub trait PlotItem {
 
     fn id(&self) -> Option<Id>;
 
+    /// The id of the Y axis this item is drawn against, see [`AxisHints::id`].
+    ///
+    /// `None` means the main Y axis.
+    fn y_axis(&self) -> Option<Id> {
+        None
+    }
+
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
         match self.geometry() {
             PlotGeometry::None => None,
 
-            PlotGeometry::Points(points) => points
-                .iter()
-                .enumerate()
-                .map(|(index, value)| {
-                    let pos = transform.position_from_point(value);
-                    let dist_sq = point.distance_sq(pos);
-                    ClosestElem { index, dist_sq }
-                })
-                .min_by_key(|e| e.dist_sq.ord()),
+            PlotGeometry::Points(points) => find_closest_point(points, point, transform),
 
             PlotGeometry::Rects => {
                 panic!("If the PlotItem is made of rects, it should implement find_closest()")
@@ -76,6 +155,32 @@ pub trait PlotItem {
         }
     }
 
+    /// The leftmost value of this item with an X value in `x_range`, e.g. the close of the first
+    /// visible candle. It is the reference value of a [`crate::YScaleMode`].
+    fn first_value(&self, x_range: &RangeInclusive<f64>) -> Option<PlotPoint> {
+        match self.geometry() {
+            PlotGeometry::Points(points) => points
+                .iter()
+                .filter(|point| x_range.contains(&point.x) && point.y.is_finite())
+                .min_by_key(|point| point.x.ord())
+                .copied(),
+            PlotGeometry::None | PlotGeometry::Rects => None,
+        }
+    }
+
+    /// The value the crosshair snaps to for the element found by [`Self::find_closest`], e.g. the
+    /// open, high, low or close of a candle, whichever is nearest to `pointer`.
+    ///
+    /// `pointer` is in the coordinates of this item's Y axis. `None` means the item can't be
+    /// snapped to. See [`crate::Plot::crosshair_snap`].
+    fn snap_value(&self, elem: &ClosestElem, pointer: PlotPoint) -> Option<PlotPoint> {
+        _ = pointer;
+        match self.geometry() {
+            PlotGeometry::Points(points) => points.get(elem.index).copied(),
+            PlotGeometry::None | PlotGeometry::Rects => None,
+        }
+    }
+
     fn on_hover(
         &self,
         elem: ClosestElem,
@@ -84,39 +189,62 @@ pub trait PlotItem {
         plot: &PlotConfig<'_>,
         label_formatter: &LabelFormatter,
     ) {
-        let points = match self.geometry() {
-            PlotGeometry::Points(points) => points,
-            PlotGeometry::None => {
-                panic!("If the PlotItem has no geometry, on_hover() must not be called")
-            }
-            PlotGeometry::Rects => {
-                panic!("If the PlotItem is made of rects, it should implement on_hover()")
-            }
-        };
-
-        let line_color = if plot.ui.visuals().dark_mode {
-            Color32::from_gray(100).additive()
-        } else {
-            Color32::from_black_alpha(180)
-        };
-
-        // this method is only called, if the value is in the result set of find_closest()
-        let value = points[elem.index];
-        let pointer = plot.transform.position_from_point(&value);
-        shapes.push(Shape::circle_filled(pointer, 3.0, line_color));
-
-        rulers_at_value(
-            pointer,
-            value,
-            self.name(),
-            plot,
+        point_hover(
+            self,
+            elem,
+            PointErrors::default(),
             shapes,
             cursors,
+            plot,
             label_formatter,
         );
     }
 }
 
+/// The default [`PlotItem::on_hover`]: marks the hovered point and shows rulers at it, with the
+/// point's `errors` in the label.
+fn point_hover(
+    item: &(impl PlotItem + ?Sized),
+    elem: ClosestElem,
+    errors: PointErrors,
+    shapes: &mut Vec<Shape>,
+    cursors: &mut Vec<Cursor>,
+    plot: &PlotConfig<'_>,
+    label_formatter: &LabelFormatter,
+) {
+    let points = match item.geometry() {
+        PlotGeometry::Points(points) => points,
+        PlotGeometry::None => {
+            panic!("If the PlotItem has no geometry, on_hover() must not be called")
+        }
+        PlotGeometry::Rects => {
+            panic!("If the PlotItem is made of rects, it should implement on_hover()")
+        }
+    };
+
+    let line_color = if plot.ui.visuals().dark_mode {
+        Color32::from_gray(100).additive()
+    } else {
+        Color32::from_black_alpha(180)
+    };
+
+    // this method is only called, if the value is in the result set of find_closest()
+    let value = points[elem.index];
+    let pointer = plot.transform.position_from_point(&value);
+    shapes.push(Shape::circle_filled(pointer, 3.0, line_color));
+
+    rulers_at_value(
+        pointer,
+        value,
+        errors,
+        item.name(),
+        plot,
+        shapes,
+        cursors,
+        label_formatter,
+    );
+}
+
 // ----------------------------------------------------------------------------
 
 /// A horizontal line in a plot, filling the full width
@@ -128,6 +256,7 @@ pub struct HLine {
     pub(super) highlight: bool,
     pub(super) style: LineStyle,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl HLine {
@@ -139,6 +268,7 @@ impl HLine {
             highlight: false,
             style: LineStyle::Solid,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -196,6 +326,15 @@ impl HLine {
         self.id = Some(id);
         self
     }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
 }
 
 impl PlotItem for HLine {
@@ -252,6 +391,10 @@ impl PlotItem for HLine {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
 
 /// A vertical line in a plot, filling the full width
@@ -397,7 +540,10 @@ pub struct Line {
     pub(super) highlight: bool,
     pub(super) fill: Option<f32>,
     pub(super) style: LineStyle,
+    pub(super) decimate: bool,
+    pub(super) error_bars: Option<ErrorBars>,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl Line {
@@ -409,7 +555,10 @@ impl Line {
             highlight: false,
             fill: None,
             style: LineStyle::Solid,
+            decimate: true,
+            error_bars: None,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -455,6 +604,28 @@ impl Line {
         self
     }
 
+    /// Whether to draw only the points that make a visible difference. Default is `true`.
+    ///
+    /// If the series is sorted by X and has several points per pixel column, only the visible
+    /// part is drawn, with the first, last, lowest and highest point of every pixel column.
+    /// This looks the same as drawing all points, but is much faster for huge series.
+    /// Hovering still finds the original points.
+    ///
+    /// Checking that the series is sorted takes a pass over all points on every frame, except
+    /// for [`IndexedPoints`], which check it only once. Prefer those for huge series.
+    #[inline]
+    pub fn decimate(mut self, decimate: bool) -> Self {
+        self.decimate = decimate;
+        self
+    }
+
+    /// Draw error bars at the points of the line, see [`ErrorBars`].
+    #[inline]
+    pub fn error_bars(mut self, error_bars: ErrorBars) -> Self {
+        self.error_bars = Some(error_bars);
+        self
+    }
+
     /// Name of this line.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -474,6 +645,77 @@ impl Line {
         self.id = Some(id);
         self
     }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
+}
+
+/// Indices of the visible part of `points`, reduced to the first, lowest, highest and last point
+/// of every pixel column (M4 decimation), which draws the same line as all points.
+///
+/// Returns `None` if there are too few points to be worth it, or they aren't sorted by X.
+/// `is_sorted_by_x` is only asked if there are enough points.
+fn decimated_indices(
+    points: &[PlotPoint],
+    is_sorted_by_x: impl FnOnce() -> bool,
+    transform: &PlotTransform,
+) -> Option<Vec<usize>> {
+    let columns = transform.frame().width().max(1.0) as usize;
+    if points.len() <= 4 * columns || !is_sorted_by_x() {
+        return None;
+    }
+
+    // Keep one point beyond each side, so the line still runs to the edges of the frame.
+    let bounds = transform.bounds();
+    let start = points
+        .partition_point(|p| p.x < bounds.min[0])
+        .saturating_sub(1);
+    let end = (points.partition_point(|p| p.x <= bounds.max[0]) + 1).min(points.len());
+
+    let mut indices = Vec::with_capacity(4 * (columns + 2));
+    let mut column = None;
+    // (index, position) of the first, lowest, highest and last point of the current column.
+    let mut extremes = [(0, Pos2::ZERO); 4];
+    let flush = |extremes: &mut [(usize, Pos2); 4], indices: &mut Vec<usize>| {
+        extremes.sort_by_key(|(i, _)| *i);
+        let mut last_index = None;
+        for &(i, _) in extremes.iter() {
+            if last_index != Some(i) {
+                indices.push(i);
+                last_index = Some(i);
+            }
+        }
+    };
+    for (i, point) in points.iter().enumerate().take(end).skip(start) {
+        let pos = transform.position_from_point(point);
+        let point_column = pos.x.floor() as i64;
+        if column != Some(point_column) {
+            if column.is_some() {
+                flush(&mut extremes, &mut indices);
+            }
+            column = Some(point_column);
+            extremes = [(i, pos); 4];
+            continue;
+        }
+        // Screen Y grows downwards, so the lowest value has the largest `pos.y`.
+        if pos.y > extremes[1].1.y {
+            extremes[1] = (i, pos);
+        }
+        if pos.y < extremes[2].1.y {
+            extremes[2] = (i, pos);
+        }
+        extremes[3] = (i, pos);
+    }
+    if column.is_some() {
+        flush(&mut extremes, &mut indices);
+    }
+    Some(indices)
 }
 
 /// Returns the x-coordinate of a possible intersection between a line segment from `p1` to `p2` and
@@ -491,14 +733,25 @@ impl PlotItem for Line {
             highlight,
             mut fill,
             style,
+            decimate,
+            error_bars,
             ..
         } = self;
 
-        let values_tf: Vec<_> = series
-            .points()
-            .iter()
-            .map(|v| transform.position_from_point(v))
-            .collect();
+        let points = series.points();
+        let decimated = decimate
+            .then(|| decimated_indices(points, || series.is_sorted_by_x(), transform))
+            .flatten();
+        let values_tf: Vec<_> = match &decimated {
+            Some(indices) => indices
+                .iter()
+                .map(|&i| transform.position_from_point(&points[i]))
+                .collect(),
+            None => points
+                .iter()
+                .map(|v| transform.position_from_point(v))
+                .collect(),
+        };
         let n_values = values_tf.len();
 
         // Fill the area between the line and a reference line, if required.
@@ -540,6 +793,16 @@ impl PlotItem for Line {
             mesh.colored_vertex(pos2(last.x, y), fill_color);
             shapes.push(Shape::Mesh(mesh));
         }
+        if let Some(error_bars) = error_bars {
+            error_bars.shapes(
+                points,
+                decimated.as_deref(),
+                transform,
+                stroke.color,
+                *highlight,
+                shapes,
+            );
+        }
         style.style_line(values_tf, *stroke, *highlight, shapes);
     }
 
@@ -568,12 +831,43 @@ impl PlotItem for Line {
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.series.bounds()
+        let mut bounds = self.series.bounds();
+        if let Some(error_bars) = &self.error_bars {
+            error_bars.extend_bounds(self.series.points(), &mut bounds);
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        match &self.series {
+            PlotPoints::Indexed(points) => points.find_closest(point, transform),
+            series => find_closest_point(series.points(), point, transform),
+        }
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        let errors = self
+            .error_bars
+            .as_ref()
+            .map(|error_bars| error_bars.at(elem.index))
+            .unwrap_or_default();
+        point_hover(self, elem, errors, shapes, cursors, plot, label_formatter);
     }
 
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
 
 /// A convex polygon.
@@ -585,6 +879,7 @@ pub struct Polygon {
     pub(super) fill_color: Option<Color32>,
     pub(super) style: LineStyle,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl Polygon {
@@ -597,6 +892,7 @@ impl Polygon {
             fill_color: None,
             style: LineStyle::Solid,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -655,6 +951,15 @@ impl Polygon {
         self.id = Some(id);
         self
     }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
 }
 
 impl PlotItem for Polygon {
@@ -713,6 +1018,10 @@ impl PlotItem for Polygon {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
 
 /// Text inside the plot.
@@ -725,6 +1034,7 @@ pub struct Text {
     pub(super) color: Color32,
     pub(super) anchor: Align2,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl Text {
@@ -737,6 +1047,7 @@ impl Text {
             color: Color32::TRANSPARENT,
             anchor: Align2::CENTER_CENTER,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -780,6 +1091,15 @@ impl Text {
         self.id = Some(id);
         self
     }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
 }
 
 impl PlotItem for Text {
@@ -840,6 +1160,10 @@ impl PlotItem for Text {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
 
 /// A set of points.
@@ -862,7 +1186,9 @@ pub struct Points {
     pub(super) highlight: bool,
 
     pub(super) stems: Option<f32>,
+    pub(super) error_bars: Option<ErrorBars>,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl Points {
@@ -876,7 +1202,9 @@ impl Points {
             name: Default::default(),
             highlight: false,
             stems: None,
+            error_bars: None,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -922,6 +1250,13 @@ impl Points {
         self
     }
 
+    /// Draw error bars at the points, see [`ErrorBars`].
+    #[inline]
+    pub fn error_bars(mut self, error_bars: ErrorBars) -> Self {
+        self.error_bars = Some(error_bars);
+        self
+    }
+
     /// Name of this set of points.
     ///
     /// This name will show up in the plot legend, if legends are turned on.
@@ -941,6 +1276,15 @@ impl Points {
         self.id = Some(id);
         self
     }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
 }
 
 impl PlotItem for Points {
@@ -957,6 +1301,7 @@ impl PlotItem for Points {
             mut radius,
             highlight,
             stems,
+            error_bars,
             ..
         } = self;
 
@@ -975,6 +1320,10 @@ impl PlotItem for Points {
             stem_stroke.width *= 2.0;
         }
 
+        if let Some(error_bars) = error_bars {
+            error_bars.shapes(series.points(), None, transform, *color, *highlight, shapes);
+        }
+
         let y_reference = stems.map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
 
         series
@@ -1097,12 +1446,43 @@ impl PlotItem for Points {
     }
 
     fn bounds(&self) -> PlotBounds {
-        self.series.bounds()
+        let mut bounds = self.series.bounds();
+        if let Some(error_bars) = &self.error_bars {
+            error_bars.extend_bounds(self.series.points(), &mut bounds);
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        match &self.series {
+            PlotPoints::Indexed(points) => points.find_closest(point, transform),
+            series => find_closest_point(series.points(), point, transform),
+        }
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        let errors = self
+            .error_bars
+            .as_ref()
+            .map(|error_bars| error_bars.at(elem.index))
+            .unwrap_or_default();
+        point_hover(self, elem, errors, shapes, cursors, plot, label_formatter);
     }
 
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
 
 /// A set of arrows.
@@ -1114,6 +1494,7 @@ pub struct Arrows {
     pub(super) name: String,
     pub(super) highlight: bool,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl Arrows {
@@ -1126,6 +1507,7 @@ impl Arrows {
             name: Default::default(),
             highlight: false,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -1169,6 +1551,15 @@ impl Arrows {
         self.id = Some(id);
         self
     }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
 }
 
 impl PlotItem for Arrows {
@@ -1248,6 +1639,10 @@ impl PlotItem for Arrows {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
 
 /// An image in the plot.
@@ -1263,6 +1658,7 @@ pub struct PlotImage {
     pub(super) highlight: bool,
     pub(super) name: String,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl PlotImage {
@@ -1283,6 +1679,7 @@ impl PlotImage {
             bg_fill: Default::default(),
             tint: Color32::WHITE,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -1333,6 +1730,15 @@ impl PlotImage {
         self.rotation = angle;
         self
     }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
 }
 
 impl PlotItem for PlotImage {
@@ -1434,6 +1840,10 @@ impl PlotItem for PlotImage {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -1449,6 +1859,7 @@ pub struct BarChart {
 
     highlight: bool,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl BarChart {
@@ -1461,6 +1872,7 @@ impl BarChart {
             element_formatter: None,
             highlight: false,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -1567,6 +1979,15 @@ impl BarChart {
         self.id = Some(id);
         self
     }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
 }
 
 impl PlotItem for BarChart {
@@ -1626,9 +2047,17 @@ impl PlotItem for BarChart {
         bar.add_rulers_and_text(self, plot, shapes, cursors);
     }
 
+    fn snap_value(&self, elem: &ClosestElem, _: PlotPoint) -> Option<PlotPoint> {
+        self.bars.get(elem.index).map(Bar::tip)
+    }
+
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
 
 /// A diagram containing a series of [`BoxElem`] elements.
@@ -1642,6 +2071,7 @@ pub struct BoxPlot {
 
     highlight: bool,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl BoxPlot {
@@ -1654,6 +2084,7 @@ impl BoxPlot {
             element_formatter: None,
             highlight: false,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -1728,6 +2159,15 @@ impl BoxPlot {
         self.id = Some(id);
         self
     }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
 }
 
 impl PlotItem for BoxPlot {
@@ -1790,6 +2230,223 @@ impl PlotItem for BoxPlot {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
+}
+
+/// A series of [`Candle`] elements, i.e. a candlestick or OHLC price chart.
+pub struct Candlestick {
+    pub(super) candles: RingBuffer<Candle>,
+    pub(super) name: String,
+    pub(super) up_color: Color32,
+    pub(super) down_color: Color32,
+    pub(super) hollow: bool,
+    pub(super) wick_width: f32,
+    pub(super) style: CandleStyle,
+
+    /// A custom element formatter
+    pub(super) element_formatter: Option<Box<dyn Fn(&Candle, &Candlestick) -> String>>,
+
+    highlight: bool,
+    id: Option<Id>,
+    y_axis: Option<Id>,
+}
+
+impl Candlestick {
+    /// Create a candlestick series from `candles`.
+    pub fn new(candles: Vec<Candle>) -> Self {
+        Self::streaming(candles.into())
+    }
+
+    /// Create a candlestick series from a shared buffer of live candles, without copying them.
+    pub fn streaming(candles: RingBuffer<Candle>) -> Self {
+        Self {
+            candles,
+            name: String::new(),
+            up_color: Color32::from_rgb(38, 166, 154),
+            down_color: Color32::from_rgb(239, 83, 80),
+            hollow: false,
+            wick_width: 1.0,
+            style: CandleStyle::default(),
+            element_formatter: None,
+            highlight: false,
+            id: None,
+            y_axis: None,
+        }
+    }
+
+    /// Color of candles that closed at or above their open. This is the color that shows up in
+    /// the legend.
+    #[inline]
+    pub fn up_color(mut self, color: impl Into<Color32>) -> Self {
+        self.up_color = color.into();
+        self
+    }
+
+    /// Color of candles that closed below their open.
+    #[inline]
+    pub fn down_color(mut self, color: impl Into<Color32>) -> Self {
+        self.down_color = color.into();
+        self
+    }
+
+    /// Draw the bodies of rising candles as outlines only ("hollow candles").
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn hollow(mut self, hollow: bool) -> Self {
+        self.hollow = hollow;
+        self
+    }
+
+    /// Stroke width of the wicks, or of the whole bar in [`CandleStyle::OhlcBars`] style.
+    #[inline]
+    pub fn wick_width(mut self, width: impl Into<f32>) -> Self {
+        self.wick_width = width.into();
+        self
+    }
+
+    /// Set the drawing style. Default is [`CandleStyle::Candles`].
+    #[inline]
+    pub fn style(mut self, style: CandleStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the width of all candle bodies in plot units.
+    ///
+    /// This modifies the candles, so a shared [`RingBuffer`] is copied. For live data, rather
+    /// set [`Candle::width`] when pushing the candles.
+    #[inline]
+    pub fn width(mut self, width: f64) -> Self {
+        for candle in self.candles.as_mut_slice() {
+            candle.width = width;
+        }
+        self
+    }
+
+    /// Name of this series.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on. Multiple series may
+    /// share the same name, in which case they will also share an entry in the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Highlight all plot elements.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Add a custom way to format an element.
+    /// Can be used to display a set number of decimals or custom labels.
+    #[inline]
+    pub fn element_formatter(mut self, formatter: Box<dyn Fn(&Candle, &Self) -> String>) -> Self {
+        self.element_formatter = Some(formatter);
+        self
+    }
+
+    /// Set the series' id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
+}
+
+impl PlotItem for Candlestick {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        for candle in &self.candles {
+            candle.add_shapes(self, transform, self.highlight, shapes);
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {
+        // nothing to do
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.up_color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for candle in &self.candles {
+            bounds.merge(&candle.bounds());
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        find_closest_rect(self.candles.iter(), point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        _: &LabelFormatter,
+    ) {
+        let candle = &self.candles[elem.index];
+
+        candle.add_shapes(self, plot.transform, true, shapes);
+        candle.add_rulers_and_text(self, plot, shapes, cursors);
+    }
+
+    fn first_value(&self, x_range: &RangeInclusive<f64>) -> Option<PlotPoint> {
+        self.candles
+            .iter()
+            .filter(|candle| x_range.contains(&candle.time))
+            .min_by_key(|candle| candle.time.ord())
+            .map(|candle| PlotPoint::new(candle.time, candle.close))
+    }
+
+    fn snap_value(&self, elem: &ClosestElem, pointer: PlotPoint) -> Option<PlotPoint> {
+        let candle = self.candles.get(elem.index)?;
+        Some(PlotPoint::new(candle.time, candle.nearest_price(pointer.y)))
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -1872,7 +2529,7 @@ fn add_rulers_and_text(
 
         if show_values {
             text.push('\n');
-            text.push_str(&elem.default_values_format(plot.transform));
+            text.push_str(&elem.default_values_format(plot));
         }
 
         text
@@ -1894,11 +2551,12 @@ fn add_rulers_and_text(
 }
 
 /// Draws a cross of horizontal and vertical ruler at the `pointer` position.
-/// `value` is used to for text displaying X/Y coordinates.
+/// `value` is used to for text displaying X/Y coordinates, followed by their `errors`.
 #[allow(clippy::too_many_arguments)]
 pub(super) fn rulers_at_value(
     pointer: Pos2,
     value: PlotPoint,
+    errors: PointErrors,
     name: &str,
     plot: &PlotConfig<'_>,
     shapes: &mut Vec<Shape>,
@@ -1919,20 +2577,27 @@ pub(super) fn rulers_at_value(
     };
 
     let text = {
-        let scale = plot.transform.dvalue_dpos();
-        let x_decimals = ((-scale[0].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
-        let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
-        if let Some(custom_label) = label_formatter {
-            custom_label(name, &value)
+        let scale = plot.transform.dvalue_dpos_at(&value);
+        let x_decimals = step_decimals(scale[0]).at_least(1);
+        let y_decimals = step_decimals(scale[1]).at_least(1);
+        let x_error = errors.format_x(x_decimals);
+        let y_error = errors.format_y(value.y, y_decimals, plot);
+        let x_text = plot.format_x(value.x, x_decimals) + &x_error;
+        let y_text = plot.format_y(value.y, y_decimals) + &y_error;
+        if let Some(mut text) = plot.custom_label(label_formatter, name, value) {
+            // The formatter only sees the value, so the errors follow on their own lines.
+            for (show, axis, error) in [(plot.show_x, 'x', x_error), (plot.show_y, 'y', y_error)] {
+                if show && !error.is_empty() {
+                    text.push_str(&format!("\n{axis}{error}"));
+                }
+            }
+            text
         } else if plot.show_x && plot.show_y {
-            format!(
-                "{}x = {:.*}\ny = {:.*}",
-                prefix, x_decimals, value.x, y_decimals, value.y
-            )
+            format!("{prefix}x = {x_text}\ny = {y_text}")
         } else if plot.show_x {
-            format!("{}x = {:.*}", prefix, x_decimals, value.x)
+            format!("{prefix}x = {x_text}")
         } else if plot.show_y {
-            format!("{}y = {:.*}", prefix, y_decimals, value.y)
+            format!("{prefix}y = {y_text}")
         } else {
             unreachable!()
         }
@@ -1951,6 +2616,22 @@ pub(super) fn rulers_at_value(
     });
 }
 
+fn find_closest_point(
+    points: &[PlotPoint],
+    point: Pos2,
+    transform: &PlotTransform,
+) -> Option<ClosestElem> {
+    points
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let pos = transform.position_from_point(value);
+            let dist_sq = point.distance_sq(pos);
+            ClosestElem { index, dist_sq }
+        })
+        .min_by_key(|e| e.dist_sq.ord())
+}
+
 fn find_closest_rect<'a, T>(
     rects: impl IntoIterator<Item = &'a T>,
     point: Pos2,
@@ -1970,3 +2651,57 @@ where
         })
         .min_by_key(|e| e.dist_sq.ord())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn decimation_keeps_column_extremes() {
+        let frame = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+        let bounds = PlotBounds::from_min_max([0.0, -2.0], [1.0, 2.0]);
+        let transform = PlotTransform::new(frame, bounds, false, false);
+        let points: Vec<PlotPoint> = (0..10_000)
+            .map(|i| {
+                let x = i as f64 / 10_000.0;
+                PlotPoint::new(x, (x * 40.0).sin() + (i as f64 * 0.77).sin() * 0.5)
+            })
+            .collect();
+
+        let decimated: Vec<Pos2> = decimated_indices(&points, || true, &transform)
+            .unwrap()
+            .into_iter()
+            .map(|i| transform.position_from_point(&points[i]))
+            .collect();
+        assert!(decimated.len() <= 4 * 102);
+
+        // Every pixel column still reaches the lowest and highest point in it.
+        let mut columns: BTreeMap<i64, (f32, f32)> = BTreeMap::new();
+        for point in &points {
+            let pos = transform.position_from_point(point);
+            let column = columns
+                .entry(pos.x.floor() as i64)
+                .or_insert((f32::INFINITY, f32::NEG_INFINITY));
+            *column = (column.0.min(pos.y), column.1.max(pos.y));
+        }
+        for (column, (min, max)) in columns {
+            let ys: Vec<f32> = decimated
+                .iter()
+                .filter(|pos| pos.x.floor() as i64 == column)
+                .map(|pos| pos.y)
+                .collect();
+            assert!(ys.contains(&min) && ys.contains(&max), "column {column}");
+        }
+    }
+
+    #[test]
+    fn decimation_skips_unsorted_and_small_series() {
+        let frame = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+        let transform = PlotTransform::new(frame, PlotBounds::new_symmetrical(1.0), false, false);
+        let points = vec![PlotPoint::new(0.0, 0.0); 1_000];
+        assert!(decimated_indices(&points, || false, &transform).is_none());
+        assert!(decimated_indices(&points[..10], || unreachable!(), &transform).is_none());
+    }
+}
@@ -12,6 +12,7 @@ pub struct HRay {
     pub(super) highlight: bool,
     pub(super) style: LineStyle,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl HRay {
@@ -23,6 +24,7 @@ impl HRay {
             highlight: false,
             style: LineStyle::Solid,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -68,6 +70,12 @@ impl HRay {
         self.id = Some(id);
         self
     }
+
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
 }
 
 impl PlotItem for HRay {
@@ -125,6 +133,10 @@ impl PlotItem for HRay {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -136,6 +148,7 @@ pub struct LinkedYHRay {
     pub(super) highlight: bool,
     pub(super) style: LineStyle,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl LinkedYHRay {
@@ -148,6 +161,7 @@ impl LinkedYHRay {
             highlight: false,
             style: LineStyle::Solid,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -187,6 +201,12 @@ impl LinkedYHRay {
         self.name = name.to_string();
         self
     }
+
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
 }
 
 impl PlotItem for LinkedYHRay {
@@ -247,6 +267,10 @@ impl PlotItem for LinkedYHRay {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
 
 #[derive(Clone)]
@@ -259,6 +283,7 @@ pub struct LinkedYText {
     pub(super) color: Color32,
     pub(super) anchor: Align2,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl LinkedYText {
@@ -272,6 +297,7 @@ impl LinkedYText {
             color: Color32::TRANSPARENT,
             anchor: Align2::CENTER_CENTER,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -299,6 +325,12 @@ impl LinkedYText {
         self.name = name.to_string();
         self
     }
+
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
 }
 
 impl PlotItem for LinkedYText {
@@ -365,6 +397,10 @@ impl PlotItem for LinkedYText {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
 
 pub struct LinkedYPolygon {
@@ -376,6 +412,7 @@ pub struct LinkedYPolygon {
     pub(super) fill_color: Option<Color32>,
     pub(super) style: LineStyle,
     id: Option<Id>,
+    y_axis: Option<Id>,
 }
 
 impl LinkedYPolygon {
@@ -389,6 +426,7 @@ impl LinkedYPolygon {
             fill_color: None,
             style: LineStyle::Solid,
             id: None,
+            y_axis: None,
         }
     }
 
@@ -428,6 +466,12 @@ impl LinkedYPolygon {
         self.name = name.to_string();
         self
     }
+
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
 }
 
 impl PlotItem for LinkedYPolygon {
@@ -490,4 +534,8 @@ impl PlotItem for LinkedYPolygon {
     fn id(&self) -> Option<Id> {
         self.id
     }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
 }
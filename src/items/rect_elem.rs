@@ -1,9 +1,9 @@
 use egui::emath::NumExt as _;
 use egui::epaint::{Color32, Rgba, Stroke};
 
-use crate::transform::{PlotBounds, PlotTransform};
+use crate::transform::PlotBounds;
 
-use super::{Orientation, PlotPoint};
+use super::{Orientation, PlotConfig, PlotPoint};
 
 /// Trait that abstracts from rectangular 'Value'-like elements, such as bars or boxes
 pub(super) trait RectElement {
@@ -50,7 +50,7 @@ pub(super) trait RectElement {
     }
 
     /// Debug formatting for hovered-over value, if none is specified by the user
-    fn default_values_format(&self, transform: &PlotTransform) -> String;
+    fn default_values_format(&self, plot: &PlotConfig<'_>) -> String;
 }
 
 // ----------------------------------------------------------------------------
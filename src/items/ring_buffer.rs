@@ -0,0 +1,248 @@
+use std::{fmt, ops::Deref, sync::Arc};
+
+/// A fixed-capacity buffer of the newest values of a live data stream.
+///
+/// Pushing to a full buffer drops the oldest value. The values are always contiguous, so a
+/// [`crate::Line`], [`crate::Points`] or [`crate::Candlestick::streaming`] can show them as a
+/// slice.
+///
+/// Clones share the values, so keep the buffer in your app state and pass a clone to the plot
+/// every frame instead of rebuilding [`crate::PlotPoints`]. Modifying the buffer only copies the
+/// values if a clone is still alive, which isn't the case once the plot has been shown.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Line, Plot, PlotPoint, RingBuffer};
+///
+/// // Typically stored in your app state.
+/// let mut prices: RingBuffer<PlotPoint> = RingBuffer::new(10_000);
+/// for i in 0..20_000 {
+///     prices.push([i as f64, (i as f64 * 0.01).sin()]);
+/// }
+/// assert_eq!(prices.len(), 10_000);
+/// assert_eq!(prices[0].x, 10_000.0);
+///
+/// Plot::new("live")
+///     .follow_latest(true)
+///     .show(ui, |plot_ui| plot_ui.line(Line::new(prices.clone())));
+/// # });
+/// ```
+pub struct RingBuffer<T> {
+    values: Arc<Vec<T>>,
+
+    /// Index of the oldest value in `values`. Dropped values stay in front of it until there are
+    /// `capacity` of them, so dropping is cheap and the values stay contiguous.
+    start: usize,
+    capacity: usize,
+}
+
+impl<T: Clone> RingBuffer<T> {
+    /// An empty buffer that holds up to `capacity` values.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            values: Arc::new(Vec::with_capacity(2 * capacity)),
+            start: 0,
+            capacity,
+        }
+    }
+
+    /// The most values the buffer holds.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Append a value, dropping the oldest one if the buffer is full.
+    pub fn push(&mut self, value: impl Into<T>) {
+        if self.len() == self.capacity {
+            self.start += 1;
+        }
+        self.values_mut().push(value.into());
+    }
+
+    /// Remove and return the oldest value.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let value = self.first().cloned()?;
+        self.start += 1;
+        Some(value)
+    }
+
+    /// The newest value, e.g. to update the candle that is still open.
+    pub fn last_mut(&mut self) -> Option<&mut T> {
+        self.as_mut_slice().last_mut()
+    }
+
+    /// The values, oldest first.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let start = self.start;
+        &mut self.values_mut()[start..]
+    }
+
+    /// Remove all values.
+    pub fn clear(&mut self) {
+        self.start = 0;
+        match Arc::get_mut(&mut self.values) {
+            Some(values) => values.clear(),
+            None => self.values = Arc::new(Vec::with_capacity(2 * self.capacity)),
+        }
+    }
+
+    /// The values for modifying, after removing the dropped ones once there are many of them.
+    fn values_mut(&mut self) -> &mut Vec<T> {
+        if Arc::get_mut(&mut self.values).is_none() {
+            // Still shared with a clone: copy only the live values.
+            let mut values = Vec::with_capacity(2 * self.capacity);
+            values.extend_from_slice(self.as_slice());
+            self.values = Arc::new(values);
+            self.start = 0;
+        }
+        let values = Arc::get_mut(&mut self.values).expect("the values aren't shared anymore");
+        if self.start > 0 && (self.start >= self.capacity || values.len() == values.capacity()) {
+            values.drain(..self.start);
+            self.start = 0;
+        }
+        values
+    }
+}
+
+impl<T> RingBuffer<T> {
+    /// The values, oldest first.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.values[self.start..]
+    }
+}
+
+impl<T> Deref for RingBuffer<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RingBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<T> Clone for RingBuffer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            values: Arc::clone(&self.values),
+            start: self.start,
+            capacity: self.capacity,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for RingBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RingBuffer")
+            .field("values", &self.as_slice())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+/// A full buffer holding `values`.
+impl<T> From<Vec<T>> for RingBuffer<T> {
+    fn from(mut values: Vec<T>) -> Self {
+        let capacity = values.len().max(1);
+        values.reserve_exact(2 * capacity - values.len());
+        Self {
+            capacity,
+            values: Arc::new(values),
+            start: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(capacity: usize, values: std::ops::Range<i32>) -> RingBuffer<i32> {
+        let mut buffer = RingBuffer::new(capacity);
+        for value in values {
+            buffer.push(value);
+        }
+        buffer
+    }
+
+    #[test]
+    fn order_is_kept_across_compaction() {
+        let mut buffer = buffer(3, 0..2);
+        // Many times the capacity, so the dropped values are removed again and again.
+        for value in 2..100 {
+            buffer.push(value);
+            let len = buffer.len() as i32;
+            assert_eq!(
+                buffer.as_slice(),
+                (value + 1 - len..=value).collect::<Vec<_>>()
+            );
+            assert!(buffer.values.len() <= 2 * buffer.capacity());
+        }
+        assert_eq!(buffer.pop_front(), Some(97));
+        buffer.push(100);
+        assert_eq!(buffer.as_slice(), [98, 99, 100]);
+        *buffer.last_mut().unwrap() = 0;
+        assert_eq!(buffer.as_slice(), [98, 99, 0]);
+    }
+
+    #[test]
+    fn clones_are_unaffected_by_later_changes() {
+        let mut buffer = buffer(4, 0..4);
+        let clone = buffer.clone();
+        assert!(Arc::ptr_eq(&buffer.values, &clone.values));
+
+        buffer.push(4);
+        assert_eq!(buffer.pop_front(), Some(1));
+        *buffer.last_mut().unwrap() = 40;
+        assert_eq!(buffer.as_slice(), [2, 3, 40]);
+        assert_eq!(clone.as_slice(), [0, 1, 2, 3]);
+
+        // Popping only moves the start, so a clone made before it keeps the value.
+        let clone = buffer.clone();
+        assert_eq!(buffer.pop_front(), Some(2));
+        assert_eq!(clone.as_slice(), [2, 3, 40]);
+
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert_eq!(clone.as_slice(), [2, 3, 40]);
+    }
+
+    #[test]
+    fn unshared_buffers_are_modified_in_place() {
+        let mut buffer = buffer(4, 0..2);
+        let values = Arc::as_ptr(&buffer.values);
+        buffer.push(2);
+        buffer.clear();
+        buffer.push(3);
+        assert_eq!(Arc::as_ptr(&buffer.values), values);
+        assert_eq!(buffer.as_slice(), [3]);
+    }
+
+    #[test]
+    fn from_vec_is_full() {
+        let mut buffer = RingBuffer::from(vec![1, 2, 3]);
+        assert_eq!(buffer.capacity(), 3);
+        buffer.push(4);
+        assert_eq!(buffer.as_slice(), [2, 3, 4]);
+
+        let mut empty = RingBuffer::from(Vec::<i32>::new());
+        assert_eq!(empty.capacity(), 1);
+        empty.push(1);
+        empty.push(2);
+        assert_eq!(empty.as_slice(), [2]);
+        assert_eq!(empty.pop_front(), Some(2));
+        assert_eq!(empty.pop_front(), None);
+    }
+}
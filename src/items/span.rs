@@ -0,0 +1,579 @@
+use std::ops::RangeInclusive;
+
+use egui::{
+    emath::NumExt as _, pos2, vec2, Align2, Color32, Id, Pos2, Rect, Shape, Stroke, TextStyle, Ui,
+};
+
+use crate::{
+    Axis, Cursor, IndexedPoints, LabelFormatter, LineStyle, PlotBounds, PlotPoint, PlotTransform,
+};
+
+use super::{step_decimals, ClosestElem, PlotConfig, PlotGeometry, PlotItem};
+
+/// Alpha of the fill derived from the stroke color, see [`HSpan::fill_color`].
+const FILL_ALPHA: f32 = 0.15;
+
+/// A horizontal band between two Y values, filling the full width of the plot.
+///
+/// Either edge may be infinite, e.g. `HSpan::new(70.0, f64::INFINITY)` shades everything above
+/// 70. Like [`crate::HLine`], only the finite edges count towards the plot bounds.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{HSpan, Plot};
+///
+/// Plot::new("target").show(ui, |plot_ui| {
+///     plot_ui.hspan(HSpan::new(120.0, 135.0).name("Target").label("Target zone"));
+/// });
+/// # });
+/// ```
+pub struct HSpan {
+    pub(crate) span: Span,
+    y_axis: Option<Id>,
+}
+
+impl HSpan {
+    /// A span between `y1` and `y2`, in any order.
+    pub fn new(y1: impl Into<f64>, y2: impl Into<f64>) -> Self {
+        Self {
+            span: Span::new(Axis::Y, y1.into(), y2.into()),
+            y_axis: None,
+        }
+    }
+
+    /// Highlight this span in the plot by scaling up the border and the fill.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.span.highlight = highlight;
+        self
+    }
+
+    /// Stroke of the border. A width of zero draws no border.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.span.stroke = stroke.into();
+        self
+    }
+
+    /// Border width. A high value means the border thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.span.stroke.width = width.into();
+        self
+    }
+
+    /// Border color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.span.stroke.color = color.into();
+        self
+    }
+
+    /// Fill color. Default is a transparent version of the border color.
+    #[inline]
+    pub fn fill_color(mut self, color: impl Into<Color32>) -> Self {
+        self.span.fill_color = Some(color.into());
+        self
+    }
+
+    /// Set the border's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.span.style = style;
+        self
+    }
+
+    /// Text drawn inside the span, e.g. the name of a zone or a session.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.span.label = Some(label.to_string());
+        self
+    }
+
+    /// Where the label is placed within the visible part of the span.
+    /// Default is `Align2::LEFT_TOP`.
+    #[inline]
+    pub fn label_anchor(mut self, anchor: Align2) -> Self {
+        self.span.label_anchor = anchor;
+        self
+    }
+
+    /// Name of this span.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.span.name = name.to_string();
+        self
+    }
+
+    /// Set the span's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.span.id = Some(id);
+        self
+    }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
+}
+
+impl PlotItem for HSpan {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        self.span.shapes(ui, transform, shapes);
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        &self.span.name
+    }
+
+    fn color(&self) -> Color32 {
+        self.span.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.span.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.span.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.span.bounds()
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.span.find_closest(point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        _elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        self.span.on_hover(shapes, cursors, plot, label_formatter);
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.span.id
+    }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
+}
+
+/// A vertical band between two X values, filling the full height of the plot.
+///
+/// Either edge may be infinite, e.g. `VSpan::new(f64::NEG_INFINITY, 0.0)` shades everything left
+/// of 0. Like [`crate::VLine`], only the finite edges count towards the plot bounds.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, VSpan};
+///
+/// Plot::new("sessions").show(ui, |plot_ui| {
+///     plot_ui.vspan(VSpan::new(9.5, 16.0).name("Regular hours").label("RTH"));
+/// });
+/// # });
+/// ```
+pub struct VSpan {
+    pub(crate) span: Span,
+}
+
+impl VSpan {
+    /// A span between `x1` and `x2`, in any order.
+    pub fn new(x1: impl Into<f64>, x2: impl Into<f64>) -> Self {
+        Self {
+            span: Span::new(Axis::X, x1.into(), x2.into()),
+        }
+    }
+
+    /// Highlight this span in the plot by scaling up the border and the fill.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.span.highlight = highlight;
+        self
+    }
+
+    /// Stroke of the border. A width of zero draws no border.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.span.stroke = stroke.into();
+        self
+    }
+
+    /// Border width. A high value means the border thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.span.stroke.width = width.into();
+        self
+    }
+
+    /// Border color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.span.stroke.color = color.into();
+        self
+    }
+
+    /// Fill color. Default is a transparent version of the border color.
+    #[inline]
+    pub fn fill_color(mut self, color: impl Into<Color32>) -> Self {
+        self.span.fill_color = Some(color.into());
+        self
+    }
+
+    /// Set the border's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.span.style = style;
+        self
+    }
+
+    /// Text drawn inside the span, e.g. the name of a zone or a session.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.span.label = Some(label.to_string());
+        self
+    }
+
+    /// Where the label is placed within the visible part of the span.
+    /// Default is `Align2::LEFT_TOP`.
+    #[inline]
+    pub fn label_anchor(mut self, anchor: Align2) -> Self {
+        self.span.label_anchor = anchor;
+        self
+    }
+
+    /// Name of this span.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    ///
+    /// Multiple plot items may share the same name, in which case they will also share an entry in
+    /// the legend.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.span.name = name.to_string();
+        self
+    }
+
+    /// Set the span's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.span.id = Some(id);
+        self
+    }
+}
+
+impl PlotItem for VSpan {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        self.span.shapes(ui, transform, shapes);
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        &self.span.name
+    }
+
+    fn color(&self) -> Color32 {
+        self.span.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.span.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.span.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        self.span.bounds()
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        self.span.find_closest(point, transform)
+    }
+
+    fn on_hover(
+        &self,
+        _elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        self.span.on_hover(shapes, cursors, plot, label_formatter);
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.span.id
+    }
+}
+
+/// What [`HSpan`] and [`VSpan`] share: a range across one axis and how it is drawn.
+pub(crate) struct Span {
+    axis: Axis,
+    range: [f64; 2],
+    pub(crate) stroke: Stroke,
+    fill_color: Option<Color32>,
+    style: LineStyle,
+    label: Option<String>,
+    label_anchor: Align2,
+    name: String,
+    highlight: bool,
+    id: Option<Id>,
+}
+
+impl Span {
+    fn new(axis: Axis, a: f64, b: f64) -> Self {
+        Self {
+            axis,
+            range: [a.min(b), a.max(b)],
+            stroke: Stroke::new(1.0, Color32::TRANSPARENT),
+            fill_color: None,
+            style: LineStyle::Solid,
+            label: None,
+            label_anchor: Align2::LEFT_TOP,
+            name: String::default(),
+            highlight: false,
+            id: None,
+        }
+    }
+
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let Some(rect) = visible_rect(transform, self.axis, self.range) else {
+            return;
+        };
+
+        let fill_alpha = if self.highlight {
+            2.0 * FILL_ALPHA
+        } else {
+            FILL_ALPHA
+        };
+        let fill_color = match self.fill_color {
+            Some(color) if self.highlight => {
+                let [r, g, b, a] = color.to_srgba_unmultiplied();
+                Color32::from_rgba_unmultiplied(r, g, b, a.saturating_mul(2))
+            }
+            Some(color) => color,
+            None => self.stroke.color.linear_multiply(fill_alpha),
+        };
+        shapes.push(Shape::rect_filled(rect, 0.0, fill_color));
+
+        if self.stroke.width > 0.0 {
+            for [a, b] in visible_edges(transform, self.axis, self.range) {
+                // Round to minimize aliasing:
+                let points = vec![
+                    ui.painter().round_pos_to_pixels(a),
+                    ui.painter().round_pos_to_pixels(b),
+                ];
+                self.style
+                    .style_line(points, self.stroke, self.highlight, shapes);
+            }
+        }
+
+        if let Some(label) = &self.label {
+            let font_id = TextStyle::Small.resolve(ui.style());
+            ui.fonts(|f| {
+                shapes.push(Shape::text(
+                    f,
+                    self.label_anchor.pos_in_rect(&rect.shrink(3.0)),
+                    self.label_anchor,
+                    label,
+                    font_id,
+                    self.stroke.color,
+                ));
+            });
+        }
+    }
+
+    /// Only the finite edges count, like the value of an [`crate::HLine`] or [`crate::VLine`].
+    fn bounds(&self) -> PlotBounds {
+        let axis = self.axis as usize;
+        let mut bounds = PlotBounds::NOTHING;
+        for value in self.range.into_iter().filter(|value| value.is_finite()) {
+            bounds.min[axis] = bounds.min[axis].min(value);
+            bounds.max[axis] = bounds.max[axis].max(value);
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let rect = visible_rect(transform, self.axis, self.range)?;
+        let pos = match self.axis {
+            Axis::X => point.x,
+            Axis::Y => point.y,
+        };
+        let dist = self
+            .range
+            .into_iter()
+            .map(|value| (position_from_value(transform, self.axis, value) - pos).abs())
+            .fold(f32::INFINITY, f32::min);
+        let dist_sq = if rect.contains(point) {
+            dist.powi(2).at_most(IndexedPoints::HOVER_RADIUS.powi(2))
+        } else {
+            dist.powi(2)
+        };
+        Some(ClosestElem { index: 0, dist_sq })
+    }
+
+    fn on_hover(
+        &self,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        let (axis, range) = (self.axis, self.range);
+        let Some(rect) = visible_rect(plot.transform, axis, range) else {
+            return;
+        };
+        for [a, b] in visible_edges(plot.transform, axis, range) {
+            LineStyle::Solid.style_line(vec![a, b], self.stroke, true, shapes);
+        }
+
+        let show = match axis {
+            Axis::X => plot.show_x,
+            Axis::Y => plot.show_y,
+        };
+        if !show {
+            return;
+        }
+        for value in range.into_iter().filter(|value| value.is_finite()) {
+            cursors.push(match axis {
+                Axis::X => Cursor::Vertical { x: value },
+                Axis::Y => Cursor::Horizontal { y: value },
+            });
+        }
+
+        // A custom label per finite edge, at the middle of the visible part of the span.
+        let center = plot.transform.value_from_position(rect.center());
+        let custom_labels: Option<Vec<String>> = range
+            .into_iter()
+            .filter(|value| value.is_finite())
+            .map(|value| {
+                let edge = match axis {
+                    Axis::X => PlotPoint::new(value, center.y),
+                    Axis::Y => PlotPoint::new(center.x, value),
+                };
+                plot.custom_label(label_formatter, &self.name, edge)
+            })
+            .collect();
+        let text = match custom_labels {
+            Some(labels) if !labels.is_empty() => labels.join("\n"),
+            _ => self.default_label(plot),
+        };
+
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                rect.right_top() + vec2(-3.0, 3.0),
+                Align2::RIGHT_TOP,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+
+    /// The hover label without a custom [`LabelFormatter`].
+    fn default_label(&self, plot: &PlotConfig<'_>) -> String {
+        let axis = self.axis;
+        let decimals = step_decimals(plot.transform.dvalue_dpos()[axis as usize]).at_least(1);
+        let format = |value: f64| match axis {
+            Axis::X => plot.format_x(value, decimals),
+            Axis::Y => plot.format_y(value, decimals),
+        };
+        let mut text = self.name.clone();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(&format!(
+            "{} = {} .. {}",
+            match axis {
+                Axis::X => "x",
+                Axis::Y => "y",
+            },
+            format(self.range[0]),
+            format(self.range[1])
+        ));
+        text
+    }
+}
+
+/// The screen coordinate of `value` on `axis`.
+fn position_from_value(transform: &PlotTransform, axis: Axis, value: f64) -> f32 {
+    match axis {
+        Axis::X => transform.position_from_point_x(value),
+        Axis::Y => transform.position_from_point_y(value),
+    }
+}
+
+/// The visible part of a span, or `None` if it is out of view.
+fn visible_rect(transform: &PlotTransform, axis: Axis, range: [f64; 2]) -> Option<Rect> {
+    let bounds = transform.bounds();
+    let min = range[0].max(bounds.min()[axis as usize]);
+    let max = range[1].min(bounds.max()[axis as usize]);
+    if min.is_nan() || max.is_nan() || min > max {
+        return None;
+    }
+
+    let (a, b) = (
+        position_from_value(transform, axis, min),
+        position_from_value(transform, axis, max),
+    );
+    let frame = *transform.frame();
+    Some(match axis {
+        Axis::X => Rect::from_x_y_ranges(a.min(b)..=a.max(b), frame.y_range()),
+        Axis::Y => Rect::from_x_y_ranges(frame.x_range(), a.min(b)..=a.max(b)),
+    })
+}
+
+/// The edges of a span that are in view, as lines across the plot.
+fn visible_edges(transform: &PlotTransform, axis: Axis, range: [f64; 2]) -> Vec<[Pos2; 2]> {
+    let bounds = transform.bounds();
+    let visible = bounds.min()[axis as usize]..=bounds.max()[axis as usize];
+    let frame = *transform.frame();
+    range
+        .into_iter()
+        .filter(|value| visible.contains(value))
+        .map(|value| {
+            let pos = position_from_value(transform, axis, value);
+            match axis {
+                Axis::X => [pos2(pos, frame.top()), pos2(pos, frame.bottom())],
+                Axis::Y => [pos2(frame.left(), pos), pos2(frame.right(), pos)],
+            }
+        })
+        .collect()
+}
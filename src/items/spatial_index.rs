@@ -0,0 +1,346 @@
+use std::sync::{Arc, OnceLock};
+
+use egui::{vec2, Pos2};
+
+use crate::{PlotPoint, PlotTransform};
+
+use super::values::ClosestElem;
+
+/// Points with a spatial index for fast hover hit-testing.
+///
+/// Hovering a plot searches the closest point of every [`crate::Line`] and [`crate::Points`]
+/// item. For owned [`crate::PlotPoints`] that is a scan over all points on every frame. Instead,
+/// create the points once, keep them, and pass a clone to the item every frame: clones share
+/// the points and an index that is built the first time the plot is hovered. Series sorted by X
+/// are searched with binary search, other series with a uniform grid.
+///
+/// Only points within [`HOVER_RADIUS`](Self::HOVER_RADIUS) of the pointer are found, which is
+/// all the plot considers for hovering anyway.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{IndexedPoints, Plot, Points};
+///
+/// // Typically stored in your app state and only rebuilt when the data changes.
+/// let scatter: IndexedPoints = (0..100_000)
+///     .map(|i| [(i as f64 * 0.37).sin(), (i as f64 * 0.91).cos()])
+///     .collect();
+///
+/// Plot::new("scatter").show(ui, |plot_ui| plot_ui.points(Points::new(scatter.clone())));
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct IndexedPoints {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    points: Vec<PlotPoint>,
+    sorted_by_x: OnceLock<bool>,
+    index: OnceLock<SpatialIndex>,
+}
+
+impl IndexedPoints {
+    /// Distance in ui points within which points are found.
+    pub const HOVER_RADIUS: f32 = 16.0;
+
+    /// Points to index, in the order of the series. The index itself is built lazily, the first
+    /// time the plot is hovered.
+    pub fn new(points: Vec<PlotPoint>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                points,
+                sorted_by_x: OnceLock::new(),
+                index: OnceLock::new(),
+            }),
+        }
+    }
+
+    /// The points, in the order they were given.
+    #[inline]
+    pub fn points(&self) -> &[PlotPoint] {
+        &self.inner.points
+    }
+
+    /// Whether the points are sorted by X. Only checked once for all clones.
+    pub(crate) fn is_sorted_by_x(&self) -> bool {
+        *self
+            .inner
+            .sorted_by_x
+            .get_or_init(|| is_sorted_by_x(self.points()))
+    }
+
+    /// The closest point within [`Self::HOVER_RADIUS`] of `pointer`.
+    pub(crate) fn find_closest(
+        &self,
+        pointer: Pos2,
+        transform: &PlotTransform,
+    ) -> Option<ClosestElem> {
+        let points = self.points();
+        let index = self
+            .inner
+            .index
+            .get_or_init(|| SpatialIndex::build(points, self.is_sorted_by_x()));
+
+        // The search area in plot values. The axes are monotonic, so this is a rectangle too.
+        let radius = vec2(Self::HOVER_RADIUS, Self::HOVER_RADIUS);
+        let corner_a = transform.value_from_position(pointer - radius);
+        let corner_b = transform.value_from_position(pointer + radius);
+        let min = PlotPoint::new(corner_a.x.min(corner_b.x), corner_a.y.min(corner_b.y));
+        let max = PlotPoint::new(corner_a.x.max(corner_b.x), corner_a.y.max(corner_b.y));
+
+        let closest = |candidates: &mut dyn Iterator<Item = usize>| {
+            candidates
+                .map(|index| {
+                    let pos = transform.position_from_point(&points[index]);
+                    ClosestElem {
+                        index,
+                        dist_sq: pointer.distance_sq(pos),
+                    }
+                })
+                .filter(|elem| elem.dist_sq <= Self::HOVER_RADIUS * Self::HOVER_RADIUS)
+                .min_by(|a, b| a.dist_sq.total_cmp(&b.dist_sq))
+        };
+
+        match index {
+            SpatialIndex::SortedX => {
+                let start = points.partition_point(|p| p.x < min.x);
+                let end = points.partition_point(|p| p.x <= max.x);
+                closest(&mut (start..end))
+            }
+            SpatialIndex::Grid(grid) => closest(&mut grid.candidates(min, max)),
+        }
+    }
+}
+
+impl From<Vec<PlotPoint>> for IndexedPoints {
+    fn from(points: Vec<PlotPoint>) -> Self {
+        Self::new(points)
+    }
+}
+
+impl FromIterator<[f64; 2]> for IndexedPoints {
+    fn from_iter<T: IntoIterator<Item = [f64; 2]>>(iter: T) -> Self {
+        Self::new(iter.into_iter().map(PlotPoint::from).collect())
+    }
+}
+
+pub(super) fn is_sorted_by_x(points: &[PlotPoint]) -> bool {
+    points.windows(2).all(|w| w[0].x <= w[1].x)
+}
+
+// ----------------------------------------------------------------------------
+
+enum SpatialIndex {
+    /// The points are sorted by X, search them with binary search.
+    SortedX,
+
+    Grid(Grid),
+}
+
+impl SpatialIndex {
+    fn build(points: &[PlotPoint], sorted_by_x: bool) -> Self {
+        if sorted_by_x {
+            Self::SortedX
+        } else {
+            Self::Grid(Grid::new(points))
+        }
+    }
+}
+
+/// Uniform grid over the bounding box of the points, with a few points per cell on average.
+struct Grid {
+    min: PlotPoint,
+    cell_size: [f64; 2],
+    columns: usize,
+    rows: usize,
+
+    /// `indices[cell_start[cell]..cell_start[cell + 1]]` are the points in `cell`.
+    cell_start: Vec<usize>,
+    indices: Vec<usize>,
+}
+
+impl Grid {
+    const POINTS_PER_CELL: usize = 8;
+
+    fn new(points: &[PlotPoint]) -> Self {
+        let finite = |p: &&PlotPoint| p.x.is_finite() && p.y.is_finite();
+        let mut min = PlotPoint::new(f64::INFINITY, f64::INFINITY);
+        let mut max = PlotPoint::new(f64::NEG_INFINITY, f64::NEG_INFINITY);
+        for p in points.iter().filter(finite) {
+            min = PlotPoint::new(min.x.min(p.x), min.y.min(p.y));
+            max = PlotPoint::new(max.x.max(p.x), max.y.max(p.y));
+        }
+
+        let side = ((points.len() / Self::POINTS_PER_CELL) as f64)
+            .sqrt()
+            .ceil() as usize;
+        let (columns, rows) = (side.max(1), side.max(1));
+        let cell_size = [
+            ((max.x - min.x) / columns as f64).max(f64::MIN_POSITIVE),
+            ((max.y - min.y) / rows as f64).max(f64::MIN_POSITIVE),
+        ];
+        let mut grid = Self {
+            min,
+            cell_size,
+            columns,
+            rows,
+            cell_start: Vec::new(),
+            indices: Vec::new(),
+        };
+
+        // Counting sort of the point indices by cell.
+        let cells: Vec<Option<usize>> = points
+            .iter()
+            .map(|p| finite(&p).then(|| grid.cell(p)))
+            .collect();
+        let mut cell_start = vec![0; columns * rows + 1];
+        for cell in cells.iter().flatten() {
+            cell_start[cell + 1] += 1;
+        }
+        for i in 1..cell_start.len() {
+            cell_start[i] += cell_start[i - 1];
+        }
+        let mut next = cell_start.clone();
+        let mut indices = vec![0; cell_start[columns * rows]];
+        for (index, cell) in cells.iter().enumerate() {
+            if let Some(cell) = *cell {
+                indices[next[cell]] = index;
+                next[cell] += 1;
+            }
+        }
+
+        grid.cell_start = cell_start;
+        grid.indices = indices;
+        grid
+    }
+
+    fn column_row(&self, point: PlotPoint) -> (usize, usize) {
+        let column = ((point.x - self.min.x) / self.cell_size[0]).max(0.0) as usize;
+        let row = ((point.y - self.min.y) / self.cell_size[1]).max(0.0) as usize;
+        (column.min(self.columns - 1), row.min(self.rows - 1))
+    }
+
+    fn cell(&self, point: &PlotPoint) -> usize {
+        let (column, row) = self.column_row(*point);
+        row * self.columns + column
+    }
+
+    /// Indices of the points in all cells overlapping the rectangle from `min` to `max`.
+    fn candidates(&self, min: PlotPoint, max: PlotPoint) -> impl Iterator<Item = usize> + '_ {
+        let (min_column, min_row) = self.column_row(min);
+        let (max_column, max_row) = self.column_row(max);
+        (min_row..=max_row).flat_map(move |row| {
+            let first = row * self.columns + min_column;
+            let last = row * self.columns + max_column;
+            self.indices[self.cell_start[first]..self.cell_start[last + 1]]
+                .iter()
+                .copied()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::Rect;
+
+    use super::*;
+    use crate::PlotBounds;
+
+    /// A small xorshift generator, so the test is random but reproducible.
+    struct Random(u64);
+
+    impl Random {
+        /// A value in `0.0..1.0`.
+        fn next(&mut self) -> f64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 >> 11) as f64 / (1u64 << 53) as f64
+        }
+    }
+
+    fn with_index(points: &[PlotPoint], index: SpatialIndex) -> IndexedPoints {
+        IndexedPoints {
+            inner: Arc::new(Inner {
+                points: points.to_vec(),
+                sorted_by_x: OnceLock::new(),
+                index: OnceLock::from(index),
+            }),
+        }
+    }
+
+    fn brute_force(points: &[PlotPoint], pointer: Pos2, transform: &PlotTransform) -> Option<f32> {
+        points
+            .iter()
+            .map(|point| pointer.distance_sq(transform.position_from_point(point)))
+            .filter(|dist_sq| *dist_sq <= IndexedPoints::HOVER_RADIUS.powi(2))
+            .min_by(f32::total_cmp)
+    }
+
+    /// Both indices find a point as close as the closest one of a scan over all points.
+    fn check(points: &[PlotPoint], random: &mut Random) {
+        let frame = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+        let transform = PlotTransform::new(
+            frame,
+            PlotBounds::from_min_max([0.0; 2], [1.0; 2]),
+            false,
+            false,
+        );
+        let mut sorted = points.to_vec();
+        sorted.sort_by(|a, b| a.x.total_cmp(&b.x));
+        let indices = [
+            (sorted.clone(), with_index(&sorted, SpatialIndex::SortedX)),
+            (
+                points.to_vec(),
+                with_index(points, SpatialIndex::Grid(Grid::new(points))),
+            ),
+        ];
+
+        for _ in 0..200 {
+            let pointer = Pos2::new(
+                (random.next() * 120.0 - 10.0) as f32,
+                (random.next() * 120.0 - 10.0) as f32,
+            );
+            for (points, indexed) in &indices {
+                let expected = brute_force(points, pointer, &transform);
+                let found = indexed.find_closest(pointer, &transform);
+                assert_eq!(found.as_ref().map(|elem| elem.dist_sq), expected);
+                if let Some(elem) = found {
+                    let pos = transform.position_from_point(&points[elem.index]);
+                    assert_eq!(pointer.distance_sq(pos), elem.dist_sq);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn indices_match_a_brute_force_scan() {
+        let mut random = Random(0x2545_f491_4f6c_dd1d);
+        let point = |random: &mut Random| {
+            PlotPoint::new(random.next() * 1.4 - 0.2, random.next() * 1.4 - 0.2)
+        };
+
+        check(&[], &mut random);
+        check(&[PlotPoint::new(0.5, 0.5)], &mut random);
+        for len in [2, 10, 100, 1_000] {
+            let points: Vec<PlotPoint> = (0..len).map(|_| point(&mut random)).collect();
+            check(&points, &mut random);
+        }
+
+        // Many points with the same X, and repeated points.
+        let points: Vec<PlotPoint> = (0..500)
+            .map(|i| PlotPoint::new((i % 7) as f64 / 6.0, random.next()))
+            .chain(std::iter::repeat_n(PlotPoint::new(0.25, 0.25), 20))
+            .collect();
+        check(&points, &mut random);
+
+        // Non-finite points are never found.
+        let points = [
+            PlotPoint::new(f64::NAN, 0.5),
+            PlotPoint::new(0.5, f64::INFINITY),
+            PlotPoint::new(0.5, 0.5),
+        ];
+        check(&points, &mut random);
+    }
+}
@@ -0,0 +1,456 @@
+use std::ops::RangeInclusive;
+
+use egui::epaint::CircleShape;
+use egui::{Color32, CursorIcon, Id, Key, PointerButton, Pos2, Response, Shape, Stroke, Ui};
+
+use crate::{PlotBounds, PlotMemory, PlotPoint, PlotTransform};
+
+use super::{LineStyle, PlotGeometry, PlotItem};
+
+/// Distance in ui points within which a handle of a [`TrendLine`] can be grabbed.
+const HANDLE_RADIUS: f32 = 8.0;
+
+/// Distance in ui points within which a [`TrendLine`] itself can be grabbed.
+const LINE_RADIUS: f32 = 5.0;
+
+/// How far a [`TrendLine`] reaches beyond its two points.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TrendLineKind {
+    /// Only the part between the two points.
+    #[default]
+    Segment,
+
+    /// From the start through the end point to the edge of the plot.
+    Ray,
+
+    /// Through both points to the edges of the plot in both directions.
+    Extended,
+}
+
+/// A change the user made to the trend lines of a plot.
+///
+/// See [`crate::PlotResponse::trend_line_events`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TrendLineEvent {
+    /// A new line was drawn with [`crate::Plot::trend_line_tool`].
+    ///
+    /// The plot doesn't keep it; add a [`TrendLine`] with these points to show it.
+    Created {
+        kind: TrendLineKind,
+        start: PlotPoint,
+        end: PlotPoint,
+    },
+
+    /// The line or one of its handles is being dragged. Sent every frame of the drag.
+    Moved {
+        id: Id,
+        start: PlotPoint,
+        end: PlotPoint,
+    },
+
+    /// The selected line was deleted with the Delete or Backspace key.
+    Deleted { id: Id },
+}
+
+/// A line through two points which the user can select, drag by its handles, and delete.
+///
+/// Trend lines are owned by the application: add them every frame with
+/// [`crate::PlotUi::trend_line`] and apply the [`TrendLineEvent`]s from
+/// [`crate::PlotResponse::trend_line_events`] to your copy. While a line is dragged, the plot
+/// already draws it at its new position.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, PlotPoint, TrendLine, TrendLineEvent, TrendLineKind};
+///
+/// let mut lines = vec![(egui::Id::new(0), PlotPoint::new(0.0, 0.0), PlotPoint::new(1.0, 1.0))];
+///
+/// let response = Plot::new("chart")
+///     .trend_line_tool(Some(TrendLineKind::Ray))
+///     .show(ui, |plot_ui| {
+///         for (id, start, end) in &lines {
+///             plot_ui.trend_line(TrendLine::new(*id, *start, *end));
+///         }
+///     });
+///
+/// for event in response.trend_line_events {
+///     match event {
+///         TrendLineEvent::Created { start, end, .. } => {
+///             lines.push((egui::Id::new(lines.len()), start, end));
+///         }
+///         TrendLineEvent::Moved { id, start, end } => {
+///             if let Some(line) = lines.iter_mut().find(|line| line.0 == id) {
+///                 *line = (id, start, end);
+///             }
+///         }
+///         TrendLineEvent::Deleted { id } => lines.retain(|line| line.0 != id),
+///     }
+/// }
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrendLine {
+    pub(super) id: Id,
+    pub(super) start: PlotPoint,
+    pub(super) end: PlotPoint,
+    pub(super) kind: TrendLineKind,
+    pub(crate) stroke: Stroke,
+    pub(super) style: LineStyle,
+    pub(super) name: String,
+    pub(super) highlight: bool,
+    pub(super) editable: bool,
+
+    /// Whether the handles are shown, set by the plot for the selected line.
+    pub(super) selected: bool,
+}
+
+impl TrendLine {
+    /// Create a line from `start` to `end`. The `id` identifies it in [`TrendLineEvent`]s.
+    pub fn new(id: Id, start: impl Into<PlotPoint>, end: impl Into<PlotPoint>) -> Self {
+        Self {
+            id,
+            start: start.into(),
+            end: end.into(),
+            kind: TrendLineKind::default(),
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+            style: LineStyle::Solid,
+            name: String::default(),
+            highlight: false,
+            editable: true,
+            selected: false,
+        }
+    }
+
+    /// How far the line reaches beyond its points. Default is [`TrendLineKind::Segment`].
+    #[inline]
+    pub fn kind(mut self, kind: TrendLineKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Highlight this line in the plot by scaling up the line.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Set the line's style. Default is `LineStyle::Solid`.
+    #[inline]
+    pub fn style(mut self, style: LineStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Name of this trend line.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Whether the user can select, drag and delete this line. Default is `true`.
+    #[inline]
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// The screen positions the drawn line runs between, after extending it for its kind.
+    fn screen_points(&self, transform: &PlotTransform) -> [Pos2; 2] {
+        let start = transform.position_from_point(&self.start);
+        let end = transform.position_from_point(&self.end);
+        let direction = end - start;
+        if direction.length_sq() < f32::EPSILON {
+            return [start, end];
+        }
+
+        // Long enough to leave the frame from anywhere.
+        let frame = transform.frame();
+        let reach = frame.size().length()
+            + (start - frame.center()).length()
+            + (end - frame.center()).length();
+        let extension = direction.normalized() * reach;
+        match self.kind {
+            TrendLineKind::Segment => [start, end],
+            TrendLineKind::Ray => [start, start + extension],
+            TrendLineKind::Extended => [start - extension, end + extension],
+        }
+    }
+
+    /// Which part of the line, if any, is at `pos`.
+    fn hit_test(&self, transform: &PlotTransform, pos: Pos2) -> Option<TrendLinePart> {
+        if !self.editable {
+            return None;
+        }
+        let start = transform.position_from_point(&self.start);
+        let end = transform.position_from_point(&self.end);
+        if start.distance(pos) <= HANDLE_RADIUS {
+            Some(TrendLinePart::Start)
+        } else if end.distance(pos) <= HANDLE_RADIUS {
+            Some(TrendLinePart::End)
+        } else {
+            let [a, b] = self.screen_points(transform);
+            (distance_sq_to_segment(pos, a, b) <= LINE_RADIUS * LINE_RADIUS)
+                .then_some(TrendLinePart::Line)
+        }
+    }
+}
+
+impl PlotItem for TrendLine {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let [a, b] = self.screen_points(transform);
+        self.style
+            .style_line(vec![a, b], self.stroke, self.highlight, shapes);
+
+        if self.selected {
+            for point in [&self.start, &self.end] {
+                shapes.push(Shape::Circle(CircleShape {
+                    center: transform.position_from_point(point),
+                    radius: HANDLE_RADIUS / 2.0,
+                    fill: ui.visuals().extreme_bg_color,
+                    stroke: Stroke::new(1.5, self.stroke.color),
+                }));
+            }
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        // Annotations don't take part in auto-bounds.
+        PlotBounds::NOTHING
+    }
+
+    fn id(&self) -> Option<Id> {
+        Some(self.id)
+    }
+}
+
+/// Part of a [`TrendLine`] grabbed by the pointer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum TrendLinePart {
+    Start,
+    End,
+    Line,
+}
+
+/// A trend line being dragged, kept in [`PlotMemory`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct TrendLineDrag {
+    id: Id,
+    part: TrendLinePart,
+
+    /// The points of the line when the drag started.
+    start: PlotPoint,
+    end: PlotPoint,
+
+    /// Where the drag started on screen.
+    origin: Pos2,
+}
+
+/// Select, drag, delete, and create trend lines with the pointer and keyboard.
+///
+/// Updates `lines` to reflect ongoing drags and adds a preview of a line being created.
+/// Returns the cursor icon to show, if any.
+pub(crate) fn interact_trend_lines(
+    ui: &Ui,
+    response: &Response,
+    mem: &mut PlotMemory,
+    lines: &mut Vec<TrendLine>,
+    tool: Option<TrendLineKind>,
+    events: &mut Vec<TrendLineEvent>,
+) -> Option<CursorIcon> {
+    let transform = mem.transform;
+    let hit_at = |lines: &[TrendLine], pos: Pos2| {
+        hit_test(lines, &transform, pos).map(|(line, part)| (line.id, part))
+    };
+
+    if mem
+        .selected_trend_line
+        .is_some_and(|id| !lines.iter().any(|line| line.id == id))
+        || response.lost_focus()
+    {
+        mem.selected_trend_line = None;
+    }
+
+    // Dragging
+    if response.drag_started_by(PointerButton::Primary) {
+        let origin = ui.input(|i| i.pointer.press_origin());
+        mem.trend_line_drag = origin.and_then(|origin| {
+            let (id, part) = hit_at(lines, origin)?;
+            let line = lines.iter().find(|line| line.id == id)?;
+            Some(TrendLineDrag {
+                id,
+                part,
+                start: line.start,
+                end: line.end,
+                origin,
+            })
+        });
+        if let Some(drag) = &mem.trend_line_drag {
+            mem.selected_trend_line = Some(drag.id);
+            response.request_focus();
+        }
+    }
+    if let Some(drag) = mem.trend_line_drag {
+        let line = lines.iter_mut().find(|line| line.id == drag.id);
+        match (line, response.interact_pointer_pos()) {
+            (Some(line), Some(pointer)) if response.dragged_by(PointerButton::Primary) => {
+                let moved = |point: &PlotPoint| {
+                    let pos = transform.position_from_point(point) + (pointer - drag.origin);
+                    transform.value_from_position(pos)
+                };
+                match drag.part {
+                    TrendLinePart::Start => line.start = transform.value_from_position(pointer),
+                    TrendLinePart::End => line.end = transform.value_from_position(pointer),
+                    TrendLinePart::Line => {
+                        line.start = moved(&drag.start);
+                        line.end = moved(&drag.end);
+                    }
+                }
+                events.push(TrendLineEvent::Moved {
+                    id: line.id,
+                    start: line.start,
+                    end: line.end,
+                });
+            }
+            _ => mem.trend_line_drag = None,
+        }
+    }
+
+    // Selecting and creating
+    if let Some(pointer) = response
+        .clicked_by(PointerButton::Primary)
+        .then(|| response.interact_pointer_pos())
+        .flatten()
+    {
+        if let Some(kind) = tool {
+            let value = transform.value_from_position(pointer);
+            match mem.trend_line_anchor.take() {
+                Some(start) => events.push(TrendLineEvent::Created {
+                    kind,
+                    start,
+                    end: value,
+                }),
+                None => mem.trend_line_anchor = Some(value),
+            }
+        } else {
+            mem.selected_trend_line = hit_at(lines, pointer).map(|(id, _)| id);
+            if mem.selected_trend_line.is_some() {
+                response.request_focus();
+            }
+        }
+    }
+    if tool.is_none() || ui.input(|i| i.key_pressed(Key::Escape)) {
+        mem.trend_line_anchor = None;
+    }
+
+    // Deleting
+    if let Some(id) = mem.selected_trend_line {
+        if response.has_focus()
+            && ui.input(|i| i.key_pressed(Key::Delete) || i.key_pressed(Key::Backspace))
+        {
+            lines.retain(|line| line.id != id);
+            events.push(TrendLineEvent::Deleted { id });
+            mem.selected_trend_line = None;
+            mem.trend_line_drag = None;
+        }
+    }
+
+    for line in lines.iter_mut() {
+        line.selected = mem.selected_trend_line == Some(line.id);
+    }
+
+    // Preview of the line being created
+    if let (Some(kind), Some(start), Some(pointer)) =
+        (tool, mem.trend_line_anchor, response.hover_pos())
+    {
+        let mut preview = TrendLine::new(Id::NULL, start, transform.value_from_position(pointer))
+            .kind(kind)
+            .color(ui.visuals().text_color())
+            .editable(false);
+        preview.selected = true;
+        lines.push(preview);
+    }
+
+    if mem.trend_line_drag.is_some() {
+        Some(CursorIcon::Grabbing)
+    } else if tool.is_some() {
+        None
+    } else {
+        let (_, part) = hit_at(lines, response.hover_pos()?)?;
+        Some(match part {
+            TrendLinePart::Start | TrendLinePart::End => CursorIcon::Grab,
+            TrendLinePart::Line => CursorIcon::PointingHand,
+        })
+    }
+}
+
+/// The topmost editable line at `pos` and the part of it that was hit.
+fn hit_test<'a>(
+    lines: &'a [TrendLine],
+    transform: &PlotTransform,
+    pos: Pos2,
+) -> Option<(&'a TrendLine, TrendLinePart)> {
+    // Lines added later are drawn on top, so they are grabbed first.
+    lines
+        .iter()
+        .rev()
+        .find_map(|line| Some(line).zip(line.hit_test(transform, pos)))
+}
+
+/// Squared distance from `pos` to the segment between `a` and `b`.
+fn distance_sq_to_segment(pos: Pos2, a: Pos2, b: Pos2) -> f32 {
+    let ab = b - a;
+    let length_sq = ab.length_sq();
+    if length_sq < f32::EPSILON {
+        return pos.distance_sq(a);
+    }
+    let t = ((pos - a).dot(ab) / length_sq).clamp(0.0, 1.0);
+    pos.distance_sq(a + t * ab)
+}
@@ -4,6 +4,8 @@ use egui::{Pos2, Shape, Stroke, Vec2};
 
 use crate::transform::PlotBounds;
 
+use super::{IndexedPoints, RingBuffer};
+
 /// A point coordinate in the plot.
 ///
 /// Uses f64 for improved accuracy to enable plotting
@@ -123,12 +125,12 @@ impl LineStyle {
     }
 }
 
-impl ToString for LineStyle {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for LineStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Solid => "Solid".into(),
-            Self::Dotted { spacing } => format!("Dotted{spacing}Px"),
-            Self::Dashed { length } => format!("Dashed{length}Px"),
+            Self::Solid => write!(f, "Solid"),
+            Self::Dotted { spacing } => write!(f, "Dotted{spacing}Px"),
+            Self::Dashed { length } => write!(f, "Dashed{length}Px"),
         }
     }
 }
@@ -136,26 +138,24 @@ impl ToString for LineStyle {
 // ----------------------------------------------------------------------------
 
 /// Determines whether a plot element is vertically or horizontally oriented.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum Orientation {
     Horizontal,
+    #[default]
     Vertical,
 }
 
-impl Default for Orientation {
-    fn default() -> Self {
-        Self::Vertical
-    }
-}
-
 // ----------------------------------------------------------------------------
 
 /// Represents many [`PlotPoint`]s.
 ///
-/// These can be an owned `Vec` or generated with a function.
+/// These can be an owned `Vec`, generated with a function, shared [`IndexedPoints`] that
+/// are fast to hover, or a shared [`RingBuffer`] of streaming data.
 pub enum PlotPoints {
     Owned(Vec<PlotPoint>),
     Generator(ExplicitGenerator),
+    Indexed(IndexedPoints),
+    Streaming(RingBuffer<PlotPoint>),
     // Borrowed(&[PlotPoint]), // TODO(EmbersArc): Lifetimes are tricky in this case.
 }
 
@@ -177,6 +177,18 @@ impl From<Vec<[f64; 2]>> for PlotPoints {
     }
 }
 
+impl From<IndexedPoints> for PlotPoints {
+    fn from(points: IndexedPoints) -> Self {
+        Self::Indexed(points)
+    }
+}
+
+impl From<RingBuffer<PlotPoint>> for PlotPoints {
+    fn from(points: RingBuffer<PlotPoint>) -> Self {
+        Self::Streaming(points)
+    }
+}
+
 impl FromIterator<[f64; 2]> for PlotPoints {
     fn from_iter<T: IntoIterator<Item = [f64; 2]>>(iter: T) -> Self {
         Self::Owned(iter.into_iter().map(|point| point.into()).collect())
@@ -192,6 +204,16 @@ impl PlotPoints {
         match self {
             Self::Owned(points) => points.as_slice(),
             Self::Generator(_) => &[],
+            Self::Indexed(points) => points.points(),
+            Self::Streaming(points) => points.as_slice(),
+        }
+    }
+
+    /// Whether the points are sorted by X. [`IndexedPoints`] remember it across frames.
+    pub(crate) fn is_sorted_by_x(&self) -> bool {
+        match self {
+            Self::Indexed(points) => points.is_sorted_by_x(),
+            series => super::spatial_index::is_sorted_by_x(series.points()),
         }
     }
 
@@ -270,6 +292,8 @@ impl PlotPoints {
         match self {
             Self::Owned(points) => points.is_empty(),
             Self::Generator(_) => false,
+            Self::Indexed(points) => points.points().is_empty(),
+            Self::Streaming(points) => points.is_empty(),
         }
     }
 
@@ -305,9 +329,9 @@ impl PlotPoints {
 
     pub(super) fn bounds(&self) -> PlotBounds {
         match self {
-            Self::Owned(points) => {
+            Self::Owned(_) | Self::Indexed(_) | Self::Streaming(_) => {
                 let mut bounds = PlotBounds::NOTHING;
-                for point in points {
+                for point in self.points() {
                     bounds.extend_with(point);
                 }
                 bounds
@@ -0,0 +1,586 @@
+use std::{cmp::Ordering, ops::RangeInclusive};
+
+use egui::{
+    emath::NumExt as _,
+    epaint::{util::FloatOrd as _, RectShape},
+    pos2, vec2, Align2, Color32, Id, Pos2, Rect, Shape, Stroke, TextStyle, Ui,
+};
+
+use crate::{
+    colormap::value_decimals, Cursor, HPlacement, LabelFormatter, PlotBounds, PlotPoint,
+    PlotTransform,
+};
+
+use super::{step_decimals, ClosestElem, PlotConfig, PlotGeometry, PlotItem};
+
+/// The traded volume at one price level of a [`VolumeProfile`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct VolumeLevel {
+    /// Center of the price bucket.
+    pub price: f64,
+
+    /// Total volume traded in the bucket.
+    pub volume: f64,
+
+    /// The part of [`Self::volume`] that was bought, if known. The rest was sold.
+    pub buy_volume: Option<f64>,
+}
+
+impl VolumeLevel {
+    /// A level with `volume` traded at `price`.
+    pub fn new(price: f64, volume: f64) -> Self {
+        Self {
+            price,
+            volume,
+            buy_volume: None,
+        }
+    }
+
+    /// A level with `buy_volume` bought and `sell_volume` sold at `price`.
+    pub fn with_split(price: f64, buy_volume: f64, sell_volume: f64) -> Self {
+        Self {
+            price,
+            volume: buy_volume + sell_volume,
+            buy_volume: Some(buy_volume),
+        }
+    }
+
+    /// The part of the volume that was sold, if the split is known.
+    #[inline]
+    pub fn sell_volume(&self) -> Option<f64> {
+        self.buy_volume.map(|buy| self.volume - buy)
+    }
+}
+
+impl From<[f64; 2]> for VolumeLevel {
+    fn from([price, volume]: [f64; 2]) -> Self {
+        Self::new(price, volume)
+    }
+}
+
+/// A volume profile: horizontal bars of traded volume per price level, anchored to the left or
+/// right edge of the plot.
+///
+/// The bars keep their width in ui points when zooming along X; the level with the most volume
+/// (the point of control) is [`Self::max_width`] wide. The point of control is marked with a line
+/// across the plot, and the levels of the value area are drawn stronger than the rest.
+///
+/// ```
+/// # use egui_plot::{VolumeLevel, VolumeProfile};
+/// let profile = VolumeProfile::new(vec![
+///     VolumeLevel::with_split(100.0, 30.0, 20.0),
+///     VolumeLevel::with_split(101.0, 80.0, 50.0),
+///     VolumeLevel::with_split(102.0, 10.0, 25.0),
+/// ])
+/// .name("volume")
+/// .max_width(150.0);
+/// ```
+pub struct VolumeProfile {
+    pub(crate) levels: Vec<VolumeLevel>,
+    pub(super) name: String,
+    pub(crate) color: Color32,
+    pub(super) buy_color: Color32,
+    pub(super) sell_color: Color32,
+    pub(super) poc_color: Option<Color32>,
+    pub(super) placement: HPlacement,
+    pub(super) max_width: f32,
+    pub(super) bucket_size: Option<f64>,
+    pub(super) value_area: f64,
+
+    /// A custom element formatter
+    pub(super) element_formatter: Option<Box<dyn Fn(&VolumeLevel, &VolumeProfile) -> String>>,
+
+    highlight: bool,
+    id: Option<Id>,
+    y_axis: Option<Id>,
+}
+
+impl VolumeProfile {
+    /// Create a volume profile from `levels`, one per price bucket, in any order.
+    pub fn new(levels: Vec<VolumeLevel>) -> Self {
+        Self {
+            levels,
+            name: String::new(),
+            color: Color32::TRANSPARENT,
+            buy_color: Color32::from_rgb(38, 166, 154),
+            sell_color: Color32::from_rgb(239, 83, 80),
+            poc_color: None,
+            placement: HPlacement::Right,
+            max_width: 120.0,
+            bucket_size: None,
+            value_area: 0.7,
+            element_formatter: None,
+            highlight: false,
+            id: None,
+            y_axis: None,
+        }
+    }
+
+    /// Name of this profile.
+    ///
+    /// This name will show up in the plot legend, if legends are turned on.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Color of the bars of levels without a buy/sell split.
+    ///
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Colors of the bought and sold parts of levels with a buy/sell split.
+    #[inline]
+    pub fn split_colors(mut self, buy: impl Into<Color32>, sell: impl Into<Color32>) -> Self {
+        self.buy_color = buy.into();
+        self.sell_color = sell.into();
+        self
+    }
+
+    /// Color of the point of control line. Default is the text color.
+    #[inline]
+    pub fn poc_color(mut self, color: impl Into<Color32>) -> Self {
+        self.poc_color = Some(color.into());
+        self
+    }
+
+    /// The plot edge the bars grow from. Default: [`HPlacement::Right`].
+    #[inline]
+    pub fn placement(mut self, placement: HPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Width in ui points of the bar with the most volume. Default: `120.0`.
+    #[inline]
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    /// Height of a price bucket in plot units.
+    ///
+    /// Default is the smallest distance between two levels.
+    #[inline]
+    pub fn bucket_size(mut self, bucket_size: f64) -> Self {
+        self.bucket_size = Some(bucket_size);
+        self
+    }
+
+    /// Share of the total volume in the value area around the point of control. Default: `0.7`.
+    #[inline]
+    pub fn value_area(mut self, share: f64) -> Self {
+        self.value_area = share.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Add a custom way to format a level in the hover text.
+    #[inline]
+    pub fn element_formatter(
+        mut self,
+        formatter: Box<dyn Fn(&VolumeLevel, &Self) -> String>,
+    ) -> Self {
+        self.element_formatter = Some(formatter);
+        self
+    }
+
+    /// Highlight all levels of this profile.
+    #[inline]
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = highlight;
+        self
+    }
+
+    /// Set the profile's id which is used to identify it in the plot's response.
+    #[inline]
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Draw this item against the Y axis with the given id instead of the main Y axis.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis(mut self, axis: Id) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
+
+    /// Index of the level with the most volume. Of levels with the same volume, the one with
+    /// the lowest price.
+    pub fn point_of_control(&self) -> Option<usize> {
+        self.levels
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| more_volume(a, b))
+            .map(|(index, _)| index)
+    }
+
+    /// Price range of the value area: the levels around the point of control that hold
+    /// [`Self::value_area`] of the total volume.
+    ///
+    /// Starting at the point of control, the neighboring level with more volume is added until
+    /// the value area holds enough volume.
+    pub fn value_area_range(&self) -> Option<RangeInclusive<f64>> {
+        let mut sorted: Vec<&VolumeLevel> = self.levels.iter().collect();
+        sorted.sort_by_key(|level| level.price.ord());
+        let poc = sorted
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| more_volume(a, b))
+            .map(|(index, _)| index)?;
+
+        let total: f64 = sorted.iter().map(|level| level.volume).sum();
+        let target = total * self.value_area;
+        let (mut low, mut high) = (poc, poc);
+        let mut volume = sorted[poc].volume;
+        while volume < target && (low > 0 || high + 1 < sorted.len()) {
+            let below = (low > 0).then(|| sorted[low - 1].volume);
+            let above = sorted.get(high + 1).map(|level| level.volume);
+            if above.unwrap_or(f64::NEG_INFINITY) >= below.unwrap_or(f64::NEG_INFINITY) {
+                high += 1;
+                volume += sorted[high].volume;
+            } else {
+                low -= 1;
+                volume += sorted[low].volume;
+            }
+        }
+        Some(sorted[low].price..=sorted[high].price)
+    }
+
+    fn bucket_height(&self) -> f64 {
+        self.bucket_size.unwrap_or_else(|| {
+            let mut prices: Vec<f64> = self.levels.iter().map(|level| level.price).collect();
+            prices.sort_by_key(|price| price.ord());
+            prices
+                .windows(2)
+                .map(|w| w[1] - w[0])
+                .filter(|step| *step > 0.0)
+                .min_by_key(|step| step.ord())
+                .unwrap_or(1.0)
+        })
+    }
+
+    fn max_volume(&self) -> f64 {
+        self.levels
+            .iter()
+            .map(|level| level.volume)
+            .fold(0.0, f64::max)
+    }
+
+    /// What the bars are scaled by, computed once for all levels.
+    fn bar_scale(&self) -> BarScale {
+        BarScale {
+            half_height: self.bucket_height() / 2.0,
+            max_volume: self.max_volume(),
+        }
+    }
+
+    /// The screen rect of the bar of `level`.
+    fn bar_rect(&self, level: &VolumeLevel, scale: BarScale, transform: &PlotTransform) -> Rect {
+        let top = transform.position_from_point_y(level.price + scale.half_height);
+        let bottom = transform.position_from_point_y(level.price - scale.half_height);
+        let width = if scale.max_volume > 0.0 {
+            (level.volume / scale.max_volume) as f32 * self.max_width
+        } else {
+            0.0
+        };
+
+        let frame = transform.frame();
+        let (left, right) = match self.placement {
+            HPlacement::Left => (frame.left(), frame.left() + width),
+            HPlacement::Right => (frame.right() - width, frame.right()),
+        };
+        // Leave a pixel between neighboring levels, unless the bars are too thin for it.
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+        let gap = if bottom - top > 2.0 { 0.5 } else { 0.0 };
+        Rect::from_x_y_ranges(left..=right, top + gap..=bottom - gap)
+    }
+
+    fn add_bar(&self, rect: Rect, level: &VolumeLevel, alpha: f32, shapes: &mut Vec<Shape>) {
+        match level.buy_volume {
+            Some(buy) if level.volume > 0.0 => {
+                // The bought part is nearest to the edge.
+                let buy_width = (buy / level.volume) as f32 * rect.width();
+                let (buy_rect, sell_rect) = match self.placement {
+                    HPlacement::Left => {
+                        let split = rect.left() + buy_width;
+                        (
+                            rect.with_max_x(split),
+                            Rect::from_x_y_ranges(split..=rect.right(), rect.y_range()),
+                        )
+                    }
+                    HPlacement::Right => {
+                        let split = rect.right() - buy_width;
+                        (
+                            rect.with_min_x(split),
+                            Rect::from_x_y_ranges(rect.left()..=split, rect.y_range()),
+                        )
+                    }
+                };
+                shapes.push(Shape::rect_filled(
+                    buy_rect,
+                    0.0,
+                    self.buy_color.linear_multiply(alpha),
+                ));
+                shapes.push(Shape::rect_filled(
+                    sell_rect,
+                    0.0,
+                    self.sell_color.linear_multiply(alpha),
+                ));
+            }
+            _ => {
+                shapes.push(Shape::rect_filled(
+                    rect,
+                    0.0,
+                    self.color.linear_multiply(alpha),
+                ));
+            }
+        }
+    }
+
+    fn level_alpha(&self, level: &VolumeLevel, value_area: Option<&RangeInclusive<f64>>) -> f32 {
+        let in_value_area = value_area.is_some_and(|range| range.contains(&level.price));
+        match (in_value_area, self.highlight) {
+            (true, false) => 0.5,
+            (true, true) => 0.8,
+            (false, false) => 0.2,
+            (false, true) => 0.4,
+        }
+    }
+}
+
+impl PlotItem for VolumeProfile {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let value_area = self.value_area_range();
+        let scale = self.bar_scale();
+        for level in &self.levels {
+            let alpha = self.level_alpha(level, value_area.as_ref());
+            let rect = self.bar_rect(level, scale, transform);
+            self.add_bar(rect, level, alpha, shapes);
+        }
+
+        if let Some(poc) = self.point_of_control() {
+            let color = self.poc_color.unwrap_or_else(|| ui.visuals().text_color());
+            let y = ui
+                .painter()
+                .round_to_pixel(transform.position_from_point_y(self.levels[poc].price));
+            let frame = transform.frame();
+            shapes.push(Shape::line_segment(
+                [pos2(frame.left(), y), pos2(frame.right(), y)],
+                Stroke::new(1.0, color),
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn highlight(&mut self) {
+        self.highlight = true;
+    }
+
+    fn highlighted(&self) -> bool {
+        self.highlight
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Rects
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let half_height = self.bucket_height() / 2.0;
+        let mut bounds = PlotBounds::NOTHING;
+        for level in &self.levels {
+            bounds.extend_with_y(level.price - half_height);
+            bounds.extend_with_y(level.price + half_height);
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let scale = self.bar_scale();
+        self.levels
+            .iter()
+            .enumerate()
+            .map(|(index, level)| ClosestElem {
+                index,
+                dist_sq: self
+                    .bar_rect(level, scale, transform)
+                    .distance_sq_to_pos(point),
+            })
+            .min_by_key(|e| e.dist_sq.ord())
+    }
+
+    fn on_hover(
+        &self,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter,
+    ) {
+        let level = &self.levels[elem.index];
+        let bar_scale = self.bar_scale();
+        let rect = self.bar_rect(level, bar_scale, plot.transform);
+        shapes.push(Shape::Rect(RectShape::stroke(
+            rect,
+            0.0,
+            Stroke::new(1.0, plot.ui.visuals().text_color()),
+        )));
+
+        if plot.show_y {
+            cursors.push(Cursor::Horizontal { y: level.price });
+        }
+
+        // The X value of a custom label is that of the middle of the bar.
+        let bar_center = PlotPoint::new(
+            plot.transform.value_from_position(rect.center()).x,
+            level.price,
+        );
+        let custom_label = || plot.custom_label(label_formatter, &self.name, bar_center);
+        let text = match &self.element_formatter {
+            Some(formatter) => formatter(level, self),
+            None => custom_label().unwrap_or_else(|| {
+                let scale = plot
+                    .transform
+                    .dvalue_dpos_at(&PlotPoint::new(0.0, level.price));
+                let decimals = step_decimals(scale[1]).at_least(1);
+                let volume_decimals = value_decimals(&(0.0..=bar_scale.max_volume));
+                let mut text = self.name.clone();
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str(&format!(
+                    "Price = {}\nVolume = {:.*}",
+                    plot.format_y(level.price, decimals),
+                    volume_decimals,
+                    level.volume
+                ));
+                if let (Some(buy), Some(sell)) = (level.buy_volume, level.sell_volume()) {
+                    text.push_str(&format!(
+                        "\nBuy = {buy:.volume_decimals$}\nSell = {sell:.volume_decimals$}"
+                    ));
+                }
+                text
+            }),
+        };
+
+        // Put the text on the inner side of the bar, so it stays inside the plot.
+        let (pos, anchor) = match self.placement {
+            HPlacement::Left => (rect.right_top() + vec2(3.0, 0.0), Align2::LEFT_BOTTOM),
+            HPlacement::Right => (rect.left_top() - vec2(3.0, 0.0), Align2::RIGHT_BOTTOM),
+        };
+        let font_id = TextStyle::Body.resolve(plot.ui.style());
+        plot.ui.fonts(|f| {
+            shapes.push(Shape::text(
+                f,
+                pos,
+                anchor,
+                text,
+                font_id,
+                plot.ui.visuals().text_color(),
+            ));
+        });
+    }
+
+    fn id(&self) -> Option<Id> {
+        self.id
+    }
+
+    fn y_axis(&self) -> Option<Id> {
+        self.y_axis
+    }
+}
+
+/// Orders levels by volume, with the lower price first among equal volumes, for the point of
+/// control.
+fn more_volume(a: &VolumeLevel, b: &VolumeLevel) -> Ordering {
+    a.volume
+        .ord()
+        .cmp(&b.volume.ord())
+        .then_with(|| b.price.ord().cmp(&a.price.ord()))
+}
+
+/// The sizes all bars of a [`VolumeProfile`] are scaled by.
+#[derive(Clone, Copy)]
+struct BarScale {
+    /// Half the price range of a level.
+    half_height: f64,
+
+    /// The volume of the widest bar.
+    max_volume: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(levels: &[(f64, f64)]) -> VolumeProfile {
+        VolumeProfile::new(
+            levels
+                .iter()
+                .map(|&(price, volume)| VolumeLevel::new(price, volume))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn value_area_grows_towards_more_volume() {
+        let profile = profile(&[
+            (1.0, 10.0),
+            (2.0, 20.0),
+            (3.0, 40.0),
+            (4.0, 20.0),
+            (5.0, 10.0),
+        ]);
+        assert_eq!(profile.point_of_control(), Some(2));
+        // 40, then 20 above (ties go up), then 20 below: 80 of 100.
+        assert_eq!(profile.value_area_range(), Some(2.0..=4.0));
+        assert_eq!(profile.value_area(0.4).value_area_range(), Some(3.0..=3.0));
+
+        let profile = self::profile(&[(1.0, 10.0), (2.0, 5.0), (3.0, 40.0), (4.0, 20.0)]);
+        assert_eq!(profile.value_area(1.0).value_area_range(), Some(1.0..=4.0));
+    }
+
+    #[test]
+    fn levels_may_come_in_any_order() {
+        let profile = profile(&[
+            (4.0, 20.0),
+            (1.0, 10.0),
+            (3.0, 40.0),
+            (5.0, 10.0),
+            (2.0, 20.0),
+        ]);
+        assert_eq!(profile.point_of_control(), Some(2));
+        assert_eq!(profile.value_area_range(), Some(2.0..=4.0));
+    }
+
+    #[test]
+    fn ties_go_to_the_lowest_price() {
+        let profile = profile(&[(3.0, 30.0), (2.0, 10.0), (1.0, 30.0)]);
+        assert_eq!(profile.point_of_control(), Some(2));
+        assert_eq!(profile.value_area(0.5).value_area_range(), Some(1.0..=2.0));
+    }
+
+    #[test]
+    fn empty_profiles_have_no_point_of_control() {
+        let profile = profile(&[]);
+        assert_eq!(profile.point_of_control(), None);
+        assert_eq!(profile.value_area_range(), None);
+    }
+}
@@ -179,10 +179,10 @@ pub(super) struct LegendWidget {
 impl LegendWidget {
     /// Create a new legend from items, the names of items that are hidden and the style of the
     /// text. Returns `None` if the legend has no entries.
-    pub(super) fn try_new(
+    pub(super) fn try_new<'a>(
         rect: Rect,
         config: Legend,
-        items: &[Box<dyn PlotItem>],
+        items: impl Iterator<Item = &'a dyn PlotItem>,
         hidden_items: &ahash::HashSet<String>, // Existing hiddent items in the plot memory.
     ) -> Option<Self> {
         // If `config.hidden_items` is not `None`, it is used.
@@ -192,7 +192,6 @@ impl LegendWidget {
         // checkbox. If their colors don't match, we pick a neutral color for the checkbox.
         let mut entries: BTreeMap<String, LegendEntry> = BTreeMap::new();
         items
-            .iter()
             .filter(|item| !item.name().is_empty())
             .for_each(|item| {
                 entries
@@ -7,10 +7,17 @@
 //!
 
 mod axis;
+mod colormap;
+mod crosshair;
+mod headless;
+pub mod indicators;
 mod items;
 mod legend;
+mod measure;
 mod memory;
 mod plot_ui;
+mod svg;
+mod time;
 mod transform;
 
 use std::{ops::RangeInclusive, sync::Arc};
@@ -20,24 +27,39 @@ use egui::*;
 use epaint::{util::FloatOrd, Hsva};
 
 pub use crate::{
-    axis::{Axis, AxisHints, HPlacement, Placement, VPlacement},
+    axis::{Axis, AxisHints, HPlacement, Placement, VPlacement, YScaleMode},
+    colormap::{Colorbar, Colormap},
+    crosshair::CrosshairSnap,
+    headless::PlotRenderer,
     items::{
-        Arrows, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, HLine, Line, LineStyle, MarkerShape,
-        Orientation, PlotGeometry, PlotImage, PlotItem, PlotPoint, PlotPoints, Points, Polygon,
-        Text, VLine,
+        Arrows, Band, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, Candle, CandleStyle, Candlestick,
+        Comparison, Contour, DraggableHLine, ErrorBars, FibRetracement, Grid, HLine, HLineDrag,
+        HSpan, Heatmap, IndexedPoints, Line, LineStyle, MarkerShape, Orientation, PlotGeometry,
+        PlotImage, PlotItem, PlotPoint, PlotPoints, Points, Polygon, RingBuffer, Text, TrendLine,
+        TrendLineEvent, TrendLineKind, VLine, VSpan, VolumeLevel, VolumeProfile,
     },
     legend::{Corner, Legend},
+    measure::Measurement,
     memory::PlotMemory,
     plot_ui::PlotUi,
-    transform::{PlotBounds, PlotTransform},
+    svg::shapes_to_svg,
+    time::{
+        format_date_time, format_duration, format_time, time_formatter, time_grid_spacer, TimeIndex,
+    },
+    transform::{AxisScale, PlotBounds, PlotTransform},
 };
 
+#[cfg(feature = "png")]
+pub use crate::headless::write_png;
+
 //grom
 pub use items::{HRay, LinkedYText, LinkedYHRay, LinkedYPolygon};
 
-use axis::AxisWidget;
+use axis::{AxisWidget, YRebase};
 use items::{horizontal_line, rulers_color, vertical_line};
 use legend::LegendWidget;
+use measure::MeasureFormatter;
+use memory::YAxisMemory;
 
 type LabelFormatterFn = dyn Fn(&str, &PlotPoint) -> String;
 pub type LabelFormatter = Option<Box<LabelFormatterFn>>;
@@ -125,6 +147,19 @@ pub struct PlotResponse<R> {
     ///
     /// This is `None` if either no item was hovered, or the hovered item didn't provide an id.
     pub hovered_plot_item: Option<Id>,
+
+    /// What the user did to the [`TrendLine`]s of the plot this frame.
+    pub trend_line_events: Vec<TrendLineEvent>,
+
+    /// The [`DraggableHLine`] the user is dragging, or stopped dragging this frame.
+    pub dragged_hline: Option<HLineDrag>,
+
+    /// The plot as an SVG document, if requested with [`Plot::export_svg`].
+    pub svg: Option<String>,
+
+    /// The distance the user is measuring, or finished measuring this frame, see
+    /// [`Plot::allow_measure`].
+    pub measurement: Option<Measurement>,
 }
 
 // ----------------------------------------------------------------------------
@@ -159,8 +194,14 @@ pub struct Plot {
     min_auto_bounds: PlotBounds,
     margin_fraction: Vec2,
     boxed_zoom_pointer_button: PointerButton,
+    allow_measure: bool,
+    measure_pointer_button: PointerButton,
+    measure_modifiers: Modifiers,
+    measure_formatter: Option<MeasureFormatter>,
     linked_axes: Option<(Id, Vec2b)>,
     linked_cursors: Option<(Id, Vec2b)>,
+    crosshair: bool,
+    crosshair_snap: CrosshairSnap,
 
     min_size: Vec2,
     width: Option<f32>,
@@ -182,9 +223,14 @@ pub struct Plot {
 
     show_grid: Vec2b,
     grid_spacing: Rangef,
-    grid_spacers: [GridSpacer; 2],
+    grid_spacers: [Option<GridSpacer>; 2],
     sharp_grid_lines: bool,
     clamp_grid: bool,
+    x_time_index: Option<TimeIndex>,
+    axis_scales: [AxisScale; 2],
+    trend_line_tool: Option<TrendLineKind>,
+    export_svg: bool,
+    follow_latest: bool,
 
     sense: Sense,
 
@@ -209,8 +255,14 @@ impl Plot {
             min_auto_bounds: PlotBounds::NOTHING,
             margin_fraction: Vec2::splat(0.05),
             boxed_zoom_pointer_button: PointerButton::Secondary,
+            allow_measure: false,
+            measure_pointer_button: PointerButton::Primary,
+            measure_modifiers: Modifiers::SHIFT,
+            measure_formatter: None,
             linked_axes: None,
             linked_cursors: None,
+            crosshair: false,
+            crosshair_snap: CrosshairSnap::Free,
 
             min_size: Vec2::splat(64.0),
             width: None,
@@ -232,9 +284,14 @@ impl Plot {
 
             show_grid: true.into(),
             grid_spacing: Rangef::new(8.0, 300.0),
-            grid_spacers: [log_grid_spacer(10), log_grid_spacer(10)],
+            grid_spacers: [None, None],
             sharp_grid_lines: true,
             clamp_grid: false,
+            x_time_index: None,
+            axis_scales: [AxisScale::Linear; 2],
+            trend_line_tool: None,
+            export_svg: false,
+            follow_latest: false,
 
             sense: egui::Sense::click_and_drag(),
             
@@ -380,6 +437,57 @@ impl Plot {
         self
     }
 
+    /// Whether to allow measuring the distance between two points by dragging with
+    /// [`Self::measure_modifiers`] held. Default: `false`.
+    ///
+    /// While measuring, the plot shades the measured area and shows ΔY, the change in percent
+    /// and ΔX (with a [`Self::x_time_index`], the number of bars and the time elapsed). The
+    /// measurement is reported in [`PlotResponse::measurement`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Line, Plot, PlotPoints};
+    ///
+    /// let response = Plot::new("chart")
+    ///     .allow_measure(true)
+    ///     .measure_formatter(|m| format!("{:.1} per bar", m.delta_y() / m.delta_x()))
+    ///     .show(ui, |plot_ui| plot_ui.line(Line::new(PlotPoints::from_ys_f64(&[1.0, 3.0, 2.0]))));
+    ///
+    /// if let Some(measurement) = response.measurement.filter(|m| m.finished) {
+    ///     println!("Moved {:+.2}", measurement.delta_y());
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn allow_measure(mut self, on: bool) -> Self {
+        self.allow_measure = on;
+        self
+    }
+
+    /// Config the button pointer to use for measuring. Default: [`Primary`](PointerButton::Primary)
+    #[inline]
+    pub fn measure_pointer_button(mut self, measure_pointer_button: PointerButton) -> Self {
+        self.measure_pointer_button = measure_pointer_button;
+        self
+    }
+
+    /// The modifier keys to hold when starting to measure. Default: [`Modifiers::SHIFT`].
+    #[inline]
+    pub fn measure_modifiers(mut self, modifiers: Modifiers) -> Self {
+        self.measure_modifiers = modifiers;
+        self
+    }
+
+    /// Add a summary line of your own to the measurement label, e.g. a risk/reward ratio.
+    #[inline]
+    pub fn measure_formatter(
+        mut self,
+        formatter: impl Fn(&Measurement) -> String + 'static,
+    ) -> Self {
+        self.measure_formatter = Some(Box::new(formatter));
+        self
+    }
+
     /// Whether to allow dragging in the plot to move the bounds. Default: `true`.
     #[inline]
     pub fn allow_drag<T>(mut self, on: T) -> Self
@@ -431,7 +539,8 @@ impl Plot {
 
     /// Configure how the grid in the background is spaced apart along the X axis.
     ///
-    /// Default is a log-10 grid, i.e. every plot unit is divided into 10 other units.
+    /// Default is a log-10 grid, i.e. every plot unit is divided into 10 other units, or
+    /// [`log_scale_grid_spacer`] on an [`AxisScale::Log`] axis.
     ///
     /// The function has this signature:
     /// ```ignore
@@ -458,19 +567,130 @@ impl Plot {
     /// # ()
     /// ```
     ///
-    /// There are helpers for common cases, see [`log_grid_spacer`] and [`uniform_grid_spacer`].
+    /// There are helpers for common cases, see [`log_grid_spacer`], [`uniform_grid_spacer`],
+    /// [`log_scale_grid_spacer`] and [`time_grid_spacer`].
     #[inline]
     pub fn x_grid_spacer(mut self, spacer: impl Fn(GridInput) -> Vec<GridMark> + 'static) -> Self {
-        self.grid_spacers[0] = Box::new(spacer);
+        self.grid_spacers[0] = Some(Box::new(spacer));
         self
     }
 
-    /// Default is a log-10 grid, i.e. every plot unit is divided into 10 other units.
+    /// Default is a log-10 grid, i.e. every plot unit is divided into 10 other units, or
+    /// [`log_scale_grid_spacer`] on an [`AxisScale::Log`] axis.
     ///
     /// See [`Self::x_grid_spacer`] for explanation.
     #[inline]
     pub fn y_grid_spacer(mut self, spacer: impl Fn(GridInput) -> Vec<GridMark> + 'static) -> Self {
-        self.grid_spacers[1] = Box::new(spacer);
+        self.grid_spacers[1] = Some(Box::new(spacer));
+        self
+    }
+
+    /// Let the user draw a [`TrendLine`] of the given kind by clicking its start and end point.
+    ///
+    /// The finished line is reported as [`TrendLineEvent::Created`]. Escape cancels the line
+    /// being drawn. While drawing, trend lines can't be selected by clicking.
+    ///
+    /// Default: `None`.
+    #[inline]
+    pub fn trend_line_tool(mut self, kind: Option<TrendLineKind>) -> Self {
+        self.trend_line_tool = kind;
+        self
+    }
+
+    /// Export this frame of the plot as an SVG document to [`PlotResponse::svg`].
+    ///
+    /// Everything the plot paints is exported: items, grid, axes, legend, and the hover label
+    /// if the plot is hovered. Only request it on the frame you want to export, e.g. when an
+    /// export button was clicked, since it adds a bit of work. See [`shapes_to_svg`] for what
+    /// is left out.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Line, Plot, PlotPoints};
+    ///
+    /// let response = Plot::new("report").export_svg(true).show(ui, |plot_ui| {
+    ///     plot_ui.line(Line::new(PlotPoints::from_ys_f64(&[1.0, 3.0, 2.0])))
+    /// });
+    /// let svg = response.svg.unwrap();
+    /// assert!(svg.starts_with("<svg"));
+    /// # });
+    /// ```
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn export_svg(mut self, export_svg: bool) -> Self {
+        self.export_svg = export_svg;
+        self
+    }
+
+    /// Keep the newest data at the right edge of the plot, e.g. for live data in a
+    /// [`RingBuffer`].
+    ///
+    /// While the X axis fits all data, the newest data is in view anyway. Once the user has
+    /// zoomed along X, the visible width is kept and scrolls along with the newest data.
+    /// Dragging or scrolling the newest data out of view stops following; bringing it back into
+    /// view, or double-clicking, follows again.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn follow_latest(mut self, follow_latest: bool) -> Self {
+        self.follow_latest = follow_latest;
+        self
+    }
+
+    /// Set the scale of the X axis, e.g. [`AxisScale::log10`].
+    ///
+    /// Default: [`AxisScale::Linear`].
+    #[inline]
+    pub fn x_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[0] = scale.sanitized();
+        self
+    }
+
+    /// Set the scale of the Y axis, e.g. [`AxisScale::log10`]. Y axes with their own id use it
+    /// too, unless they have their own [`AxisHints::scale`].
+    ///
+    /// Items keep using plain values; there is no need to take the logarithm of the data or to
+    /// undo it in formatters.
+    ///
+    /// Default: [`AxisScale::Linear`].
+    #[inline]
+    pub fn y_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[1] = scale.sanitized();
+        self
+    }
+
+    /// Use a gapless time axis: items are placed by bar index instead of by time.
+    ///
+    /// The X value of the `n`-th bar is `n`, so periods without data (nights, weekends, holidays)
+    /// don't leave empty space. The X grid spacer, the X axis formatters, the hover label
+    /// (including a custom [`Self::label_formatter`]) and the coordinates formatter receive
+    /// timestamps mapped through the index, so they can be the same as for a continuous time axis.
+    /// [`PlotUi::pointer_coordinate`] also returns a timestamp as X.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{time_formatter, time_grid_spacer, Candle, Candlestick, Plot, TimeIndex};
+    ///
+    /// // Two trading days, with the night in between left out.
+    /// let timestamps: Vec<f64> = (0..8)
+    ///     .chain(24..32)
+    ///     .map(|hour| 1_700_000_000.0 + hour as f64 * 3600.0)
+    ///     .collect();
+    /// let candles = (0..timestamps.len())
+    ///     .map(|i| Candle::new(i as f64, 10.0, 12.0, 9.0, 11.0))
+    ///     .collect();
+    ///
+    /// Plot::new("prices")
+    ///     .x_time_index(TimeIndex::new(timestamps))
+    ///     .x_grid_spacer(time_grid_spacer(0))
+    ///     .x_axis_formatter(time_formatter(0))
+    ///     .show(ui, |plot_ui| plot_ui.candlesticks(Candlestick::new(candles)));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn x_time_index(mut self, time_index: TimeIndex) -> Self {
+        self.x_time_index = Some(time_index);
         self
     }
 
@@ -607,6 +827,37 @@ impl Plot {
         self
     }
 
+    /// Show a crosshair wherever the pointer is in the plot, with its value in badges on the X and
+    /// Y axes. Default: `false`.
+    ///
+    /// The crosshair replaces the rulers of hovered items, but their labels are still shown. In a
+    /// [`Self::link_cursor`] group, the other plots show the crosshair lines and badges of the
+    /// linked axes too, if they have a crosshair.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Candle, Candlestick, CrosshairSnap, Plot};
+    ///
+    /// let candles = vec![Candle::new(0.0, 10.0, 12.0, 9.0, 11.0)];
+    /// Plot::new("chart")
+    ///     .crosshair(true)
+    ///     .crosshair_snap(CrosshairSnap::WeakMagnet)
+    ///     .show(ui, |plot_ui| plot_ui.candlesticks(Candlestick::new(candles)));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn crosshair(mut self, on: bool) -> Self {
+        self.crosshair = on;
+        self
+    }
+
+    /// What the crosshair snaps to. Default: [`CrosshairSnap::Free`].
+    #[inline]
+    pub fn crosshair_snap(mut self, snap: CrosshairSnap) -> Self {
+        self.crosshair_snap = snap;
+        self
+    }
+
     /// Round grid positions to full pixels to avoid aliasing. Improves plot appearance but might have an
     /// undesired effect when shifting the plot bounds. Enabled by default.
     #[inline]
@@ -644,6 +895,20 @@ impl Plot {
         self
     }
 
+    /// Show the values of the main Y axis relative to the leftmost visible data point, e.g. as
+    /// percent changes to compare instruments. See [`YScaleMode`] and [`AxisHints::scale_mode`].
+    ///
+    /// The tick labels, the hover labels and the `y_highlights` badges show the rebased values.
+    ///
+    /// Default: [`YScaleMode::Normal`].
+    #[inline]
+    pub fn y_scale_mode(mut self, mode: YScaleMode) -> Self {
+        if let Some(main) = self.y_axes.first_mut() {
+            main.scale_mode = mode;
+        }
+        self
+    }
+
     /// Set the position of the main X-axis.
     #[inline]
     pub fn x_axis_position(mut self, placement: axis::VPlacement) -> Self {
@@ -752,6 +1017,10 @@ impl Plot {
             allow_double_click_reset,
             allow_boxed_zoom,
             boxed_zoom_pointer_button,
+            allow_measure,
+            measure_pointer_button,
+            measure_modifiers,
+            measure_formatter,
             default_auto_bounds,
             min_auto_bounds,
             margin_fraction,
@@ -764,7 +1033,7 @@ impl Plot {
             mut show_y,
             label_formatter,
             coordinates_formatter,
-            x_axes,
+            mut x_axes,
             y_axes,
             legend_config,
             reset,
@@ -774,16 +1043,31 @@ impl Plot {
             grid_spacing,
             linked_axes,
             linked_cursors,
+            crosshair,
+            crosshair_snap,
 
             clamp_grid,
-            grid_spacers,
+            grid_spacers: custom_grid_spacers,
             sharp_grid_lines,
+            x_time_index,
+            axis_scales,
+            trend_line_tool,
+            export_svg,
+            follow_latest,
             sense,
 
             //grom
-            y_highlights,
+            mut y_highlights,
         } = self;
 
+        // Everything painted from here on is part of the plot.
+        let svg_shapes_start = export_svg.then(|| {
+            ui.ctx().graphics(|g| {
+                g.get(ui.layer_id())
+                    .map_or(0, |list| list.all_entries().len())
+            })
+        });
+
         // Determine position of widget.
         let pos = ui.available_rect_before_wrap().min;
         // Determine size of widget.
@@ -818,6 +1102,24 @@ impl Plot {
 
         let plot_id = id.unwrap_or_else(|| ui.make_persistent_id(id_source));
 
+        // Without a custom spacer, log axes get a grid of decades and their subdivisions.
+        let custom_y_spacer = custom_grid_spacers[1].is_some();
+        let mut grid_spacers = axis_scales.map(default_grid_spacer);
+        for (spacer, custom) in grid_spacers.iter_mut().zip(custom_grid_spacers) {
+            if let Some(custom) = custom {
+                *spacer = custom;
+            }
+        }
+
+        // With a time index, grid and tick labels work on time while the plot works on bar indices.
+        if let Some(time_index) = &x_time_index {
+            let x_spacer = std::mem::replace(&mut grid_spacers[0], log_grid_spacer(10));
+            grid_spacers[0] = time_index.wrap_grid_spacer(x_spacer);
+            for hints in &mut x_axes {
+                hints.formatter = time_index.wrap_axis_formatter(hints.formatter.clone());
+            }
+        }
+
         let ([x_axis_widgets, y_axis_widgets], plot_rect) = axis_widgets(
             PlotMemory::load(ui.ctx(), plot_id).as_ref(), // TODO(emilk): avoid loading plot memory twice
             show_axes,
@@ -846,10 +1148,18 @@ impl Plot {
             auto_bounds: default_auto_bounds,
             hovered_legend_item: None,
             hidden_items: Default::default(),
-            transform: PlotTransform::new(plot_rect, min_auto_bounds, center_axis.x, center_axis.y),
+            transform: PlotTransform::new(plot_rect, min_auto_bounds, center_axis.x, center_axis.y)
+                .with_scales(axis_scales),
             last_click_pos_for_zoom: None,
             x_axis_thickness: Default::default(),
             y_axis_thickness: Default::default(),
+            y_axes: Default::default(),
+            selected_trend_line: None,
+            trend_line_drag: None,
+            trend_line_anchor: None,
+            follow_paused: false,
+            hline_drag: None,
+            measure_start: None,
         });
 
         let last_plot_transform = mem.transform;
@@ -863,6 +1173,9 @@ impl Plot {
             last_auto_bounds: mem.auto_bounds,
             response,
             bounds_modifications: Vec::new(),
+            x_time_index: x_time_index.clone(),
+            trend_lines: Vec::new(),
+            draggable_hlines: Vec::new(),
         };
         let inner = build_fn(&mut plot_ui);
         let PlotUi {
@@ -870,6 +1183,8 @@ impl Plot {
             mut response,
             last_plot_transform,
             bounds_modifications,
+            mut trend_lines,
+            mut draggable_hlines,
             ..
         } = plot_ui;
 
@@ -886,8 +1201,16 @@ impl Plot {
         }
 
         // --- Legend ---
-        let legend = legend_config
-            .and_then(|config| LegendWidget::try_new(plot_rect, config, &items, &mem.hidden_items));
+        // Trend lines and draggable lines are kept apart until after their interaction, but are
+        // part of the legend like all other items.
+        let legend = legend_config.and_then(|config| {
+            let legend_items = items
+                .iter()
+                .map(|item| item.as_ref())
+                .chain(trend_lines.iter().map(|line| line as &dyn PlotItem))
+                .chain(draggable_hlines.iter().map(|line| line as &dyn PlotItem));
+            LegendWidget::try_new(plot_rect, config, legend_items, &mem.hidden_items)
+        });
         // Don't show hover cursor when hovering over legend.
         if mem.hovered_legend_item.is_some() {
             show_x = false;
@@ -895,16 +1218,36 @@ impl Plot {
         }
         // Remove the deselected items.
         items.retain(|item| !mem.hidden_items.contains(item.name()));
+        trend_lines.retain(|line| !mem.hidden_items.contains(PlotItem::name(line)));
+        draggable_hlines.retain(|line| !mem.hidden_items.contains(PlotItem::name(line)));
         // Highlight the hovered items.
         if let Some(hovered_name) = &mem.hovered_legend_item {
             items
                 .iter_mut()
                 .filter(|entry| entry.name() == hovered_name)
                 .for_each(|entry| entry.highlight());
+            trend_lines
+                .iter_mut()
+                .filter(|line| PlotItem::name(*line) == hovered_name)
+                .for_each(PlotItem::highlight);
+            draggable_hlines
+                .iter_mut()
+                .filter(|line| PlotItem::name(*line) == hovered_name)
+                .for_each(PlotItem::highlight);
         }
         // Move highlighted items to front.
         items.sort_by_key(|item| item.highlighted());
 
+        // Y axes with their own scale. Items bound to any other id use the main Y axis.
+        let y_axis_ids: Vec<Id> = y_axes.iter().filter_map(|hints| hints.id).collect();
+        let y_axis_scales: HashMap<Id, AxisScale> = y_axes
+            .iter()
+            .filter_map(|hints| Some((hints.id?, hints.scale.unwrap_or(axis_scales[1]))))
+            .collect();
+        mem.y_axes.retain(|id, _| y_axis_ids.contains(id));
+        let on_main_y_axis =
+            |item: &dyn PlotItem| !item.y_axis().is_some_and(|id| y_axis_ids.contains(&id));
+
         // --- Bound computation ---
         let mut bounds = *last_plot_transform.bounds();
 
@@ -956,6 +1299,10 @@ impl Plot {
         // Allow double-clicking to reset to the initial bounds.
         if allow_double_click_reset && response.double_clicked() {
             mem.auto_bounds = true.into();
+            mem.follow_paused = false;
+            for axis in mem.y_axes.values_mut() {
+                axis.auto_bounds = true;
+            }
         }
 
         // Apply bounds modifications.
@@ -966,14 +1313,26 @@ impl Plot {
                     mem.auto_bounds = false.into();
                 }
                 BoundsModification::Translate(delta) => {
-                    bounds.translate(delta);
+                    let mut scaled = bounds.scaled(axis_scales);
+                    scaled.translate(delta);
+                    bounds = scaled.unscaled(axis_scales);
                     mem.auto_bounds = false.into();
                 }
                 BoundsModification::AutoBounds(new_auto_bounds) => {
                     mem.auto_bounds = new_auto_bounds;
+                    mem.follow_paused = false;
+                    for axis in mem.y_axes.values_mut() {
+                        axis.auto_bounds = new_auto_bounds.y;
+                    }
                 }
                 BoundsModification::Zoom(zoom_factor, center) => {
-                    bounds.zoom(zoom_factor, center);
+                    let center = PlotPoint::new(
+                        axis_scales[0].scale(center.x),
+                        axis_scales[1].scale(center.y),
+                    );
+                    let mut scaled = bounds.scaled(axis_scales);
+                    scaled.zoom(zoom_factor, center);
+                    bounds = scaled.unscaled(axis_scales);
                     mem.auto_bounds = false.into();
                 }
             }
@@ -997,21 +1356,38 @@ impl Plot {
                 if auto_x {
                     bounds.merge_x(&item_bounds);
                 }
-                if auto_y {
+                if auto_y && on_main_y_axis(&**item) {
                     bounds.merge_y(&item_bounds);
                 }
             }
 
-            if auto_x {
-                bounds.add_relative_margin_x(margin_fraction);
-            }
+            bounds.add_relative_margin_scaled(
+                margin_fraction,
+                Vec2b::new(auto_x, auto_y),
+                axis_scales,
+            );
+        }
 
-            if auto_y {
-                bounds.add_relative_margin_y(margin_fraction);
+        // Keep the newest data at the right edge, with the usual margin.
+        let latest_x = follow_latest.then(|| {
+            items
+                .iter()
+                .map(|item| item.bounds().max[0])
+                .filter(|x| x.is_finite())
+                .fold(f64::NEG_INFINITY, f64::max)
+        });
+        if let Some(latest_x) = latest_x {
+            if !auto_x && !mem.follow_paused && latest_x.is_finite() {
+                let mut scaled = bounds.scaled(axis_scales);
+                let right =
+                    axis_scales[0].scale(latest_x) + margin_fraction.x as f64 * scaled.width();
+                scaled.translate_x(right - scaled.max[0]);
+                bounds = scaled.unscaled(axis_scales);
             }
         }
 
-        mem.transform = PlotTransform::new(plot_rect, bounds, center_axis.x, center_axis.y);
+        mem.transform = PlotTransform::new(plot_rect, bounds, center_axis.x, center_axis.y)
+            .with_scales(axis_scales);
 
         // Enforce aspect ratio
         if let Some(data_aspect) = data_aspect {
@@ -1029,8 +1405,98 @@ impl Plot {
             }
         }
 
+        // Y axes with their own scale share the X range of the main transform.
+        for &axis_id in &y_axis_ids {
+            let axis_scales = [axis_scales[0], y_axis_scales[&axis_id]];
+            let axis_mem = mem.y_axes.get(&axis_id).copied();
+            let auto_bounds = axis_mem.is_none_or(|axis| axis.auto_bounds);
+            let mut axis_bounds = *mem.transform.bounds();
+            if auto_bounds {
+                let mut items_bounds = PlotBounds::NOTHING;
+                for item in items.iter().filter(|item| item.y_axis() == Some(axis_id)) {
+                    items_bounds.merge_y(&item.bounds());
+                }
+                axis_bounds.set_y(&items_bounds);
+                axis_bounds.add_relative_margin_scaled(
+                    margin_fraction,
+                    Vec2b::new(false, true),
+                    axis_scales,
+                );
+            } else if let Some(axis_mem) = axis_mem {
+                axis_bounds.set_y(axis_mem.transform.bounds());
+            }
+            let transform = PlotTransform::new(plot_rect, axis_bounds, false, center_axis.y)
+                .with_scales(axis_scales);
+            mem.y_axes.insert(
+                axis_id,
+                YAxisMemory {
+                    auto_bounds,
+                    transform,
+                },
+            );
+        }
+
+        // Dragging a Y axis pans only that axis.
+        if allow_drag.y {
+            for (i, widget) in y_axis_widgets.iter().enumerate() {
+                let axis_response = ui
+                    .interact(widget.rect, plot_id.with(("y_axis", i)), Sense::drag())
+                    .on_hover_cursor(CursorIcon::ResizeVertical);
+                if !axis_response.dragged_by(PointerButton::Primary) {
+                    continue;
+                }
+                let delta = vec2(0.0, -axis_response.drag_delta().y);
+                match widget.hints.id.and_then(|id| mem.y_axes.get_mut(&id)) {
+                    Some(axis) => {
+                        axis.transform.translate_bounds(delta);
+                        axis.auto_bounds = false;
+                    }
+                    None => {
+                        mem.transform.translate_bounds(delta);
+                        mem.auto_bounds.y = false;
+                    }
+                }
+            }
+        }
+
+        // Trend lines take precedence over dragging the plot.
+        let mut trend_line_events = Vec::new();
+        let trend_line_cursor = items::interact_trend_lines(
+            ui,
+            &response,
+            &mut mem,
+            &mut trend_lines,
+            trend_line_tool,
+            &mut trend_line_events,
+        );
+        let (dragged_hline, hline_cursor) = items::interact_draggable_hlines(
+            ui,
+            &response,
+            &mut mem,
+            &mut draggable_hlines,
+            &mut y_highlights,
+        );
+
+        let measurement = allow_measure
+            .then(|| {
+                measure::interact_measure(
+                    ui,
+                    &response,
+                    &mut mem,
+                    measure_pointer_button,
+                    measure_modifiers,
+                    x_time_index.as_ref(),
+                )
+            })
+            .flatten();
+
         // Dragging
-        if allow_drag.any() && response.dragged_by(PointerButton::Primary) {
+        if allow_drag.any()
+            && response.dragged_by(PointerButton::Primary)
+            && mem.trend_line_drag.is_none()
+            && mem.hline_drag.is_none()
+            && mem.measure_start.is_none()
+        {
             response = response.on_hover_cursor(CursorIcon::Grabbing);
             let mut delta = -response.drag_delta();
             if !allow_drag.x {
@@ -1041,11 +1507,20 @@ impl Plot {
             }
             mem.transform.translate_bounds(delta);
             mem.auto_bounds = !allow_drag;
+            if let Some(latest_x) = latest_x {
+                if delta.x != 0.0 {
+                    mem.follow_paused = mem.transform.bounds().max[0] < latest_x;
+                }
+            }
+            for axis in mem.y_axes.values_mut() {
+                axis.transform.translate_bounds(delta);
+                axis.auto_bounds = !allow_drag.y;
+            }
         }
 
         // Zooming
         let mut boxed_zoom_rect = None;
-        if allow_boxed_zoom {
+        if allow_boxed_zoom && mem.measure_start.is_none() {
             // Save last click to allow boxed zooming
             if response.drag_started() && response.dragged_by(boxed_zoom_pointer_button) {
                 // it would be best for egui that input has a memory of the last click pos because it's a common pattern
@@ -1073,6 +1548,17 @@ impl Plot {
                 }
                 // when the click is release perform the zoom
                 if response.drag_stopped() {
+                    for axis in mem.y_axes.values_mut() {
+                        let y_start = axis.transform.value_from_position(box_start_pos).y;
+                        let y_end = axis.transform.value_from_position(box_end_pos).y;
+                        let mut axis_bounds = *axis.transform.bounds();
+                        axis_bounds.min[1] = y_start.min(y_end);
+                        axis_bounds.max[1] = y_start.max(y_end);
+                        if axis_bounds.is_valid_y() {
+                            axis.transform.set_bounds(axis_bounds);
+                            axis.auto_bounds = false;
+                        }
+                    }
                     let box_start_pos = mem.transform.value_from_position(box_start_pos);
                     let box_end_pos = mem.transform.value_from_position(box_end_pos);
                     let new_bounds = PlotBounds {
@@ -1112,6 +1598,10 @@ impl Plot {
                 if zoom_factor != Vec2::splat(1.0) {
                     mem.transform.zoom(zoom_factor, hover_pos);
                     mem.auto_bounds = !allow_zoom;
+                    for axis in mem.y_axes.values_mut() {
+                        axis.transform.zoom(zoom_factor, hover_pos);
+                        axis.auto_bounds = !allow_zoom.y;
+                    }
                 }
             }
             if allow_scroll.any() {
@@ -1125,52 +1615,188 @@ impl Plot {
                 if scroll_delta != Vec2::ZERO {
                     mem.transform.translate_bounds(-scroll_delta);
                     mem.auto_bounds = false.into();
+                    if let Some(latest_x) = latest_x {
+                        if scroll_delta.x != 0.0 {
+                            mem.follow_paused = mem.transform.bounds().max[0] < latest_x;
+                        }
+                    }
+                    for axis in mem.y_axes.values_mut() {
+                        axis.transform.translate_bounds(-scroll_delta);
+                        axis.auto_bounds = false;
+                    }
                 }
             }
         }
 
         // --- transform initialized
 
+        for axis in mem.y_axes.values_mut() {
+            let mut axis_bounds = *axis.transform.bounds();
+            axis_bounds.set_x(mem.transform.bounds());
+            axis.transform.set_bounds(axis_bounds);
+        }
+
+        items.extend(
+            trend_lines
+                .into_iter()
+                .map(|line| Box::new(line) as Box<dyn PlotItem>),
+        );
+        items.extend(
+            draggable_hlines
+                .into_iter()
+                .map(|line| Box::new(line) as Box<dyn PlotItem>),
+        );
+
+        // Initialize values from functions.
+        for item in &mut items {
+            item.initialize(mem.transform.bounds().range_x());
+        }
+
+        let y_axis_transforms: HashMap<Id, PlotTransform> = mem
+            .y_axes
+            .iter()
+            .map(|(id, axis)| (*id, axis.transform))
+            .collect();
+
+        // Y scale modes show values relative to the leftmost visible data point of the axis items.
+        let x_range = mem.transform.bounds().range_x();
+        let y_rebase = |hints: &AxisHints| -> Option<YRebase> {
+            if hints.scale_mode == YScaleMode::Normal {
+                return None;
+            }
+            let axis = hints.id.filter(|id| y_axis_transforms.contains_key(id));
+            let reference = items
+                .iter()
+                .filter(|item| match axis {
+                    Some(id) => item.y_axis() == Some(id),
+                    None => on_main_y_axis(&***item),
+                })
+                .filter_map(|item| item.first_value(&x_range))
+                .min_by_key(|point| point.x.ord())?;
+            YRebase::new(hints.scale_mode, reference.y)
+        };
+        let main_y_rebase = y_axes
+            .iter()
+            .find(|hints| {
+                !hints
+                    .id
+                    .is_some_and(|id| y_axis_transforms.contains_key(&id))
+            })
+            .and_then(y_rebase);
+        let y_rebases: HashMap<Id, YRebase> = y_axes
+            .iter()
+            .filter_map(|hints| {
+                let id = hints.id.filter(|id| y_axis_transforms.contains_key(id))?;
+                Some((id, y_rebase(hints)?))
+            })
+            .collect();
+
+        // The crosshair of this plot, or the linked cursors of other plots, shown in the axes.
+        let crosshair_point = crosshair
+            .then(|| response.hover_pos())
+            .flatten()
+            .filter(|pointer| plot_rect.contains(*pointer))
+            .map(|pointer| {
+                crosshair::crosshair_point(
+                    &items,
+                    |item| {
+                        item.y_axis()
+                            .and_then(|id| y_axis_transforms.get(&id))
+                            .unwrap_or(&mem.transform)
+                    },
+                    &mem.transform,
+                    pointer,
+                    crosshair_snap,
+                    x_time_index.as_ref(),
+                )
+            });
+        let crosshair_badges = crosshair.then(|| {
+            let linked = linked_cursors
+                .as_ref()
+                .map_or(Vec2b::FALSE, |group| group.1);
+            let linked_x = || {
+                draw_cursors.iter().find_map(|cursor| match cursor {
+                    Cursor::Vertical { x } => linked.x.then_some(*x),
+                    Cursor::Horizontal { .. } => None,
+                })
+            };
+            let linked_y = || {
+                draw_cursors.iter().find_map(|cursor| match cursor {
+                    Cursor::Horizontal { y } => linked.y.then_some(*y),
+                    Cursor::Vertical { .. } => None,
+                })
+            };
+            [
+                crosshair_point.map(|point| point.x).or_else(linked_x),
+                crosshair_point.map(|point| point.y).or_else(linked_y),
+            ]
+        });
+        let [crosshair_x, crosshair_y] = crosshair_badges.unwrap_or_default();
+
         // Add legend widgets to plot
         let bounds = mem.transform.bounds();
         let x_axis_range = bounds.range_x();
-        let x_steps = Arc::new({
-            let input = GridInput {
-                bounds: (bounds.min[0], bounds.max[0]),
-                base_step_size: mem.transform.dvalue_dpos()[0].abs() * grid_spacing.min as f64,
-            };
-            (grid_spacers[0])(input)
-        });
+        let x_steps = Arc::new(mem.transform.grid_marks(
+            Axis::X,
+            &grid_spacers[0],
+            grid_spacing.min,
+        ));
         let y_axis_range = bounds.range_y();
-        let y_steps = Arc::new({
-            let input = GridInput {
-                bounds: (bounds.min[1], bounds.max[1]),
-                base_step_size: mem.transform.dvalue_dpos()[1].abs() * grid_spacing.min as f64,
-            };
-            (grid_spacers[1])(input)
-        });
+        let y_steps = Arc::new(mem.transform.grid_marks(
+            Axis::Y,
+            &grid_spacers[1],
+            grid_spacing.min,
+        ));
         for (i, mut widget) in x_axis_widgets.into_iter().enumerate() {
             widget.range = x_axis_range.clone();
             widget.transform = Some(mem.transform);
             widget.steps = x_steps.clone();
+            widget.crosshair = crosshair_x;
             let (_response, thickness) = widget.ui(ui, Axis::X);
             mem.x_axis_thickness.insert(i, thickness);
         }
         for (i, mut widget) in y_axis_widgets.into_iter().enumerate() {
-            widget.range = y_axis_range.clone();
-            widget.transform = Some(mem.transform);
-            widget.steps = y_steps.clone();
-            // grom
-            widget.highlights = y_highlights.clone();
+            let rebase = y_rebase(&widget.hints);
+            if let Some(axis) = widget.hints.id.and_then(|id| mem.y_axes.get(&id)) {
+                let axis_bounds = axis.transform.bounds();
+                widget.range = axis_bounds.range_y();
+                widget.transform = Some(axis.transform);
+                // An axis with a scale of its own gets the default grid of that scale.
+                let axis_scale = axis.transform.scale(Axis::Y);
+                let own_spacer = (axis_scale != axis_scales[1] && !custom_y_spacer)
+                    .then(|| default_grid_spacer(axis_scale));
+                let spacer = own_spacer.as_ref().unwrap_or(&grid_spacers[1]);
+                widget.steps = Arc::new(match &rebase {
+                    Some(rebase) => rebase.grid_marks(&axis.transform, spacer, grid_spacing.min),
+                    None => axis.transform.grid_marks(Axis::Y, spacer, grid_spacing.min),
+                });
+                // The crosshair is in main axis coordinates.
+                widget.crosshair = crosshair_y.map(|y| {
+                    let pos_y = mem.transform.position_from_point_y(y);
+                    axis.transform.value_from_position(pos2(0.0, pos_y)).y
+                });
+            } else {
+                widget.range = y_axis_range.clone();
+                widget.transform = Some(mem.transform);
+                widget.steps = match &rebase {
+                    Some(rebase) => Arc::new(rebase.grid_marks(
+                        &mem.transform,
+                        &grid_spacers[1],
+                        grid_spacing.min,
+                    )),
+                    None => y_steps.clone(),
+                };
+                widget.crosshair = crosshair_y;
+                // grom
+                widget.highlights = y_highlights.clone();
+            }
+            if let Some(rebase) = rebase {
+                widget.hints.formatter = rebase.wrap_axis_formatter(widget.hints.formatter.clone());
+            }
             let (_response, thickness) = widget.ui(ui, Axis::Y);
             mem.y_axis_thickness.insert(i, thickness);
         }
 
-        // Initialize values from functions.
-        for item in &mut items {
-            item.initialize(mem.transform.bounds().range_x());
-        }
-
         let prepared = PreparedPlot {
             items,
             show_x,
@@ -1180,12 +1806,17 @@ impl Plot {
             show_grid,
             grid_spacing,
             transform: mem.transform,
-            draw_cursor_x: linked_cursors.as_ref().map_or(false, |group| group.1.x),
-            draw_cursor_y: linked_cursors.as_ref().map_or(false, |group| group.1.y),
+            y_axis_transforms,
+            draw_cursor_x: linked_cursors.as_ref().is_some_and(|group| group.1.x),
+            draw_cursor_y: linked_cursors.as_ref().is_some_and(|group| group.1.y),
             draw_cursors,
+            crosshair: crosshair_point,
+            y_rebase: main_y_rebase,
+            y_rebases,
             grid_spacers,
             sharp_grid_lines,
             clamp_grid,
+            x_time_index,
         };
 
         let (plot_cursors, hovered_plot_item) = prepared.ui(ui, &response);
@@ -1199,6 +1830,10 @@ impl Plot {
                 .add(boxed_zoom_rect.1);
         }
 
+        if let Some(measurement) = &measurement {
+            measure::paint_measurement(ui, &mem.transform, measurement, measure_formatter.as_ref());
+        }
+
         if let Some(mut legend) = legend {
             ui.add(&mut legend);
             mem.hidden_items = legend.hidden_items();
@@ -1239,14 +1874,31 @@ impl Plot {
         } else {
             response
         };
+        if let Some(cursor) = trend_line_cursor.or(hline_cursor) {
+            ui.ctx().set_cursor_icon(cursor);
+        }
 
         ui.advance_cursor_after_rect(complete_rect);
 
+        let svg = svg_shapes_start.map(|start| {
+            ui.ctx().graphics(|g| {
+                let shapes = g
+                    .get(ui.layer_id())
+                    .into_iter()
+                    .flat_map(|list| list.all_entries());
+                shapes_to_svg(shapes.skip(start), complete_rect)
+            })
+        });
+
         PlotResponse {
             inner,
             response,
             transform,
             hovered_plot_item,
+            trend_line_events,
+            dragged_hline,
+            svg,
+            measurement,
         }
     }
 }
@@ -1434,6 +2086,52 @@ pub fn log_grid_spacer(log_base: i64) -> GridSpacer {
     Box::new(step_sizes)
 }
 
+/// The grid spacer of an axis without a custom one: decades and their subdivisions on a log
+/// axis, powers of ten otherwise.
+fn default_grid_spacer(scale: AxisScale) -> GridSpacer {
+    match scale {
+        AxisScale::Log { base } => log_scale_grid_spacer(base),
+        AxisScale::Linear | AxisScale::SymLog { .. } => log_grid_spacer(10),
+    }
+}
+
+/// Grid spacer for an axis with [`AxisScale::Log`] of the given base, the default for such axes.
+///
+/// Grid lines are placed at the powers of `base` (e.g. 1, 10, 100) with the strongest lines at
+/// every tenth power, and for integer bases at the multiples in between (2, 3, …, 9 for base 10).
+/// When the powers get too close together, only every 10th, 100th, … power is shown.
+pub fn log_scale_grid_spacer(base: f64) -> GridSpacer {
+    let decimal_spacer = log_grid_spacer(10);
+    let step_sizes = move |input: GridInput| -> Vec<GridMark> {
+        // handle degenerate cases
+        if input.base_step_size.abs() < f64::EPSILON {
+            return Vec::new();
+        }
+        // The input is in powers of `base`, so a step of 1 is one power.
+        if input.base_step_size > 1.0 {
+            return decimal_spacer(input);
+        }
+
+        let (min, max) = input.bounds;
+        let mut marks = generate_marks([1.0, 10.0, 100.0], input.bounds);
+        if base.fract() == 0.0 && base > 2.0 {
+            // Multiples share a step size of their average distance, so they fade in together.
+            let step_size = 1.0 / (base - 1.0);
+            for power in (min.floor() as i64)..=(max.floor() as i64) {
+                for multiple in 2..(base as i64) {
+                    let value = power as f64 + (multiple as f64).log(base);
+                    if (min..=max).contains(&value) {
+                        marks.push(GridMark { value, step_size });
+                    }
+                }
+            }
+        }
+        marks
+    };
+
+    Box::new(step_sizes)
+}
+
 /// Splits the grid into uniform-sized spacings (e.g. 100, 25, 1).
 ///
 /// This function should return 3 positive step sizes, designating where the lines in the grid are drawn.
@@ -1461,18 +2159,41 @@ struct PreparedPlot {
     coordinates_formatter: Option<(Corner, CoordinatesFormatter)>,
     // axis_formatters: [AxisFormatter; 2],
     transform: PlotTransform,
+    y_axis_transforms: HashMap<Id, PlotTransform>,
     show_grid: Vec2b,
     grid_spacing: Rangef,
     grid_spacers: [GridSpacer; 2],
     draw_cursor_x: bool,
     draw_cursor_y: bool,
     draw_cursors: Vec<Cursor>,
+    crosshair: Option<PlotPoint>,
+    y_rebase: Option<YRebase>,
+    y_rebases: HashMap<Id, YRebase>,
 
     sharp_grid_lines: bool,
     clamp_grid: bool,
+    x_time_index: Option<TimeIndex>,
 }
 
 impl PreparedPlot {
+    /// The transform of the Y axis the item is bound to.
+    fn item_transform(&self, item: &dyn PlotItem) -> &PlotTransform {
+        item.y_axis()
+            .and_then(|id| self.y_axis_transforms.get(&id))
+            .unwrap_or(&self.transform)
+    }
+
+    /// The Y scale mode of the axis the item is bound to.
+    fn item_rebase(&self, item: &dyn PlotItem) -> Option<YRebase> {
+        match item
+            .y_axis()
+            .filter(|id| self.y_axis_transforms.contains_key(id))
+        {
+            Some(id) => self.y_rebases.get(&id).copied(),
+            None => self.y_rebase,
+        }
+    }
+
     fn ui(self, ui: &mut Ui, response: &Response) -> (Vec<Cursor>, Option<Id>) {
         let mut axes_shapes = Vec::new();
 
@@ -1493,15 +2214,22 @@ impl PreparedPlot {
         let mut plot_ui = ui.child_ui(*transform.frame(), Layout::default());
         plot_ui.set_clip_rect(transform.frame().intersect(ui.clip_rect()));
         for item in &self.items {
-            item.shapes(&plot_ui, transform, &mut shapes);
+            item.shapes(&plot_ui, self.item_transform(&**item), &mut shapes);
         }
 
         let hover_pos = response.hover_pos();
-        let (cursors, hovered_item_id) = if let Some(pointer) = hover_pos {
+        let (mut cursors, hovered_item_id) = if let Some(pointer) = hover_pos {
             self.hover(ui, pointer, &mut shapes)
         } else {
             (Vec::new(), None)
         };
+        if let Some(crosshair) = self.crosshair {
+            // The crosshair replaces the item rulers, also in linked plots.
+            cursors = vec![
+                Cursor::Vertical { x: crosshair.x },
+                Cursor::Horizontal { y: crosshair.y },
+            ];
+        }
 
         // Draw cursors
         let line_color = rulers_color(ui);
@@ -1541,7 +2269,13 @@ impl PreparedPlot {
             let hover_pos = response.hover_pos();
             if let Some(pointer) = hover_pos {
                 let font_id = TextStyle::Monospace.resolve(ui.style());
-                let coordinate = transform.value_from_position(pointer);
+                let mut coordinate = transform.value_from_position(pointer);
+                if let Some(time_index) = &self.x_time_index {
+                    coordinate.x = time_index.time_from_index(coordinate.x);
+                }
+                if let Some(rebase) = &self.y_rebase {
+                    coordinate.y = rebase.rebase(coordinate.y);
+                }
                 let text = formatter.format(&coordinate, transform.bounds());
                 let padded_frame = transform.frame().shrink(4.0);
                 let (anchor, position) = match corner {
@@ -1573,11 +2307,12 @@ impl PreparedPlot {
         let bounds = transform.bounds();
         let value_cross = 0.0_f64.clamp(bounds.min[1 - iaxis], bounds.max[1 - iaxis]);
 
-        let input = GridInput {
-            bounds: (bounds.min[iaxis], bounds.max[iaxis]),
-            base_step_size: transform.dvalue_dpos()[iaxis].abs() * fade_range.min as f64,
+        let steps = match (axis, &self.y_rebase) {
+            (Axis::Y, Some(rebase)) => {
+                rebase.grid_marks(transform, &grid_spacers[iaxis], fade_range.min)
+            }
+            _ => transform.grid_marks(axis, &grid_spacers[iaxis], fade_range.min),
         };
-        let steps = (grid_spacers[iaxis])(input);
 
         let clamp_range = clamp_grid.then(|| {
             let mut tight_bounds = PlotBounds::NOTHING;
@@ -1668,11 +2403,11 @@ impl PreparedPlot {
             return (Vec::new(), None);
         }
 
-        let interact_radius_sq = (16.0_f32).powi(2);
+        let interact_radius_sq = IndexedPoints::HOVER_RADIUS.powi(2);
 
         let candidates = items.iter().filter_map(|item| {
             let item = &**item;
-            let closest = item.find_closest(pointer, transform);
+            let closest = item.find_closest(pointer, self.item_transform(item));
 
             Some(item).zip(closest)
         });
@@ -1686,18 +2421,38 @@ impl PreparedPlot {
             transform,
             show_x: *show_x,
             show_y: *show_y,
+            x_time_index: self.x_time_index.as_ref(),
+            y_rebase: self.y_rebase,
         };
 
         let mut cursors = Vec::new();
 
         let hovered_plot_item_id = if let Some((item, elem)) = closest {
-            item.on_hover(elem, shapes, &mut cursors, &plot, label_formatter);
+            let item_transform = self.item_transform(item);
+            let item_plot = items::PlotConfig {
+                transform: item_transform,
+                y_rebase: self.item_rebase(item),
+                ..plot
+            };
+            item.on_hover(elem, shapes, &mut cursors, &item_plot, label_formatter);
+
+            // Cursors are drawn (and shared with linked plots) in main axis coordinates.
+            if !std::ptr::eq(item_transform, transform) {
+                for cursor in &mut cursors {
+                    if let Cursor::Horizontal { y } = cursor {
+                        let pos_y = item_transform.position_from_point_y(*y);
+                        *y = transform.value_from_position(pos2(0.0, pos_y)).y;
+                    }
+                }
+            }
             item.id()
-        } else {
+        } else if self.crosshair.is_none() {
+            // Without an item nearby, the crosshair badges already show the pointer value.
             let value = transform.value_from_position(pointer);
             items::rulers_at_value(
                 pointer,
                 value,
+                Default::default(),
                 "",
                 &plot,
                 shapes,
@@ -1705,6 +2460,8 @@ impl PreparedPlot {
                 label_formatter,
             );
             None
+        } else {
+            None
         };
 
         (cursors, hovered_plot_item_id)
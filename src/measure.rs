@@ -0,0 +1,185 @@
+//! Measuring the distance between two points of a plot.
+
+use egui::{
+    vec2, Align, Align2, Color32, Modifiers, PointerButton, Rect, Response, Shape, Stroke,
+    TextStyle, Ui,
+};
+
+use crate::{
+    format_duration, items::step_decimals, PlotMemory, PlotPoint, PlotTransform, TimeIndex,
+};
+
+type MeasureFormatterFn = dyn Fn(&Measurement) -> String;
+pub(crate) type MeasureFormatter = Box<MeasureFormatterFn>;
+
+/// The distance between two points that the user measured, see [`crate::Plot::allow_measure`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Measurement {
+    /// Where the measurement started.
+    pub start: PlotPoint,
+
+    /// Where the measurement ends, i.e. the pointer position.
+    pub end: PlotPoint,
+
+    /// The time elapsed from `start` to `end` in seconds, if the plot has a
+    /// [`crate::Plot::x_time_index`]. X values are bar indices then.
+    pub duration: Option<f64>,
+
+    /// The user released the pointer button this frame, so this is the final measurement.
+    pub finished: bool,
+}
+
+impl Measurement {
+    /// Difference of the X values, e.g. the number of bars.
+    #[inline]
+    pub fn delta_x(&self) -> f64 {
+        self.end.x - self.start.x
+    }
+
+    /// Difference of the Y values, e.g. the price change.
+    #[inline]
+    pub fn delta_y(&self) -> f64 {
+        self.end.y - self.start.y
+    }
+
+    /// Change of the Y value in percent of the start value, if that isn't zero.
+    pub fn percent_change(&self) -> Option<f64> {
+        (self.start.y != 0.0).then(|| 100.0 * self.delta_y() / self.start.y.abs())
+    }
+
+    fn new(start: PlotPoint, end: PlotPoint, time_index: Option<&TimeIndex>) -> Self {
+        Self {
+            start,
+            end,
+            duration: time_index
+                .map(|index| index.time_from_index(end.x) - index.time_from_index(start.x)),
+            finished: false,
+        }
+    }
+
+    /// The label text: ΔY, ΔY% and ΔX with decimals fitting the zoom level of `transform` at the
+    /// end of the measurement.
+    fn text(&self, transform: &PlotTransform) -> String {
+        let scale = transform.dvalue_dpos_at(&self.end);
+        let y_decimals = step_decimals(scale[1]);
+
+        let mut text = format!("ΔY = {:+.*}", y_decimals, self.delta_y());
+        if let Some(percent) = self.percent_change() {
+            text.push_str(&format!(" ({percent:+.2}%)"));
+        }
+        match self.duration {
+            Some(duration) => text.push_str(&format!(
+                "\nΔX = {:+.0} bars, {}",
+                self.delta_x(),
+                format_duration(duration)
+            )),
+            None => text.push_str(&format!(
+                "\nΔX = {:+.*}",
+                step_decimals(scale[0]),
+                self.delta_x()
+            )),
+        }
+        text
+    }
+}
+
+/// Start, update and finish a measurement with the pointer.
+///
+/// Returns the measurement to report, if the user is measuring.
+pub(crate) fn interact_measure(
+    ui: &Ui,
+    response: &Response,
+    mem: &mut PlotMemory,
+    button: PointerButton,
+    modifiers: Modifiers,
+    time_index: Option<&TimeIndex>,
+) -> Option<Measurement> {
+    let transform = mem.transform;
+
+    if response.drag_started_by(button)
+        && mem.trend_line_drag.is_none()
+        && mem.hline_drag.is_none()
+        && ui.input(|i| i.modifiers.matches_logically(modifiers))
+    {
+        mem.measure_start = ui
+            .input(|i| i.pointer.press_origin())
+            .map(|origin| transform.value_from_position(origin));
+    }
+
+    let start = mem.measure_start?;
+    let end = response
+        .interact_pointer_pos()
+        .map_or(start, |pointer| transform.value_from_position(pointer));
+    let mut measurement = Measurement::new(start, end, time_index);
+    if !response.dragged_by(button) {
+        mem.measure_start = None;
+        measurement.finished = true;
+    }
+    Some(measurement)
+}
+
+/// Paint the measured area with its label.
+pub(crate) fn paint_measurement(
+    ui: &Ui,
+    transform: &PlotTransform,
+    measurement: &Measurement,
+    formatter: Option<&MeasureFormatter>,
+) {
+    let painter = ui.painter().with_clip_rect(*transform.frame());
+    let start = transform.position_from_point(&measurement.start);
+    let end = transform.position_from_point(&measurement.end);
+
+    let visuals = ui.visuals();
+    let color = if measurement.delta_y() < 0.0 {
+        visuals.error_fg_color
+    } else {
+        visuals.selection.stroke.color
+    };
+    painter.rect(
+        Rect::from_two_pos(start, end),
+        0.0,
+        color.gamma_multiply(0.2),
+        Stroke::NONE,
+    );
+    painter.line_segment([start, end], Stroke::new(1.0, color));
+
+    let mut text = measurement.text(transform);
+    if let Some(formatter) = formatter {
+        text.push('\n');
+        text.push_str(&formatter(measurement));
+    }
+
+    // Put the label beyond the end of the measurement, but keep it inside the plot.
+    let galley = painter.layout_no_wrap(
+        text,
+        TextStyle::Body.resolve(ui.style()),
+        visuals.text_color(),
+    );
+    let anchor = Align2([
+        if end.x >= start.x {
+            Align::Min
+        } else {
+            Align::Max
+        },
+        if end.y <= start.y {
+            Align::Max
+        } else {
+            Align::Min
+        },
+    ]);
+    let margin = vec2(6.0, 4.0);
+    let offset = vec2(
+        if end.x >= start.x { 8.0 } else { -8.0 },
+        if end.y <= start.y { -8.0 } else { 8.0 },
+    );
+    let frame = transform.frame().shrink2(margin);
+    let mut rect = anchor.anchor_size(end + offset, galley.size() + 2.0 * margin);
+    rect = rect.translate(vec2(
+        (frame.left() - rect.left()).max(0.0) + (frame.right() - rect.right()).min(0.0),
+        (frame.top() - rect.top()).max(0.0) + (frame.bottom() - rect.bottom()).min(0.0),
+    ));
+
+    painter.add(Shape::rect_filled(rect, 4.0, visuals.window_fill));
+    painter.add(Shape::rect_stroke(rect, 4.0, Stroke::new(1.0, color)));
+    painter.galley(rect.min + margin, galley, Color32::PLACEHOLDER);
+}
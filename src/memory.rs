@@ -2,7 +2,10 @@ use std::collections::BTreeMap;
 
 use egui::{ahash, Context, Id, Pos2, Vec2b};
 
-use crate::{PlotBounds, PlotTransform};
+use crate::{
+    items::{HLineDragState, TrendLineDrag},
+    PlotBounds, PlotPoint, PlotTransform,
+};
 
 /// Information about the plot that has to persist between frames.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -32,6 +35,44 @@ pub struct PlotMemory {
     /// in order to fit the labels, if necessary.
     pub(crate) x_axis_thickness: BTreeMap<usize, f32>,
     pub(crate) y_axis_thickness: BTreeMap<usize, f32>,
+
+    /// The state of every Y axis with its own scale, by [`crate::AxisHints::id`].
+    pub(crate) y_axes: ahash::HashMap<Id, YAxisMemory>,
+
+    /// The [`crate::TrendLine`] selected by clicking on it.
+    pub(crate) selected_trend_line: Option<Id>,
+
+    /// The trend line being dragged, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) trend_line_drag: Option<TrendLineDrag>,
+
+    /// The first point of a trend line being drawn with [`crate::Plot::trend_line_tool`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) trend_line_anchor: Option<PlotPoint>,
+
+    /// Whether the user has dragged the newest data out of view, see
+    /// [`crate::Plot::follow_latest`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) follow_paused: bool,
+
+    /// The [`crate::DraggableHLine`] being dragged, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) hline_drag: Option<HLineDragState>,
+
+    /// Where the measurement with [`crate::Plot::allow_measure`] started, while measuring.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) measure_start: Option<PlotPoint>,
+}
+
+/// State of a Y axis that has its own scale.
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct YAxisMemory {
+    /// Whether the Y range of this axis is fitted to its items.
+    pub(crate) auto_bounds: bool,
+
+    /// The transform from last frame. Its X range is that of the main transform.
+    pub(crate) transform: PlotTransform,
 }
 
 impl PlotMemory {
@@ -56,6 +97,20 @@ impl PlotMemory {
     pub fn set_bounds(&mut self, bounds: PlotBounds) {
         self.transform.set_bounds(bounds);
     }
+
+    /// The transform of the Y axis with the given id, if it has its own scale.
+    ///
+    /// See [`crate::AxisHints::id`].
+    #[inline]
+    pub fn y_axis_transform(&self, axis: Id) -> Option<PlotTransform> {
+        self.y_axes.get(&axis).map(|axis| axis.transform)
+    }
+
+    /// The id of the selected [`crate::TrendLine`], if any.
+    #[inline]
+    pub fn selected_trend_line(&self) -> Option<Id> {
+        self.selected_trend_line
+    }
 }
 
 #[cfg(feature = "serde")]
@@ -1,5 +1,7 @@
 use crate::*;
 
+use crate::items::ComparedLine;
+
 /// Provides methods to interact with a plot while building it. It is the single argument of the closure
 /// provided to [`Plot::show`]. See [`Plot`] for an example of how to use it.
 pub struct PlotUi {
@@ -10,6 +12,9 @@ pub struct PlotUi {
     pub(crate) last_auto_bounds: Vec2b,
     pub(crate) response: Response,
     pub(crate) bounds_modifications: Vec<BoundsModification>,
+    pub(crate) x_time_index: Option<TimeIndex>,
+    pub(crate) trend_lines: Vec<TrendLine>,
+    pub(crate) draggable_hlines: Vec<DraggableHLine>,
 }
 
 impl PlotUi {
@@ -81,17 +86,28 @@ impl PlotUi {
     /// - `zoom_factor < 1.0` zooms out, i.e., increases the visible range to show more data.
     /// - `zoom_factor > 1.0` zooms in, i.e., reduces the visible range to show more detail.
     pub fn zoom_bounds_around_hovered(&mut self, zoom_factor: Vec2) {
-        if let Some(hover_pos) = self.pointer_coordinate() {
+        if let Some(hover_pos) = self.pointer_position() {
             self.zoom_bounds(zoom_factor, hover_pos);
         }
     }
 
     /// The pointer position in plot coordinates. Independent of whether the pointer is in the plot area.
+    ///
+    /// With a [`Plot::x_time_index`], X is the timestamp under the pointer rather than the bar
+    /// index, see [`TimeIndex::time_from_index`].
     pub fn pointer_coordinate(&self) -> Option<PlotPoint> {
+        let mut value = self.pointer_position()?;
+        if let Some(time_index) = &self.x_time_index {
+            value.x = time_index.time_from_index(value.x);
+        }
+        Some(value)
+    }
+
+    /// The pointer position in plot coordinates, with X in bar indices if there is a time index.
+    fn pointer_position(&self) -> Option<PlotPoint> {
         // We need to subtract the drag delta to keep in sync with the frame-delayed screen transform:
         let last_pos = self.ctx().input(|i| i.pointer.latest_pos())? - self.response.drag_delta();
-        let value = self.plot_from_screen(last_pos);
-        Some(value)
+        Some(self.plot_from_screen(last_pos))
     }
 
     /// The pointer drag delta in plot coordinates.
@@ -147,6 +163,61 @@ impl PlotUi {
         self.items.push(Box::new(polygon));
     }
 
+    /// Add a filled band between two series.
+    pub fn band(&mut self, mut band: Band) {
+        if band.upper.is_empty() || band.lower.is_empty() {
+            return;
+        };
+
+        // Give the fill an automatic color if no color has been assigned.
+        if band.fill == Color32::TRANSPARENT {
+            band.fill = self.auto_color();
+        }
+        self.items.push(Box::new(band));
+    }
+
+    /// Add several series as changes in percent from a common anchor, see [`Comparison`].
+    pub fn comparison(&mut self, comparison: Comparison) {
+        let anchor = comparison
+            .anchor
+            .unwrap_or_else(|| self.plot_bounds().min()[0]);
+        for mut line in comparison.series {
+            if line.series.is_empty() {
+                continue;
+            }
+
+            // Assign the color first, so the colors don't change when a series is skipped.
+            if line.stroke.color == Color32::TRANSPARENT {
+                line.stroke.color = self.auto_color();
+            }
+            if let Some(line) = ComparedLine::new(line, anchor) {
+                self.items.push(Box::new(line));
+            }
+        }
+    }
+
+    /// Add a grid of values drawn as colored cells, see [`Heatmap`].
+    pub fn heatmap(&mut self, heatmap: Heatmap) {
+        if heatmap.grid.is_empty() {
+            return;
+        };
+
+        self.items.push(Box::new(heatmap));
+    }
+
+    /// Add isolines of a grid of values, see [`Contour`].
+    pub fn contour(&mut self, mut contour: Contour) {
+        if contour.grid.is_empty() {
+            return;
+        };
+
+        // Give the lines an automatic color if no color has been assigned.
+        if contour.stroke.color == Color32::TRANSPARENT {
+            contour.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(contour));
+    }
+
     /// Add a text.
     pub fn text(&mut self, text: Text) {
         if text.text.is_empty() {
@@ -207,6 +278,24 @@ impl PlotUi {
         self.items.push(Box::new(vline));
     }
 
+    /// Add a horizontal span, shading the range between two Y values.
+    /// Always fills the full width of the plot.
+    pub fn hspan(&mut self, mut hspan: HSpan) {
+        if hspan.span.stroke.color == Color32::TRANSPARENT {
+            hspan.span.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(hspan));
+    }
+
+    /// Add a vertical span, shading the range between two X values.
+    /// Always fills the full height of the plot.
+    pub fn vspan(&mut self, mut vspan: VSpan) {
+        if vspan.span.stroke.color == Color32::TRANSPARENT {
+            vspan.span.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(vspan));
+    }
+
     // grom
     pub fn hray(&mut self, mut hray: HRay) {
         if hray.stroke.color == Color32::TRANSPARENT {
@@ -244,6 +333,44 @@ impl PlotUi {
         self.items.push(Box::new(polygon));
     }
 
+    /// Add a volume profile, see [`VolumeProfile`].
+    pub fn volume_profile(&mut self, mut profile: VolumeProfile) {
+        if profile.levels.is_empty() {
+            return;
+        }
+
+        // Give the bars an automatic color if no color has been assigned.
+        if profile.color == Color32::TRANSPARENT {
+            profile.color = self.auto_color();
+        }
+        self.items.push(Box::new(profile));
+    }
+
+    /// Add Fibonacci retracement levels, see [`FibRetracement`].
+    pub fn fib_retracement(&mut self, mut fib: FibRetracement) {
+        // Give the levels an automatic color if no color has been assigned.
+        if fib.stroke.color == Color32::TRANSPARENT {
+            fib.stroke.color = self.auto_color();
+        }
+        self.items.push(Box::new(fib));
+    }
+
+    /// Add a trend line the user can edit, see [`TrendLine`].
+    pub fn trend_line(&mut self, mut trend_line: TrendLine) {
+        if trend_line.stroke.color == Color32::TRANSPARENT {
+            trend_line.stroke.color = self.auto_color();
+        }
+        self.trend_lines.push(trend_line);
+    }
+
+    /// Add a horizontal line the user can drag, see [`DraggableHLine`].
+    pub fn draggable_hline(&mut self, mut hline: DraggableHLine) {
+        if hline.stroke.color == Color32::TRANSPARENT {
+            hline.stroke.color = self.auto_color();
+        }
+        self.draggable_hlines.push(hline);
+    }
+
     /// Add a box plot diagram.
     pub fn box_plot(&mut self, mut box_plot: BoxPlot) {
         if box_plot.boxes.is_empty() {
@@ -269,4 +396,13 @@ impl PlotUi {
         }
         self.items.push(Box::new(chart));
     }
+
+    /// Add a candlestick (OHLC) price chart.
+    pub fn candlesticks(&mut self, candlestick: Candlestick) {
+        if candlestick.candles.is_empty() {
+            return;
+        }
+
+        self.items.push(Box::new(candlestick));
+    }
 }
@@ -0,0 +1,368 @@
+//! Export of painted [`Shape`]s as an SVG document.
+
+use std::fmt::Write as _;
+
+use egui::{
+    epaint::{ClippedShape, TextShape},
+    Color32, FontFamily, Pos2, Rect, Shape, Stroke, TextureId,
+};
+
+/// Write `shapes`, as painted by egui, into an SVG document showing the area `rect`.
+///
+/// This is what [`crate::Plot::export_svg`] uses, but it works with any shapes, e.g. the
+/// `shapes` of an [`egui::FullOutput`].
+///
+/// The document has no background: it is transparent wherever egui painted nothing, so choose
+/// visuals that also work on the background of the report. Textures (e.g. of a
+/// [`crate::PlotImage`]) and paint callbacks are left out. Text is written as text, using
+/// generic font families with the size and color from egui.
+///
+/// ```
+/// use egui::{epaint::ClippedShape, pos2, Color32, Rect, Shape, Stroke};
+///
+/// let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0));
+/// let line = Shape::line_segment([rect.left_center(), rect.right_center()], Stroke::new(1.0, Color32::RED));
+/// let svg = egui_plot::shapes_to_svg([&ClippedShape { clip_rect: rect, shape: line }], rect);
+/// assert!(svg.contains("<line"));
+/// ```
+pub fn shapes_to_svg<'a>(shapes: impl IntoIterator<Item = &'a ClippedShape>, rect: Rect) -> String {
+    let mut svg = String::new();
+    let mut clip_rects: Vec<Rect> = Vec::new();
+    let mut body = String::new();
+    let mut current_clip = None;
+
+    for ClippedShape { clip_rect, shape } in shapes {
+        let clip = match clip_rects.iter().position(|r| r == clip_rect) {
+            Some(clip) => clip,
+            None => {
+                clip_rects.push(*clip_rect);
+                clip_rects.len() - 1
+            }
+        };
+        if current_clip != Some(clip) {
+            if current_clip.is_some() {
+                body.push_str("</g>\n");
+            }
+            let _ = writeln!(body, r#"<g clip-path="url(#clip{clip})">"#);
+            current_clip = Some(clip);
+        }
+        write_shape(&mut body, shape);
+    }
+    if current_clip.is_some() {
+        body.push_str("</g>\n");
+    }
+
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+        num(rect.width()),
+        num(rect.height()),
+        num(rect.left()),
+        num(rect.top()),
+        num(rect.width()),
+        num(rect.height()),
+    );
+    if !clip_rects.is_empty() {
+        svg.push_str("<defs>\n");
+        for (i, clip_rect) in clip_rects.iter().enumerate() {
+            // Clip rects may be infinite, which SVG can't express.
+            let clip_rect = clip_rect.intersect(rect);
+            let _ = writeln!(
+                svg,
+                r#"<clipPath id="clip{i}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+                num(clip_rect.left()),
+                num(clip_rect.top()),
+                num(clip_rect.width().max(0.0)),
+                num(clip_rect.height().max(0.0)),
+            );
+        }
+        svg.push_str("</defs>\n");
+    }
+    svg.push_str(&body);
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn write_shape(svg: &mut String, shape: &Shape) {
+    match shape {
+        Shape::Noop | Shape::Callback(_) => {}
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                write_shape(svg, shape);
+            }
+        }
+        Shape::Circle(circle) => {
+            let _ = writeln!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}"{}/>"#,
+                num(circle.center.x),
+                num(circle.center.y),
+                num(circle.radius),
+                paint(circle.fill, circle.stroke),
+            );
+        }
+        Shape::Ellipse(ellipse) => {
+            let _ = writeln!(
+                svg,
+                r#"<ellipse cx="{}" cy="{}" rx="{}" ry="{}"{}/>"#,
+                num(ellipse.center.x),
+                num(ellipse.center.y),
+                num(ellipse.radius.x),
+                num(ellipse.radius.y),
+                paint(ellipse.fill, ellipse.stroke),
+            );
+        }
+        Shape::LineSegment { points, stroke } => {
+            let _ = writeln!(
+                svg,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}"{}/>"#,
+                num(points[0].x),
+                num(points[0].y),
+                num(points[1].x),
+                num(points[1].y),
+                paint(Color32::TRANSPARENT, *stroke),
+            );
+        }
+        Shape::Path(path) => {
+            // egui only fills closed paths.
+            let (element, fill) = if path.closed {
+                ("polygon", path.fill)
+            } else {
+                ("polyline", Color32::TRANSPARENT)
+            };
+            let _ = writeln!(
+                svg,
+                r#"<{element} points="{}"{}/>"#,
+                points(&path.points),
+                paint(fill, path.stroke),
+            );
+        }
+        Shape::Rect(rect) => {
+            let fill = if rect.fill_texture_id == TextureId::default() {
+                rect.fill
+            } else {
+                Color32::TRANSPARENT
+            };
+            let rounding = rect.rounding;
+            let radius = rounding
+                .nw
+                .max(rounding.ne)
+                .max(rounding.sw)
+                .max(rounding.se);
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{}"{}/>"#,
+                num(rect.rect.left()),
+                num(rect.rect.top()),
+                num(rect.rect.width()),
+                num(rect.rect.height()),
+                num(radius),
+                paint(fill, rect.stroke),
+            );
+        }
+        Shape::Text(text) => write_text(svg, text),
+        Shape::Mesh(mesh) => {
+            // Untextured meshes are plain colored triangles.
+            if mesh.texture_id != TextureId::default() {
+                return;
+            }
+            for triangle in mesh.indices.chunks_exact(3) {
+                let vertices: Vec<_> = triangle
+                    .iter()
+                    .map(|&i| mesh.vertices[i as usize])
+                    .collect();
+                let positions: Vec<_> = vertices.iter().map(|v| v.pos).collect();
+                let _ = writeln!(
+                    svg,
+                    r#"<polygon points="{}"{}/>"#,
+                    points(&positions),
+                    paint(vertices[0].color, Stroke::NONE),
+                );
+            }
+        }
+        Shape::QuadraticBezier(bezier) => {
+            let [p0, p1, p2] = bezier.points;
+            let _ = writeln!(
+                svg,
+                r#"<path d="M {} {} Q {} {} {} {}{}"{}/>"#,
+                num(p0.x),
+                num(p0.y),
+                num(p1.x),
+                num(p1.y),
+                num(p2.x),
+                num(p2.y),
+                if bezier.closed { " Z" } else { "" },
+                paint(bezier.fill, bezier.stroke),
+            );
+        }
+        Shape::CubicBezier(bezier) => {
+            let [p0, p1, p2, p3] = bezier.points;
+            let _ = writeln!(
+                svg,
+                r#"<path d="M {} {} C {} {} {} {} {} {}{}"{}/>"#,
+                num(p0.x),
+                num(p0.y),
+                num(p1.x),
+                num(p1.y),
+                num(p2.x),
+                num(p2.y),
+                num(p3.x),
+                num(p3.y),
+                if bezier.closed { " Z" } else { "" },
+                paint(bezier.fill, bezier.stroke),
+            );
+        }
+    }
+}
+
+/// Write the text of a galley, one `<text>` per run of glyphs with the same format.
+fn write_text(svg: &mut String, text: &TextShape) {
+    let galley = &text.galley;
+    if text.angle != 0.0 {
+        let _ = writeln!(
+            svg,
+            r#"<g transform="rotate({} {} {})">"#,
+            num(text.angle.to_degrees()),
+            num(text.pos.x),
+            num(text.pos.y),
+        );
+    }
+
+    for row in &galley.rows {
+        let mut glyphs = row.glyphs.iter().peekable();
+        while let Some(first) = glyphs.next() {
+            let mut run = String::from(first.chr);
+            let mut max_x = first.max_x();
+            while let Some(glyph) = glyphs.next_if(|g| g.section_index == first.section_index) {
+                run.push(glyph.chr);
+                max_x = glyph.max_x();
+            }
+
+            let format = &galley.job.sections[first.section_index as usize].format;
+            let origin = text.pos + first.pos.to_vec2();
+
+            if format.background != Color32::TRANSPARENT {
+                let _ = writeln!(
+                    svg,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}"{}/>"#,
+                    num(origin.x),
+                    num(text.pos.y + row.rect.top()),
+                    num(max_x - first.pos.x),
+                    num(row.rect.height()),
+                    paint(format.background, Stroke::NONE),
+                );
+            }
+
+            let mut color = text.override_text_color.unwrap_or(format.color);
+            if color == Color32::PLACEHOLDER {
+                color = text.fallback_color;
+            }
+            if text.opacity_factor < 1.0 {
+                color = color.gamma_multiply(text.opacity_factor);
+            }
+            let family = match format.font_id.family {
+                FontFamily::Monospace => "monospace",
+                FontFamily::Proportional | FontFamily::Name(_) => "sans-serif",
+            };
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" font-family="{family}" font-size="{}" xml:space="preserve"{}>{}</text>"#,
+                num(origin.x),
+                num(origin.y),
+                num(format.font_id.size),
+                paint(color, Stroke::NONE),
+                escape(run.trim_end_matches('\n')),
+            );
+        }
+    }
+
+    if text.angle != 0.0 {
+        svg.push_str("</g>\n");
+    }
+}
+
+/// The `fill` and `stroke` attributes.
+fn paint(fill: Color32, stroke: Stroke) -> String {
+    let mut attributes = String::new();
+    if fill == Color32::TRANSPARENT {
+        attributes.push_str(r#" fill="none""#);
+    } else {
+        let (color, opacity) = color(fill);
+        let _ = write!(attributes, r#" fill="{color}""#);
+        if opacity < 1.0 {
+            let _ = write!(attributes, r#" fill-opacity="{}""#, num(opacity));
+        }
+    }
+    if !stroke.is_empty() {
+        let (color, opacity) = color(stroke.color);
+        let _ = write!(
+            attributes,
+            r#" stroke="{color}" stroke-width="{}""#,
+            num(stroke.width)
+        );
+        if opacity < 1.0 {
+            let _ = write!(attributes, r#" stroke-opacity="{}""#, num(opacity));
+        }
+    }
+    attributes
+}
+
+/// Hex color and opacity of a premultiplied egui color.
+fn color(color: Color32) -> (String, f32) {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    (format!("#{r:02x}{g:02x}{b:02x}"), a as f32 / 255.0)
+}
+
+fn points(points: &[Pos2]) -> String {
+    let mut list = String::new();
+    for (i, p) in points.iter().enumerate() {
+        if i > 0 {
+            list.push(' ');
+        }
+        let _ = write!(list, "{},{}", num(p.x), num(p.y));
+    }
+    list
+}
+
+/// A number with at most two decimals, which is plenty for ui points.
+fn num(value: f32) -> String {
+    let mut text = format!("{value:.2}");
+    if text.contains('.') {
+        text.truncate(text.trim_end_matches('0').trim_end_matches('.').len());
+    }
+    if text == "-0" {
+        text.remove(0);
+    }
+    text
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_markup() {
+        assert_eq!(escape("plain"), "plain");
+        assert_eq!(
+            escape(r#"<b>"a" & 'b'</b>"#),
+            "&lt;b&gt;&quot;a&quot; &amp; 'b'&lt;/b&gt;"
+        );
+        // Already escaped text is escaped again.
+        assert_eq!(escape("&amp;"), "&amp;amp;");
+    }
+
+    #[test]
+    fn num_trims_decimals() {
+        assert_eq!(num(12.0), "12");
+        assert_eq!(num(12.5), "12.5");
+        assert_eq!(num(0.123), "0.12");
+        assert_eq!(num(-0.001), "0");
+    }
+}
@@ -0,0 +1,573 @@
+//! Grid spacer and tick formatter for axes that show time as Unix seconds.
+
+use std::{ops::RangeInclusive, sync::Arc};
+
+use crate::{axis::AxisFormatterFn, log_grid_spacer, GridInput, GridMark, GridSpacer};
+
+const MINUTE: i64 = 60;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+const WEEK: i64 = 7 * DAY;
+
+/// Days from 1970-01-01 (a Thursday) to the first Monday, where weeks start.
+const FIRST_MONDAY: i64 = 4 * DAY;
+
+/// Approximate lengths, only used to compare and fade steps.
+const MONTH_SECONDS: f64 = 30.0 * DAY as f64;
+const YEAR_SECONDS: f64 = 365.0 * DAY as f64;
+
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A step between two grid lines on a time axis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TimeStep {
+    /// A fixed number of seconds.
+    Seconds(i64),
+
+    /// A number of calendar months. Years are multiples of 12.
+    Months(i64),
+}
+
+impl TimeStep {
+    const ALL: [Self; 32] = [
+        Self::Seconds(1),
+        Self::Seconds(2),
+        Self::Seconds(5),
+        Self::Seconds(10),
+        Self::Seconds(15),
+        Self::Seconds(30),
+        Self::Seconds(MINUTE),
+        Self::Seconds(2 * MINUTE),
+        Self::Seconds(5 * MINUTE),
+        Self::Seconds(10 * MINUTE),
+        Self::Seconds(15 * MINUTE),
+        Self::Seconds(30 * MINUTE),
+        Self::Seconds(HOUR),
+        Self::Seconds(2 * HOUR),
+        Self::Seconds(3 * HOUR),
+        Self::Seconds(6 * HOUR),
+        Self::Seconds(12 * HOUR),
+        Self::Seconds(DAY),
+        Self::Seconds(2 * DAY),
+        Self::Seconds(WEEK),
+        Self::Months(1),
+        Self::Months(3),
+        Self::Months(6),
+        Self::Months(12),
+        Self::Months(2 * 12),
+        Self::Months(5 * 12),
+        Self::Months(10 * 12),
+        Self::Months(20 * 12),
+        Self::Months(50 * 12),
+        Self::Months(100 * 12),
+        Self::Months(500 * 12),
+        Self::Months(1000 * 12),
+    ];
+
+    fn approx_seconds(self) -> f64 {
+        match self {
+            Self::Seconds(seconds) => seconds as f64,
+            Self::Months(months) if months % 12 == 0 => (months / 12) as f64 * YEAR_SECONDS,
+            Self::Months(months) => months as f64 * MONTH_SECONDS,
+        }
+    }
+
+    /// All marks of this step within `(min, max)`, with the given UTC offset in seconds.
+    fn fill_marks(self, out: &mut Vec<GridMark>, (min, max): (f64, f64), utc_offset: i64) {
+        let step_size = self.approx_seconds();
+        match self {
+            Self::Seconds(step) => {
+                // Align to multiples of the step in local time, so days start at local midnight
+                // and weeks on Monday.
+                let origin = if step % WEEK == 0 { FIRST_MONDAY } else { 0 } - utc_offset;
+                let first = ((min - origin as f64) / step as f64).ceil() as i64;
+                let last = ((max - origin as f64) / step as f64).floor() as i64;
+                out.extend((first..=last).map(|i| GridMark {
+                    value: (i * step + origin) as f64,
+                    step_size,
+                }));
+            }
+            Self::Months(step) => {
+                let (year, month, _) = civil_from_days((min as i64 + utc_offset).div_euclid(DAY));
+                let mut index = (year * 12 + month as i64 - 1).div_euclid(step) * step;
+                loop {
+                    let days =
+                        days_from_civil(index.div_euclid(12), index.rem_euclid(12) as u32 + 1, 1);
+                    let value = (days * DAY - utc_offset) as f64;
+                    if value > max {
+                        break;
+                    }
+                    if value >= min {
+                        out.push(GridMark { value, step_size });
+                    }
+                    index += step;
+                }
+            }
+        }
+    }
+}
+
+/// Grid spacer for an axis whose values are Unix timestamps in seconds.
+///
+/// Grid lines are placed at calendar steps: seconds, minutes, hours, days (at local midnight),
+/// weeks (on Monday), month starts and years. `utc_offset` is the offset of the displayed time zone from UTC, in
+/// seconds (e.g. `3 * 3600` for UTC+3).
+///
+/// Below one second the grid falls back to decimal steps.
+///
+/// Use together with [`time_formatter`]:
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{time_formatter, time_grid_spacer, Line, Plot, PlotPoints};
+///
+/// let prices: PlotPoints = (0..100)
+///     .map(|i| [1_700_000_000.0 + i as f64 * 3600.0, (i as f64 * 0.1).sin()])
+///     .collect();
+/// Plot::new("prices")
+///     .x_grid_spacer(time_grid_spacer(0))
+///     .x_axis_formatter(time_formatter(0))
+///     .show(ui, |plot_ui| plot_ui.line(Line::new(prices)));
+/// # });
+/// ```
+pub fn time_grid_spacer(utc_offset: i64) -> GridSpacer {
+    let decimal_spacer = log_grid_spacer(10);
+    let step_sizes = move |input: GridInput| -> Vec<GridMark> {
+        // handle degenerate cases
+        if input.base_step_size.abs() < f64::EPSILON {
+            return Vec::new();
+        }
+        if input.base_step_size < 1.0 {
+            return decimal_spacer(input);
+        }
+
+        let Some(first) = TimeStep::ALL
+            .iter()
+            .position(|step| step.approx_seconds() >= input.base_step_size)
+        else {
+            return decimal_spacer(input);
+        };
+
+        let mut marks = Vec::new();
+        for step in TimeStep::ALL.iter().skip(first).take(3) {
+            step.fill_marks(&mut marks, input.bounds, utc_offset);
+        }
+        marks
+    };
+
+    Box::new(step_sizes)
+}
+
+/// Tick formatter for an axis whose values are Unix timestamps in seconds.
+///
+/// Each label shows only as much as the tick's value needs, see [`format_time`]: ticks at the
+/// start of a year show the year, at the start of a month the month, at midnight the date, and
+/// all other ticks the time of day. Since [`time_grid_spacer`] puts day and longer steps at local
+/// midnight, these are the ticks where the date changes. `utc_offset` is the offset of the
+/// displayed time zone from UTC, in seconds.
+///
+/// See [`time_grid_spacer`] for an example.
+pub fn time_formatter(utc_offset: i64) -> impl Fn(GridMark, usize, &RangeInclusive<f64>) -> String {
+    move |mark, _max_chars, _range| format_time(mark.value, utc_offset)
+}
+
+/// Format a Unix timestamp in seconds with the precision implied by its value, see [`time_formatter`].
+///
+/// ```
+/// # use egui_plot::format_time;
+/// assert_eq!(format_time(1_704_067_200.0, 0), "2024"); // 2024-01-01 00:00
+/// assert_eq!(format_time(1_709_251_200.0, 0), "Mar"); // 2024-03-01 00:00
+/// assert_eq!(format_time(1_709_164_800.0, 0), "29 Feb"); // 2024-02-29 00:00
+/// assert_eq!(format_time(1_709_164_800.0 + 9.5 * 3600.0, 0), "09:30");
+/// ```
+pub fn format_time(timestamp: f64, utc_offset: i64) -> String {
+    // Round to whole milliseconds first, so e.g. 59.9996 s rolls over to the next minute.
+    let millis = ((timestamp + utc_offset as f64) * 1000.0).round() as i64;
+    let seconds = millis.div_euclid(1000);
+    let millis = millis.rem_euclid(1000);
+    let days = seconds.div_euclid(DAY);
+    let time_of_day = seconds.rem_euclid(DAY);
+    let (year, month, day) = civil_from_days(days);
+
+    let hour = time_of_day / HOUR;
+    let minute = time_of_day % HOUR / MINUTE;
+    let second = time_of_day % MINUTE;
+
+    if millis != 0 {
+        format!("{hour:02}:{minute:02}:{second:02}.{millis:03}")
+    } else if second != 0 {
+        format!("{hour:02}:{minute:02}:{second:02}")
+    } else if time_of_day != 0 {
+        format!("{hour:02}:{minute:02}")
+    } else if day != 1 {
+        format!("{day} {}", MONTH_NAMES[month as usize - 1])
+    } else if month != 1 {
+        MONTH_NAMES[month as usize - 1].to_owned()
+    } else {
+        year.to_string()
+    }
+}
+
+/// Format a Unix timestamp in seconds as `YYYY-MM-DD HH:MM:SS`.
+pub fn format_date_time(timestamp: f64, utc_offset: i64) -> String {
+    let seconds = (timestamp + utc_offset as f64).floor() as i64;
+    let (year, month, day) = civil_from_days(seconds.div_euclid(DAY));
+    let time_of_day = seconds.rem_euclid(DAY);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02}",
+        time_of_day / HOUR,
+        time_of_day % HOUR / MINUTE,
+        time_of_day % MINUTE
+    )
+}
+
+/// Format a duration in seconds with its two largest units, e.g. `2d 3h`, `3h 20m` or `45s`.
+pub fn format_duration(seconds: f64) -> String {
+    let sign = if seconds < 0.0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    if seconds < MINUTE as f64 {
+        let text = format!("{seconds:.3}");
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        return format!("{sign}{text}s");
+    }
+
+    let seconds = seconds.round() as i64;
+    let units = [(DAY, "d"), (HOUR, "h"), (MINUTE, "m"), (1, "s")];
+    let Some(first) = units.iter().position(|&(unit, _)| seconds >= unit) else {
+        return "0s".to_owned();
+    };
+    let (unit, name) = units[first];
+    let (small_unit, small_name) = units[first + 1];
+    let small = seconds % unit / small_unit;
+    if small == 0 {
+        format!("{sign}{}{name}", seconds / unit)
+    } else {
+        format!("{sign}{}{name} {small}{small_name}", seconds / unit)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Maps bar indices to Unix timestamps for a gapless time axis, see [`crate::Plot::x_time_index`].
+///
+/// Items are positioned by bar index (the X value of the `n`-th bar is `n`), so nights, weekends
+/// and holidays without data take no space. The axis labels, grid and hover label still show
+/// time: fractional indices are interpolated between neighbouring bars, and indices outside the
+/// table are extrapolated with the typical bar duration.
+#[derive(Clone, Debug)]
+pub struct TimeIndex {
+    timestamps: Arc<[f64]>,
+
+    /// Median distance between two bars, in seconds.
+    bar_duration: f64,
+    utc_offset: i64,
+}
+
+impl TimeIndex {
+    /// Create a mapping from the timestamps (Unix seconds, ascending) of every bar.
+    pub fn new(timestamps: impl Into<Arc<[f64]>>) -> Self {
+        let timestamps = timestamps.into();
+        let mut durations: Vec<f64> = timestamps
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .filter(|duration| *duration > 0.0)
+            .collect();
+        durations.sort_by(f64::total_cmp);
+        let bar_duration = durations.get(durations.len() / 2).copied().unwrap_or(1.0);
+        Self {
+            timestamps,
+            bar_duration,
+            utc_offset: 0,
+        }
+    }
+
+    /// Offset of the displayed time zone from UTC in seconds, used by the default hover label.
+    ///
+    /// Default: `0`.
+    #[inline]
+    pub fn utc_offset(mut self, utc_offset: i64) -> Self {
+        self.utc_offset = utc_offset;
+        self
+    }
+
+    /// The timestamps of all bars.
+    #[inline]
+    pub fn timestamps(&self) -> &[f64] {
+        &self.timestamps
+    }
+
+    /// The typical (median) distance between two bars, in seconds.
+    #[inline]
+    pub fn bar_duration(&self) -> f64 {
+        self.bar_duration
+    }
+
+    /// Unix timestamp at a (possibly fractional) bar index.
+    pub fn time_from_index(&self, index: f64) -> f64 {
+        let timestamps = &self.timestamps;
+        let Some((&first, &last)) = timestamps.first().zip(timestamps.last()) else {
+            return index * self.bar_duration;
+        };
+        let last_index = (timestamps.len() - 1) as f64;
+        if index <= 0.0 {
+            first + index * self.bar_duration
+        } else if index >= last_index {
+            last + (index - last_index) * self.bar_duration
+        } else {
+            let i = index.floor() as usize;
+            let t = index - i as f64;
+            timestamps[i] + t * (timestamps[i + 1] - timestamps[i])
+        }
+    }
+
+    /// (Possibly fractional) bar index at a Unix timestamp. Inverse of [`Self::time_from_index`].
+    pub fn index_from_time(&self, time: f64) -> f64 {
+        let timestamps = &self.timestamps;
+        let Some((&first, &last)) = timestamps.first().zip(timestamps.last()) else {
+            return time / self.bar_duration;
+        };
+        let after = timestamps.partition_point(|&t| t <= time);
+        if after == 0 {
+            (time - first) / self.bar_duration
+        } else if after == timestamps.len() {
+            (timestamps.len() - 1) as f64 + (time - last) / self.bar_duration
+        } else {
+            let i = after - 1;
+            i as f64 + (time - timestamps[i]) / (timestamps[after] - timestamps[i])
+        }
+    }
+
+    /// Hover label text of the X value.
+    pub(crate) fn format_index(&self, index: f64) -> String {
+        format_date_time(self.time_from_index(index), self.utc_offset)
+    }
+
+    /// Run `spacer` on timestamps and map the resulting marks back to bar indices.
+    ///
+    /// Marks that fall into the same gap between two bars are merged, keeping the strongest one.
+    pub(crate) fn wrap_grid_spacer(&self, spacer: GridSpacer) -> GridSpacer {
+        let index = self.clone();
+        Box::new(move |input: GridInput| -> Vec<GridMark> {
+            let time_input = GridInput {
+                bounds: (
+                    index.time_from_index(input.bounds.0),
+                    index.time_from_index(input.bounds.1),
+                ),
+                base_step_size: input.base_step_size * index.bar_duration,
+            };
+            let mut marks: Vec<GridMark> = spacer(time_input)
+                .into_iter()
+                .map(|mark| GridMark {
+                    value: index.index_from_time(mark.value),
+                    step_size: mark.step_size / index.bar_duration,
+                })
+                .collect();
+
+            // Keep the latest of the strongest marks before each bar.
+            marks.sort_by(|a, b| {
+                a.value
+                    .ceil()
+                    .total_cmp(&b.value.ceil())
+                    .then(b.step_size.total_cmp(&a.step_size))
+                    .then(b.value.total_cmp(&a.value))
+            });
+            marks.dedup_by(|later, earlier| later.value.ceil() == earlier.value.ceil());
+            marks
+        })
+    }
+
+    /// Make an axis formatter receive timestamps instead of bar indices.
+    #[allow(clippy::arc_with_non_send_sync)] // `AxisFormatterFn` is not `Send` either
+    pub(crate) fn wrap_axis_formatter(&self, fmt: Arc<AxisFormatterFn>) -> Arc<AxisFormatterFn> {
+        let index = self.clone();
+        Arc::new(move |mark, max_digits, range| {
+            let mark = GridMark {
+                value: index.time_from_index(mark.value),
+                step_size: mark.step_size * index.bar_duration,
+            };
+            let range = index.time_from_index(*range.start())..=index.time_from_index(*range.end());
+            fmt(mark, max_digits, &range)
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Days since 1970-01-01 of the given proleptic Gregorian date.
+///
+/// See <https://howardhinnant.github.io/date_algorithms.html>.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Proleptic Gregorian `(year, month, day)` of the given number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn civil_days_round_trip() {
+        for (date, days) in [
+            ((1970, 1, 1), 0),
+            ((1969, 12, 31), -1),
+            ((2000, 2, 29), 11_016),
+            ((2000, 3, 1), 11_017),
+            ((1900, 3, 1), -25_508),
+            ((2024, 2, 29), 19_782),
+            ((1600, 1, 1), -135_140),
+        ] {
+            assert_eq!(days_from_civil(date.0, date.1, date.2), days, "{date:?}");
+            assert_eq!(civil_from_days(days), date, "{days}");
+        }
+        for days in -800_000..800_000 {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn leap_years() {
+        // 2000 is a leap year, 1900 and 2100 are not.
+        for (year, next) in [
+            (2000, (2, 29)),
+            (1900, (3, 1)),
+            (2100, (3, 1)),
+            (2024, (2, 29)),
+        ] {
+            let day_after = civil_from_days(days_from_civil(year, 2, 28) + 1);
+            assert_eq!(day_after, (year, next.0, next.1));
+        }
+    }
+
+    #[test]
+    fn format_time_negative_timestamps() {
+        assert_eq!(format_time(-1.0, 0), "23:59:59");
+        assert_eq!(format_time(-DAY as f64, 0), "31 Dec");
+        assert_eq!(format_time(-0.5, 0), "23:59:59.500");
+        assert_eq!(format_date_time(-1.0, 0), "1969-12-31 23:59:59");
+        // Local midnight of 1970-01-01 in UTC+3.
+        assert_eq!(format_time(-3.0 * HOUR as f64, 3 * HOUR), "1970");
+    }
+
+    #[test]
+    fn format_time_leap_day() {
+        let leap_day = (days_from_civil(2024, 2, 29) * DAY) as f64;
+        assert_eq!(format_time(leap_day, 0), "29 Feb");
+        assert_eq!(format_time(leap_day + DAY as f64, 0), "Mar");
+        assert_eq!(format_date_time(leap_day + 90.0, 0), "2024-02-29 00:01:30");
+    }
+
+    #[test]
+    fn format_time_rounds_milliseconds_over() {
+        assert_eq!(format_time(59.9996, 0), "00:01");
+        assert_eq!(format_time(59.9994, 0), "00:00:59.999");
+        assert_eq!(format_time(86_399.999_6, 0), "2 Jan");
+        assert_eq!(format_time(0.25, 0), "00:00:00.250");
+        assert_eq!(format_time(-0.25, 0), "23:59:59.750");
+    }
+
+    #[test]
+    fn format_duration_units() {
+        assert_eq!(format_duration(0.0), "0s");
+        assert_eq!(format_duration(1.25), "1.25s");
+        assert_eq!(format_duration(45.0), "45s");
+        assert_eq!(format_duration(90.0), "1m 30s");
+        assert_eq!(format_duration(3.0 * 3600.0 + 20.0 * 60.0), "3h 20m");
+        assert_eq!(format_duration(2.0 * 86_400.0 + 3.0 * 3600.0), "2d 3h");
+        assert_eq!(format_duration(86_400.0), "1d");
+        assert_eq!(format_duration(-90.0), "-1m 30s");
+        assert_eq!(format_duration(-0.5), "-0.5s");
+    }
+
+    #[test]
+    fn weeks_start_on_monday() {
+        let mut marks = Vec::new();
+        let start = (days_from_civil(2024, 1, 1) * DAY) as f64; // a Monday
+        TimeStep::Seconds(WEEK).fill_marks(&mut marks, (start - 1.0, start + 20.0 * 86_400.0), 0);
+        let dates: Vec<_> = marks
+            .iter()
+            .map(|mark| civil_from_days((mark.value as i64).div_euclid(DAY)))
+            .collect();
+        assert_eq!(dates, [(2024, 1, 1), (2024, 1, 8), (2024, 1, 15)]);
+    }
+
+    /// Daily bars from Thursday 2024-01-04 to Wednesday 2024-01-10, without the weekend.
+    fn weekdays() -> TimeIndex {
+        let thursday = days_from_civil(2024, 1, 4);
+        let days = [0, 1, 4, 5, 6];
+        TimeIndex::new(days.map(|day| ((thursday + day) * DAY) as f64).to_vec())
+    }
+
+    #[test]
+    fn time_index_round_trip() {
+        let index = weekdays();
+        assert_eq!(index.bar_duration(), DAY as f64);
+        for (i, &time) in index.timestamps().iter().enumerate() {
+            assert_eq!(index.time_from_index(i as f64), time);
+            assert_eq!(index.index_from_time(time), i as f64);
+        }
+        for i in -30..90 {
+            let i = i as f64 / 10.0;
+            assert!(
+                (index.index_from_time(index.time_from_index(i)) - i).abs() < 1e-9,
+                "{i}"
+            );
+        }
+    }
+
+    #[test]
+    fn time_index_spans_gaps() {
+        let index = weekdays();
+        let friday = (days_from_civil(2024, 1, 5) * DAY) as f64;
+        let monday = (days_from_civil(2024, 1, 8) * DAY) as f64;
+        // The weekend takes one bar, so its middle is half way between Friday and Monday.
+        assert_eq!(index.time_from_index(1.5), (friday + monday) / 2.0);
+        assert_eq!(index.index_from_time(friday + 1.5 * DAY as f64), 1.5);
+        assert_eq!(index.format_index(1.5), "2024-01-06 12:00:00");
+    }
+
+    #[test]
+    fn time_index_extrapolates_with_the_bar_duration() {
+        let index = weekdays();
+        let thursday = (days_from_civil(2024, 1, 4) * DAY) as f64;
+        let wednesday = (days_from_civil(2024, 1, 10) * DAY) as f64;
+        assert_eq!(index.time_from_index(-2.0), thursday - 2.0 * DAY as f64);
+        assert_eq!(index.time_from_index(6.5), wednesday + 2.5 * DAY as f64);
+        assert_eq!(index.index_from_time(thursday - 0.5 * DAY as f64), -0.5);
+        assert_eq!(index.index_from_time(wednesday + 3.0 * DAY as f64), 7.0);
+
+        // Without bars, an index is a number of seconds.
+        let empty = TimeIndex::new(Vec::new());
+        assert_eq!(empty.time_from_index(3.0), 3.0);
+        assert_eq!(empty.index_from_time(-2.0), -2.0);
+        let single = TimeIndex::new(vec![100.0]);
+        assert_eq!(single.time_from_index(2.0), 102.0);
+        assert_eq!(single.index_from_time(99.0), -1.0);
+    }
+}
@@ -220,6 +220,190 @@ impl PlotBounds {
         self.min[1] = -y_abs;
         self.max[1] = y_abs;
     }
+
+    /// Like [`Self::add_relative_margin_x`] and [`Self::add_relative_margin_y`] on the chosen
+    /// `axes`, but measured in the scaled space of `scales`.
+    pub(crate) fn add_relative_margin_scaled(
+        &mut self,
+        margin_fraction: Vec2,
+        axes: Vec2b,
+        scales: [AxisScale; 2],
+    ) {
+        let mut scaled = *self;
+        for (i, scale) in scales.iter().enumerate() {
+            if scaled.min[i].is_finite() && scaled.max[i].is_finite() {
+                scale.sanitize_range(&mut scaled.min[i], &mut scaled.max[i]);
+            }
+        }
+        let mut scaled = scaled.scaled(scales);
+        if axes.x {
+            scaled.add_relative_margin_x(margin_fraction);
+        }
+        if axes.y {
+            scaled.add_relative_margin_y(margin_fraction);
+        }
+        let expanded = scaled.unscaled(scales);
+        if axes.x {
+            self.set_x(&expanded);
+        }
+        if axes.y {
+            self.set_y(&expanded);
+        }
+    }
+
+    /// These bounds in the scaled space of `scales`.
+    pub(crate) fn scaled(&self, scales: [AxisScale; 2]) -> Self {
+        Self {
+            min: [scales[0].scale(self.min[0]), scales[1].scale(self.min[1])],
+            max: [scales[0].scale(self.max[0]), scales[1].scale(self.max[1])],
+        }
+    }
+
+    /// Inverse of [`Self::scaled`].
+    pub(crate) fn unscaled(&self, scales: [AxisScale; 2]) -> Self {
+        Self {
+            min: [
+                scales[0].unscale(self.min[0]),
+                scales[1].unscale(self.min[1]),
+            ],
+            max: [
+                scales[0].unscale(self.max[0]),
+                scales[1].unscale(self.max[1]),
+            ],
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How plot values along an axis are mapped to screen positions.
+///
+/// Items, bounds and hover labels always use plain plot values. Panning, zooming, auto-bounds
+/// margins and grid spacing happen in the scaled space, so e.g. every decade of a log axis
+/// takes the same room on screen. A grid spacer (see [`crate::Plot::x_grid_spacer`]) therefore
+/// receives scaled bounds and step sizes; the resulting marks are converted back to plot values
+/// before they are formatted. Unless a custom spacer is set, log axes use
+/// [`crate::log_scale_grid_spacer`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AxisScale {
+    /// Positions are proportional to values.
+    #[default]
+    Linear,
+
+    /// Positions are proportional to the logarithm of values in the given base.
+    ///
+    /// Only positive values can be shown; zero and negative values are pushed far out of view.
+    Log { base: f64 },
+
+    /// Symmetric logarithm: approximately linear within `±linear_threshold` and logarithmic
+    /// beyond, so zero and negative values can be shown as well.
+    SymLog { base: f64, linear_threshold: f64 },
+}
+
+impl AxisScale {
+    /// Logarithmic scale with base 10.
+    pub fn log10() -> Self {
+        Self::Log { base: 10.0 }
+    }
+
+    /// Logarithmic scale with the given base.
+    ///
+    /// # Panics
+    /// If `base` isn't a finite number greater than 1.
+    pub fn log(base: f64) -> Self {
+        assert!(Self::valid_base(base), "invalid log base: {base}");
+        Self::Log { base }
+    }
+
+    /// Symmetric logarithmic scale, see [`Self::SymLog`].
+    ///
+    /// # Panics
+    /// If `base` isn't a finite number greater than 1, or `linear_threshold` isn't a finite
+    /// positive number.
+    pub fn symlog(base: f64, linear_threshold: f64) -> Self {
+        assert!(Self::valid_base(base), "invalid symlog base: {base}");
+        assert!(
+            Self::valid_threshold(linear_threshold),
+            "invalid symlog linear threshold: {linear_threshold}"
+        );
+        Self::SymLog {
+            base,
+            linear_threshold,
+        }
+    }
+
+    fn valid_base(base: f64) -> bool {
+        base.is_finite() && base > 1.0
+    }
+
+    fn valid_threshold(linear_threshold: f64) -> bool {
+        linear_threshold.is_finite() && linear_threshold > 0.0
+    }
+
+    /// This scale with invalid parameters (of a variant built directly) replaced by `10` for the
+    /// base and `1` for the linear threshold, so the mapping stays finite.
+    pub(crate) fn sanitized(self) -> Self {
+        let base = |base: f64| if Self::valid_base(base) { base } else { 10.0 };
+        match self {
+            Self::Linear => self,
+            Self::Log { base: b } => Self::Log { base: base(b) },
+            Self::SymLog {
+                base: b,
+                linear_threshold,
+            } => Self::SymLog {
+                base: base(b),
+                linear_threshold: if Self::valid_threshold(linear_threshold) {
+                    linear_threshold
+                } else {
+                    1.0
+                },
+            },
+        }
+    }
+
+    #[inline]
+    pub fn is_linear(&self) -> bool {
+        matches!(self, Self::Linear)
+    }
+
+    /// Map a plot value into the scaled space.
+    pub fn scale(&self, value: f64) -> f64 {
+        match *self {
+            Self::Linear => value,
+            Self::Log { base } => value.max(f64::MIN_POSITIVE).log(base),
+            Self::SymLog {
+                base,
+                linear_threshold,
+            } => value.signum() * (value.abs() / linear_threshold).ln_1p() / base.ln(),
+        }
+    }
+
+    /// Map a value of the scaled space back to a plot value. Inverse of [`Self::scale`].
+    pub fn unscale(&self, scaled: f64) -> f64 {
+        match *self {
+            Self::Linear => scaled,
+            Self::Log { base } => base.powf(scaled),
+            Self::SymLog {
+                base,
+                linear_threshold,
+            } => scaled.signum() * linear_threshold * (scaled.abs() * base.ln()).exp_m1(),
+        }
+    }
+
+    /// Replace a `min..=max` range that can't be shown on this scale.
+    ///
+    /// On a log axis, a non-positive minimum becomes three orders of magnitude below the maximum.
+    fn sanitize_range(&self, min: &mut f64, max: &mut f64) {
+        if let Self::Log { base } = *self {
+            if *max <= 0.0 {
+                *min = 1.0;
+                *max = base;
+            } else if *min <= 0.0 {
+                *min = *max / base.powi(3);
+            }
+        }
+    }
 }
 
 /// Contains the screen rectangle and the plot bounds and provides methods to transform between them.
@@ -237,6 +421,10 @@ pub struct PlotTransform {
 
     /// Whether to always center the y-range of the bounds.
     y_centered: bool,
+
+    /// How the X and Y axes map values to positions.
+    #[cfg_attr(feature = "serde", serde(default))]
+    scales: [AxisScale; 2],
 }
 
 impl PlotTransform {
@@ -262,7 +450,33 @@ impl PlotTransform {
             bounds,
             x_centered,
             y_centered,
+            scales: [AxisScale::Linear; 2],
+        }
+    }
+
+    /// Set the scales of the X and Y axes.
+    ///
+    /// Bounds that can't be shown on the new scale (e.g. non-positive values on a log axis) are
+    /// replaced.
+    pub fn with_scales(mut self, scales: [AxisScale; 2]) -> Self {
+        let scales = scales.map(AxisScale::sanitized);
+        self.scales = scales;
+        for (i, scale) in scales.iter().enumerate() {
+            scale.sanitize_range(&mut self.bounds.min[i], &mut self.bounds.max[i]);
         }
+        self
+    }
+
+    /// The scale of the given axis.
+    #[inline]
+    pub fn scale(&self, axis: Axis) -> AxisScale {
+        self.scales[usize::from(axis)]
+    }
+
+    /// The scales of the X and Y axes.
+    #[inline]
+    pub fn scales(&self) -> [AxisScale; 2] {
+        self.scales
     }
 
     /// ui-space rectangle.
@@ -291,33 +505,44 @@ impl PlotTransform {
         }
         delta_pos.x *= self.dvalue_dpos()[0] as f32;
         delta_pos.y *= self.dvalue_dpos()[1] as f32;
-        self.bounds.translate(delta_pos);
+        let mut scaled = self.scaled_bounds();
+        scaled.translate(delta_pos);
+        self.bounds = scaled.unscaled(self.scales);
     }
 
     /// Zoom by a relative factor with the given screen position as center.
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
-        let center = self.value_from_position(center);
+        let center = self.scaled_from_position(center);
 
-        let mut new_bounds = self.bounds;
-        new_bounds.zoom(zoom_factor, center);
+        let mut new_scaled = self.scaled_bounds();
+        new_scaled.zoom(zoom_factor, center);
+        let new_bounds = new_scaled.unscaled(self.scales);
 
-        if new_bounds.is_valid() {
+        if new_scaled.is_valid() && new_bounds.is_valid() {
             self.bounds = new_bounds;
         }
     }
 
+    /// The plot bounds in the scaled space of the axes, see [`AxisScale`].
+    #[inline]
+    fn scaled_bounds(&self) -> PlotBounds {
+        self.bounds.scaled(self.scales)
+    }
+
     pub fn position_from_point_x(&self, value: f64) -> f32 {
+        let bounds = self.scaled_bounds();
         remap(
-            value,
-            self.bounds.min[0]..=self.bounds.max[0],
+            self.scales[0].scale(value),
+            bounds.min[0]..=bounds.max[0],
             (self.frame.left() as f64)..=(self.frame.right() as f64),
         ) as f32
     }
 
     pub fn position_from_point_y(&self, value: f64) -> f32 {
+        let bounds = self.scaled_bounds();
         remap(
-            value,
-            self.bounds.min[1]..=self.bounds.max[1],
+            self.scales[1].scale(value),
+            bounds.min[1]..=bounds.max[1],
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
         ) as f32
     }
@@ -332,15 +557,25 @@ impl PlotTransform {
 
     /// Plot point from screen/ui position.
     pub fn value_from_position(&self, pos: Pos2) -> PlotPoint {
+        let scaled = self.scaled_from_position(pos);
+        PlotPoint::new(
+            self.scales[0].unscale(scaled.x),
+            self.scales[1].unscale(scaled.y),
+        )
+    }
+
+    /// Point in the scaled space of the axes from screen/ui position.
+    fn scaled_from_position(&self, pos: Pos2) -> PlotPoint {
+        let bounds = self.scaled_bounds();
         let x = remap(
             pos.x as f64,
             (self.frame.left() as f64)..=(self.frame.right() as f64),
-            self.bounds.min[0]..=self.bounds.max[0],
+            bounds.min[0]..=bounds.max[0],
         );
         let y = remap(
             pos.y as f64,
             (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
-            self.bounds.min[1]..=self.bounds.max[1],
+            bounds.min[1]..=bounds.max[1],
         );
         PlotPoint::new(x, y)
     }
@@ -360,13 +595,17 @@ impl PlotTransform {
     }
 
     /// delta position / delta value = how many ui points per step in the X axis in "plot space"
+    ///
+    /// On a non-linear axis the step is measured in the scaled space, see [`AxisScale`].
     pub fn dpos_dvalue_x(&self) -> f64 {
-        self.frame.width() as f64 / self.bounds.width()
+        self.frame.width() as f64 / self.scaled_bounds().width()
     }
 
     /// delta position / delta value = how many ui points per step in the Y axis in "plot space"
+    ///
+    /// On a non-linear axis the step is measured in the scaled space, see [`AxisScale`].
     pub fn dpos_dvalue_y(&self) -> f64 {
-        -self.frame.height() as f64 / self.bounds.height() // negated y axis!
+        -self.frame.height() as f64 / self.scaled_bounds().height() // negated y axis!
     }
 
     /// delta position / delta value = how many ui points per step in "plot space"
@@ -379,13 +618,57 @@ impl PlotTransform {
         [1.0 / self.dpos_dvalue_x(), 1.0 / self.dpos_dvalue_y()]
     }
 
+    /// Like [`Self::dvalue_dpos`], but in plot values around `value`.
+    ///
+    /// Differs from [`Self::dvalue_dpos`] only on non-linear axes.
+    pub fn dvalue_dpos_at(&self, value: &PlotPoint) -> [f64; 2] {
+        let dvalue_dpos = self.dvalue_dpos();
+        let value = [value.x, value.y];
+        std::array::from_fn(|i| {
+            let scale = self.scales[i];
+            if scale.is_linear() {
+                dvalue_dpos[i]
+            } else {
+                scale.unscale(scale.scale(value[i]) + dvalue_dpos[i]) - value[i]
+            }
+        })
+    }
+
+    /// Ask `spacer` for the grid marks of `axis` with at least `min_spacing` ui points between
+    /// the thinnest lines.
+    ///
+    /// The spacer works in the scaled space; the values of the returned marks are plot values,
+    /// their step sizes stay in the scaled space to match [`Self::dpos_dvalue`].
+    pub(crate) fn grid_marks(
+        &self,
+        axis: Axis,
+        spacer: &GridSpacer,
+        min_spacing: f32,
+    ) -> Vec<GridMark> {
+        let iaxis = usize::from(axis);
+        let scale = self.scales[iaxis];
+        let bounds = self.scaled_bounds();
+        let input = GridInput {
+            bounds: (bounds.min[iaxis], bounds.max[iaxis]),
+            base_step_size: self.dvalue_dpos()[iaxis].abs() * min_spacing as f64,
+        };
+        let mut marks = spacer(input);
+        if !scale.is_linear() {
+            for mark in &mut marks {
+                mark.value = scale.unscale(mark.value);
+            }
+        }
+        marks
+    }
+
     /// scale.x/scale.y ratio.
     ///
     /// If 1.0, it means the scale factor is the same in both axes.
     fn aspect(&self) -> f64 {
         let rw = self.frame.width() as f64;
         let rh = self.frame.height() as f64;
-        (self.bounds.width() / rw) / (self.bounds.height() / rh)
+        let bounds = self.scaled_bounds();
+        (bounds.width() / rw) / (bounds.height() / rh)
     }
 
     /// Sets the aspect ratio by expanding the x- or y-axis.
@@ -400,13 +683,13 @@ impl PlotTransform {
             return;
         }
 
+        let mut scaled = self.scaled_bounds();
         if current_aspect < aspect {
-            self.bounds
-                .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
+            scaled.expand_x((aspect / current_aspect - 1.0) * scaled.width() * 0.5);
         } else {
-            self.bounds
-                .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
+            scaled.expand_y((current_aspect / aspect - 1.0) * scaled.height() * 0.5);
         }
+        self.bounds = scaled.unscaled(self.scales);
     }
 
     /// Sets the aspect ratio by changing either the X or Y axis (callers choice).
@@ -419,14 +702,53 @@ impl PlotTransform {
             return;
         }
 
+        let mut scaled = self.scaled_bounds();
         match axis {
             Axis::X => {
-                self.bounds
-                    .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
+                scaled.expand_x((aspect / current_aspect - 1.0) * scaled.width() * 0.5);
             }
             Axis::Y => {
-                self.bounds
-                    .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
+                scaled.expand_y((current_aspect / aspect - 1.0) * scaled.height() * 0.5);
+            }
+        }
+        self.bounds = scaled.unscaled(self.scales);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic = "invalid log base"]
+    fn log_rejects_base_one() {
+        _ = AxisScale::log(1.0);
+    }
+
+    #[test]
+    #[should_panic = "invalid symlog linear threshold"]
+    fn symlog_rejects_zero_threshold() {
+        _ = AxisScale::symlog(10.0, 0.0);
+    }
+
+    #[test]
+    fn invalid_variants_stay_finite() {
+        for scale in [
+            AxisScale::Log { base: 1.0 },
+            AxisScale::Log { base: -2.0 },
+            AxisScale::SymLog {
+                base: 0.0,
+                linear_threshold: -1.0,
+            },
+        ] {
+            let scale = scale.sanitized();
+            for value in [0.5, 1.0, 42.0] {
+                let scaled = scale.scale(value);
+                assert!(scaled.is_finite(), "{scale:?} {value}");
+                assert!(
+                    (scale.unscale(scaled) - value).abs() < 1e-9,
+                    "{scale:?} {value}"
+                );
             }
         }
     }
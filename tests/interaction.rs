@@ -0,0 +1,564 @@
+//! Tests of pointer interactions, driven by synthetic input events frame by frame.
+
+use egui::{Context, Event, Id, Key, Modifiers, PointerButton, Pos2, RawInput, Rect, Ui, Vec2};
+use egui_plot::{
+    Axis, AxisHints, AxisScale, Comparison, DraggableHLine, Grid, HPlacement, Heatmap,
+    IndexedPoints, Line, Plot, PlotMemory, PlotPoint, PlotPoints, PlotResponse, PlotTransform,
+    TimeIndex, TrendLine, TrendLineEvent, TrendLineKind,
+};
+
+/// One frame of input: where the pointer is, whether the primary button is down, how much to
+/// zoom, and which key is pressed.
+#[derive(Clone, Copy)]
+struct Frame {
+    pointer: Pos2,
+    pressed: bool,
+    modifiers: Modifiers,
+    zoom: f32,
+    key: Option<Key>,
+}
+
+impl Frame {
+    fn hover(x: f32, y: f32) -> Self {
+        Self {
+            pointer: Pos2::new(x, y),
+            pressed: false,
+            modifiers: Modifiers::NONE,
+            zoom: 1.0,
+            key: None,
+        }
+    }
+
+    fn press(x: f32, y: f32) -> Self {
+        Self {
+            pressed: true,
+            ..Self::hover(x, y)
+        }
+    }
+
+    fn with_modifiers(self, modifiers: Modifiers) -> Self {
+        Self { modifiers, ..self }
+    }
+
+    fn with_zoom(self, zoom: f32) -> Self {
+        Self { zoom, ..self }
+    }
+
+    fn with_key(self, key: Key) -> Self {
+        Self {
+            key: Some(key),
+            ..self
+        }
+    }
+}
+
+/// Run `show` once per frame of `frames` and collect what it returns.
+fn run<R>(frames: &[Frame], mut show: impl FnMut(&mut Ui) -> R) -> Vec<R> {
+    let ctx = Context::default();
+    let mut pressed = false;
+    let mut results = Vec::new();
+    for (index, frame) in frames.iter().enumerate() {
+        let mut events = vec![Event::PointerMoved(frame.pointer)];
+        if frame.pressed != pressed {
+            events.push(Event::PointerButton {
+                pos: frame.pointer,
+                button: PointerButton::Primary,
+                pressed: frame.pressed,
+                modifiers: frame.modifiers,
+            });
+            pressed = frame.pressed;
+        }
+        if frame.zoom != 1.0 {
+            events.push(Event::Zoom(frame.zoom));
+        }
+        if let Some(key) = frame.key {
+            events.push(Event::Key {
+                key,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers: frame.modifiers,
+            });
+        }
+        let input = RawInput {
+            screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(400.0, 300.0))),
+            time: Some(index as f64 / 60.0),
+            modifiers: frame.modifiers,
+            events,
+            ..Default::default()
+        };
+        let mut result = None;
+        let _ = ctx.run(input, |ctx| {
+            egui::CentralPanel::default().show(ctx, |ui| {
+                result = Some(show(ui));
+            });
+        });
+        results.push(result.expect("the panel is shown every frame"));
+    }
+    results
+}
+
+fn line() -> Line {
+    Line::new(PlotPoints::from_iter(
+        (0..=100).map(|i| [i as f64, i as f64 / 10.0]),
+    ))
+}
+
+fn measure_plot(ui: &mut Ui) -> PlotResponse<()> {
+    Plot::new("measure")
+        .allow_measure(true)
+        .show(ui, |plot_ui| plot_ui.line(line()))
+}
+
+/// A drag from `start` to `end`, with `modifiers` held.
+fn drag(start: Pos2, end: Pos2, modifiers: Modifiers) -> Vec<Frame> {
+    let middle = start + (end - start) / 2.0;
+    vec![
+        Frame::hover(start.x, start.y),
+        Frame::press(start.x, start.y),
+        Frame::press(middle.x, middle.y),
+        Frame::press(end.x, end.y),
+        Frame::hover(end.x, end.y),
+        Frame::hover(end.x, end.y),
+    ]
+    .into_iter()
+    .map(|frame| frame.with_modifiers(modifiers))
+    .collect()
+}
+
+/// A click at `pos`, after hovering it.
+fn click(pos: Pos2) -> Vec<Frame> {
+    vec![
+        Frame::hover(pos.x, pos.y),
+        Frame::press(pos.x, pos.y),
+        Frame::hover(pos.x, pos.y),
+    ]
+}
+
+fn assert_near(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-6, "{a} != {b}");
+}
+
+fn assert_point_near(a: PlotPoint, b: PlotPoint) {
+    assert_near(a.x, b.x);
+    assert_near(a.y, b.y);
+}
+
+#[test]
+fn shift_drag_measures() {
+    let (start, end) = (Pos2::new(100.0, 200.0), Pos2::new(250.0, 100.0));
+    let responses = run(&drag(start, end, Modifiers::SHIFT), measure_plot);
+
+    // Nothing is measured before the drag starts.
+    assert!(responses[0].measurement.is_none());
+    assert!(responses[1].measurement.is_none());
+
+    // While dragging, the measurement follows the pointer.
+    let transform = responses[2].transform;
+    let measurement = responses[3].measurement.expect("measuring while dragging");
+    assert!(!measurement.finished);
+    let expected_start = transform.value_from_position(start);
+    let expected_end = transform.value_from_position(end);
+    assert_near(measurement.start.x, expected_start.x);
+    assert_near(measurement.start.y, expected_start.y);
+    assert_near(measurement.end.x, expected_end.x);
+    assert_near(measurement.end.y, expected_end.y);
+    assert!(measurement.delta_x() > 0.0 && measurement.delta_y() > 0.0);
+    assert!(measurement.duration.is_none());
+
+    // Releasing the button reports the final measurement once.
+    let finished = responses[4].measurement.expect("finished measurement");
+    assert!(finished.finished);
+    assert_eq!(finished.start, measurement.start);
+    assert!(responses[5].measurement.is_none());
+
+    // Measuring doesn't pan the plot.
+    assert_eq!(
+        responses[5].transform.bounds(),
+        responses[0].transform.bounds()
+    );
+}
+
+#[test]
+fn drag_without_modifiers_pans_instead_of_measuring() {
+    let (start, end) = (Pos2::new(100.0, 200.0), Pos2::new(250.0, 100.0));
+    let responses = run(&drag(start, end, Modifiers::NONE), measure_plot);
+    assert!(responses
+        .iter()
+        .all(|response| response.measurement.is_none()));
+    assert_ne!(
+        responses[5].transform.bounds(),
+        responses[0].transform.bounds()
+    );
+}
+
+#[test]
+fn measurement_is_off_by_default() {
+    let (start, end) = (Pos2::new(100.0, 200.0), Pos2::new(250.0, 100.0));
+    let responses = run(&drag(start, end, Modifiers::SHIFT), |ui| {
+        Plot::new("measure").show(ui, |plot_ui| plot_ui.line(line()))
+    });
+    assert!(responses
+        .iter()
+        .all(|response| response.measurement.is_none()));
+}
+
+#[test]
+fn measurement_reports_duration_on_a_time_axis() {
+    // Hourly bars.
+    let timestamps: Vec<f64> = (0..=100)
+        .map(|i| 1_700_000_000.0 + i as f64 * 3600.0)
+        .collect();
+    let (start, end) = (Pos2::new(100.0, 200.0), Pos2::new(250.0, 100.0));
+    let responses = run(&drag(start, end, Modifiers::SHIFT), |ui| {
+        Plot::new("measure")
+            .allow_measure(true)
+            .x_time_index(TimeIndex::new(timestamps.clone()))
+            .show(ui, |plot_ui| plot_ui.line(line()))
+    });
+    let measurement = responses[4].measurement.expect("finished measurement");
+    let duration = measurement.duration.expect("a duration on a time axis");
+    assert_near(duration, measurement.delta_x() * 3600.0);
+}
+
+#[test]
+fn comparison_hovers_series_at_their_normalized_values() {
+    let id = Id::new("btc");
+    let btc: IndexedPoints = (0..=100)
+        .map(|i| [i as f64, 40_000.0 + i as f64 * 100.0])
+        .collect();
+    let show = |ui: &mut Ui| {
+        Plot::new("comparison").show(ui, |plot_ui| {
+            plot_ui.comparison(
+                Comparison::new()
+                    .anchor(0.0)
+                    .series(Line::new(PlotPoints::from_iter(
+                        (0..=100).map(|i| [i as f64, 4800.0 - i as f64]),
+                    )))
+                    .series(Line::new(btc.clone()).id(id)),
+            );
+        })
+    };
+
+    // Locate the point at x = 50 on the first frame, then hover it.
+    let transform = run(&[Frame::hover(0.0, 0.0)], show)[0].transform;
+    let percent = 100.0 * (45_000.0 - 40_000.0) / 40_000.0;
+    let pos = transform.position_from_point(&PlotPoint::new(50.0, percent));
+    let responses = run(
+        &[Frame::hover(pos.x, pos.y), Frame::hover(pos.x, pos.y)],
+        show,
+    );
+    assert_eq!(responses[1].hovered_plot_item, Some(id));
+    assert!(responses[1].transform.bounds().max()[1] >= 25.0);
+}
+
+#[test]
+fn items_over_a_heatmap_win_the_hover() {
+    let line_id = Id::new("line");
+    let heatmap_id = Id::new("heatmap");
+    let show = |ui: &mut Ui| {
+        Plot::new("heatmap").show(ui, |plot_ui| {
+            plot_ui.heatmap(
+                Heatmap::new(
+                    Grid::new(vec![1.0, 2.0, 3.0, 4.0], 2)
+                        .x_range(0.0..=100.0)
+                        .y_range(0.0..=10.0),
+                )
+                .id(heatmap_id),
+            );
+            plot_ui.line(line().id(line_id));
+        })
+    };
+
+    let transform = run(&[Frame::hover(0.0, 0.0)], show)[0].transform;
+    let on_line = transform.position_from_point(&PlotPoint::new(20.0, 2.0));
+    let off_line = transform.position_from_point(&PlotPoint::new(70.0, 2.0));
+    let hovered = |pos: Pos2| {
+        run(
+            &[Frame::hover(pos.x, pos.y), Frame::hover(pos.x, pos.y)],
+            show,
+        )[1]
+        .hovered_plot_item
+    };
+    assert_eq!(hovered(on_line), Some(line_id));
+    assert_eq!(hovered(off_line), Some(heatmap_id));
+}
+
+#[test]
+fn y_axes_with_an_id_can_have_their_own_scale() {
+    let (plot_id, volume_axis) = (Id::new("scales"), Id::new("volume"));
+    let transforms = run(&[Frame::hover(0.0, 0.0)], |ui| {
+        Plot::new("scales")
+            .id(plot_id)
+            .custom_y_axes(vec![
+                AxisHints::new_y(),
+                AxisHints::new_y().id(volume_axis).scale(AxisScale::log10()),
+            ])
+            .show(ui, |plot_ui| {
+                plot_ui.line(line());
+                plot_ui.line(
+                    Line::new(PlotPoints::from_iter(
+                        (0..=100).map(|i| [i as f64, 10f64.powf(i as f64 / 25.0)]),
+                    ))
+                    .y_axis(volume_axis),
+                );
+            });
+        let memory = PlotMemory::load(ui.ctx(), plot_id).unwrap();
+        (
+            memory.transform(),
+            memory.y_axis_transform(volume_axis).unwrap(),
+        )
+    });
+    let (main, volume) = transforms[0];
+    assert_eq!(main.scale(Axis::Y), AxisScale::Linear);
+    assert_eq!(volume.scale(Axis::Y), AxisScale::log10());
+
+    // Evenly spaced decades on the volume axis, evenly spaced values on the main one.
+    let y = |transform: &PlotTransform, value: f64| transform.position_from_point_y(value);
+    assert!(
+        ((y(&volume, 10.0) - y(&volume, 1.0)) - (y(&volume, 100.0) - y(&volume, 10.0))).abs()
+            < 0.01
+    );
+    assert!(((y(&main, 2.0) - y(&main, 1.0)) - (y(&main, 3.0) - y(&main, 2.0))).abs() < 0.01);
+}
+
+#[test]
+fn y_axes_with_an_id_fit_drag_and_zoom_independently() {
+    let (plot_id, prices, volumes) = (Id::new("axes"), Id::new("prices"), Id::new("volumes"));
+    let show = |ui: &mut Ui| {
+        let response = Plot::new("axes")
+            .id(plot_id)
+            .custom_y_axes(vec![
+                AxisHints::new_y().id(prices),
+                AxisHints::new_y().id(volumes).placement(HPlacement::Right),
+            ])
+            .show(ui, |plot_ui| {
+                plot_ui.line(line().y_axis(prices));
+                plot_ui.line(
+                    Line::new(PlotPoints::from_iter(
+                        (0..=100).map(|i| [i as f64, 1000.0 + 10.0 * i as f64]),
+                    ))
+                    .y_axis(volumes),
+                );
+            });
+        let memory = PlotMemory::load(ui.ctx(), plot_id).unwrap();
+        let y_range = |id| {
+            let bounds = *memory.y_axis_transform(id).unwrap().bounds();
+            (bounds.min()[1], bounds.max()[1])
+        };
+        (response.response.rect, y_range(prices), y_range(volumes))
+    };
+
+    // Each axis fits its own items, with a margin.
+    let (plot_rect, price_range, volume_range) = run(&[Frame::hover(0.0, 0.0)], show)[0];
+    assert!(
+        price_range.0 < 0.0 && price_range.0 > -2.0,
+        "{price_range:?}"
+    );
+    assert!(
+        price_range.1 > 10.0 && price_range.1 < 12.0,
+        "{price_range:?}"
+    );
+    assert!(
+        volume_range.0 < 1000.0 && volume_range.0 > 900.0,
+        "{volume_range:?}"
+    );
+    assert!(
+        volume_range.1 > 2000.0 && volume_range.1 < 2100.0,
+        "{volume_range:?}"
+    );
+
+    // A drag over the price axis, left of the plot, pans only the price axis.
+    let x = plot_rect.left() - 5.0;
+    let dragged = run(
+        &drag(Pos2::new(x, 100.0), Pos2::new(x, 200.0), Modifiers::NONE),
+        show,
+    );
+    let (_, dragged_prices, dragged_volumes) = *dragged.last().unwrap();
+    let price_shift = dragged_prices.0 - price_range.0;
+    assert!(price_shift > 1.0, "{dragged_prices:?}");
+    assert_near(dragged_prices.1 - price_range.1, price_shift);
+    assert_eq!(dragged_volumes, volume_range);
+
+    // Zooming over the plot applies to both axes.
+    let center = plot_rect.center();
+    let zoomed = run(
+        &[
+            Frame::hover(center.x, center.y),
+            Frame::hover(center.x, center.y).with_zoom(2.0),
+            Frame::hover(center.x, center.y),
+        ],
+        show,
+    );
+    let (_, zoomed_prices, zoomed_volumes) = *zoomed.last().unwrap();
+    let height = |(min, max): (f64, f64)| max - min;
+    assert!((height(zoomed_prices) / height(price_range) - 0.5).abs() < 0.01);
+    assert!((height(zoomed_volumes) / height(volume_range) - 0.5).abs() < 0.01);
+}
+
+fn trend_line_plot(
+    ui: &mut Ui,
+    tool: Option<TrendLineKind>,
+    trend_line: Option<TrendLine>,
+) -> PlotResponse<()> {
+    Plot::new("trend lines")
+        .trend_line_tool(tool)
+        .show(ui, |plot_ui| {
+            plot_ui.line(line());
+            if let Some(trend_line) = trend_line {
+                plot_ui.trend_line(trend_line);
+            }
+        })
+}
+
+#[test]
+fn two_clicks_with_the_tool_create_a_trend_line() {
+    let show = |ui: &mut Ui| trend_line_plot(ui, Some(TrendLineKind::Ray), None);
+    let (start, end) = (Pos2::new(100.0, 200.0), Pos2::new(250.0, 100.0));
+
+    let frames = [click(start), click(end)].concat();
+    let responses = run(&frames, show);
+    let transform = responses.last().unwrap().transform;
+    let events: Vec<TrendLineEvent> = responses
+        .into_iter()
+        .flat_map(|response| response.trend_line_events)
+        .collect();
+    let [TrendLineEvent::Created {
+        kind,
+        start: created_start,
+        end: created_end,
+    }] = events[..]
+    else {
+        panic!("expected one created line, got {events:?}");
+    };
+    assert_eq!(kind, TrendLineKind::Ray);
+    assert_point_near(created_start, transform.value_from_position(start));
+    assert_point_near(created_end, transform.value_from_position(end));
+}
+
+#[test]
+fn dragging_an_end_point_moves_only_that_point() {
+    let id = Id::new("trend");
+    let show = |ui: &mut Ui| {
+        let trend_line = TrendLine::new(id, [20.0, 2.0], [80.0, 8.0]);
+        trend_line_plot(ui, None, Some(trend_line))
+    };
+    let transform = run(&[Frame::hover(0.0, 0.0)], show)[0].transform;
+    let end = transform.position_from_point(&PlotPoint::new(80.0, 8.0));
+    let target = end + Vec2::new(-40.0, 30.0);
+
+    let responses = run(&drag(end, target, Modifiers::NONE), show);
+    let moved = responses
+        .iter()
+        .flat_map(|response| &response.trend_line_events)
+        .filter_map(|event| match event {
+            TrendLineEvent::Moved { id, start, end } => Some((*id, *start, *end)),
+            _ => None,
+        })
+        .next_back()
+        .expect("the line was moved");
+    assert_eq!(moved.0, id);
+    assert_point_near(moved.1, PlotPoint::new(20.0, 2.0));
+    assert_point_near(moved.2, responses[3].transform.value_from_position(target));
+
+    // The drag moved the line, not the plot.
+    assert_eq!(
+        responses[5].transform.bounds(),
+        responses[0].transform.bounds()
+    );
+}
+
+#[test]
+fn delete_removes_the_selected_trend_line() {
+    let id = Id::new("trend");
+    let show = |ui: &mut Ui| {
+        let trend_line = TrendLine::new(id, [20.0, 2.0], [80.0, 8.0]);
+        trend_line_plot(ui, None, Some(trend_line))
+    };
+    let transform = run(&[Frame::hover(0.0, 0.0)], show)[0].transform;
+    let middle = transform.position_from_point(&PlotPoint::new(50.0, 5.0));
+
+    // Without a selection, the key does nothing.
+    let unselected = run(
+        &[Frame::hover(middle.x, middle.y).with_key(Key::Delete)],
+        show,
+    );
+    assert!(unselected[0].trend_line_events.is_empty());
+
+    let mut frames = click(middle);
+    frames.push(Frame::hover(middle.x, middle.y).with_key(Key::Delete));
+    let events: Vec<TrendLineEvent> = run(&frames, show)
+        .into_iter()
+        .flat_map(|response| response.trend_line_events)
+        .collect();
+    assert_eq!(events, [TrendLineEvent::Deleted { id }]);
+}
+
+fn hline_plot(ui: &mut Ui, snap: Option<f64>) -> PlotResponse<()> {
+    Plot::new("hlines").show(ui, |plot_ui| {
+        plot_ui.line(line());
+        let mut hline = DraggableHLine::new(Id::new("order"), 5.0);
+        if let Some(tick_size) = snap {
+            hline = hline.snap(tick_size);
+        }
+        plot_ui.draggable_hline(hline);
+    })
+}
+
+#[test]
+fn dragging_an_hline_reports_its_value_until_released() {
+    let show = |ui: &mut Ui| hline_plot(ui, None);
+    let transform = run(&[Frame::hover(0.0, 0.0); 2], show)[1].transform;
+    let start = transform.position_from_point(&PlotPoint::new(30.0, 5.0));
+    let end = start + Vec2::new(40.0, -60.0);
+
+    let responses = run(&drag(start, end, Modifiers::NONE), show);
+    assert!(responses[0].dragged_hline.is_none());
+
+    // The line follows the pointer vertically while the button is held.
+    let dragged = responses[3].dragged_hline.expect("the line is dragged");
+    assert_eq!(dragged.id, Id::new("order"));
+    assert!(!dragged.stopped);
+    assert_near(dragged.start_y, 5.0);
+    let expected = responses[3].transform.value_from_position(end).y;
+    assert!(
+        (dragged.y - expected).abs() < 1e-3,
+        "{} != {expected}",
+        dragged.y
+    );
+
+    // The release is reported once, with the final value.
+    let released = responses[4].dragged_hline.expect("the release is reported");
+    assert!(released.stopped);
+    assert_eq!(released.y, dragged.y);
+    assert!(responses[5].dragged_hline.is_none());
+
+    // The drag moved the line, not the plot.
+    assert_eq!(
+        responses[5].transform.bounds(),
+        responses[0].transform.bounds()
+    );
+}
+
+#[test]
+fn dragged_hline_values_snap_to_the_tick_size() {
+    let show = |ui: &mut Ui| hline_plot(ui, Some(0.25));
+    let transform = run(&[Frame::hover(0.0, 0.0); 2], show)[1].transform;
+    let start = transform.position_from_point(&PlotPoint::new(30.0, 5.0));
+    let end = start + Vec2::new(0.0, -37.0);
+
+    let responses = run(&drag(start, end, Modifiers::NONE), show);
+    let unsnapped = responses[3].transform.value_from_position(end).y;
+    for response in &responses[2..=4] {
+        let dragged = response.dragged_hline.expect("the line is dragged");
+        assert_eq!(
+            (dragged.y / 0.25).fract(),
+            0.0,
+            "{} is not snapped",
+            dragged.y
+        );
+    }
+    let dragged = responses[3].dragged_hline.unwrap();
+    assert!(dragged.y != 5.0);
+    assert!((dragged.y - unsnapped).abs() <= 0.125);
+}
@@ -0,0 +1,970 @@
+//! Visual regression tests.
+//!
+//! Each test renders a plot headlessly with [`PlotRenderer`] and compares both the painted shapes
+//! and the rasterized image against the golden files `tests/snapshots/<name>.txt` and
+//! `tests/snapshots/<name>.png`. Next to the comparison, tests check what was painted where a
+//! plain assertion says more than an image diff, e.g. the labels or the edges of an item.
+//!
+//! After an intended change, update the golden files with
+//!
+//! ```sh
+//! UPDATE_SNAPSHOTS=1 cargo test --test snapshots
+//! ```
+//!
+//! and review the changed images before committing them. When a test fails, the new rendering
+//! is written next to the golden file as `<name>.new.png` (and `<name>.new.txt`).
+
+use std::{collections::HashSet, fmt::Write as _, fs, path::PathBuf};
+
+use egui::{
+    epaint::{ClippedShape, Mesh},
+    pos2, vec2, Align2, Color32, ColorImage, Pos2, Rect, Shape, Stroke, Ui, Vec2, WidgetText,
+};
+use egui_plot::{
+    AxisHints, AxisScale, Band, Candle, Candlestick, Colormap, Comparison, Contour, DraggableHLine,
+    ErrorBars, FibRetracement, Grid, HRay, HSpan, Heatmap, Legend, Line, LineStyle, LinkedYHRay,
+    LinkedYPolygon, LinkedYText, MarkerShape, Plot, PlotPoints, PlotRenderer, PlotTransform,
+    PlotUi, Points, Polygon, RingBuffer, TrendLine, VSpan, VolumeLevel, VolumeProfile, YScaleMode,
+};
+
+/// A golden file comparison of what a ui paints.
+struct Snapshot {
+    name: &'static str,
+    size: Vec2,
+
+    /// Largest difference of a color channel for a pixel to still count as equal.
+    channel_tolerance: u8,
+
+    /// How many pixels may differ by more than the channel tolerance.
+    max_different_pixels: usize,
+
+    /// Largest difference of a coordinate or size in the shape list, in ui points.
+    shape_tolerance: f32,
+}
+
+impl Snapshot {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            size: vec2(320.0, 200.0),
+            channel_tolerance: 8,
+            max_different_pixels: 32,
+            shape_tolerance: 0.5,
+        }
+    }
+
+    /// Compare what `add_contents` paints against the golden files, and return it for further
+    /// assertions.
+    fn check(self, add_contents: impl FnMut(&mut Ui)) -> Painted {
+        let renderer = PlotRenderer::new(self.size);
+        let mut add_contents = add_contents;
+        let clipped = renderer.shapes(&mut add_contents);
+        let shapes = describe_shapes(&clipped);
+        let painted = Painted::new(clipped);
+        let image = renderer.render(&mut add_contents);
+
+        let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+        let shapes_path = dir.join(format!("{}.txt", self.name));
+        let image_path = dir.join(format!("{}.png", self.name));
+        let new_shapes_path = dir.join(format!("{}.new.txt", self.name));
+        let new_image_path = dir.join(format!("{}.new.png", self.name));
+
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(&shapes_path, &shapes).unwrap();
+            write_png(&image_path, &image);
+            let _ = fs::remove_file(&new_shapes_path);
+            let _ = fs::remove_file(&new_image_path);
+            return painted;
+        }
+
+        let mut errors = Vec::new();
+        match fs::read_to_string(&shapes_path) {
+            Ok(golden) => {
+                if let Some(error) = self.compare_shapes(&golden, &shapes) {
+                    errors.push(error);
+                    fs::write(&new_shapes_path, &shapes).unwrap();
+                }
+            }
+            Err(err) => errors.push(format!("can't read {}: {err}", shapes_path.display())),
+        }
+        match read_png(&image_path) {
+            Ok(golden) => {
+                if let Some(error) = self.compare_images(&golden, &image) {
+                    errors.push(error);
+                    write_png(&new_image_path, &image);
+                }
+            }
+            Err(err) => errors.push(format!("can't read {}: {err}", image_path.display())),
+        }
+
+        assert!(
+            errors.is_empty(),
+            "snapshot {:?} doesn't match:\n{}\nRun with UPDATE_SNAPSHOTS=1 if the change is intended.",
+            self.name,
+            errors.join("\n"),
+        );
+        painted
+    }
+
+    /// [`Self::check`] a plot with the name of the snapshot, set up by `plot` and filled by
+    /// `items`. Also returns the transform of the plot.
+    fn check_plot(
+        self,
+        plot: impl Fn(Plot) -> Plot,
+        items: impl Fn(&mut PlotUi),
+    ) -> (Painted, PlotTransform) {
+        let name = self.name;
+        let mut transform = None;
+        let painted = self.check(|ui| {
+            transform = Some(plot(Plot::new(name)).show(ui, &items).transform);
+        });
+        (painted, transform.expect("the plot is shown"))
+    }
+
+    fn compare_shapes(&self, golden: &str, shapes: &str) -> Option<String> {
+        let (golden_lines, lines): (Vec<_>, Vec<_>) =
+            (golden.lines().collect(), shapes.lines().collect());
+        if golden_lines.len() != lines.len() {
+            return Some(format!(
+                "{} shapes instead of {}",
+                lines.len(),
+                golden_lines.len()
+            ));
+        }
+        for (i, (golden_line, line)) in golden_lines.iter().zip(&lines).enumerate() {
+            let golden_tokens: Vec<_> = golden_line.split_whitespace().collect();
+            let tokens: Vec<_> = line.split_whitespace().collect();
+            let equal = golden_tokens.len() == tokens.len()
+                && golden_tokens.iter().zip(&tokens).all(|(a, b)| {
+                    match (a.parse::<f32>(), b.parse::<f32>()) {
+                        (Ok(a), Ok(b)) => (a - b).abs() <= self.shape_tolerance,
+                        _ => a == b,
+                    }
+                });
+            if !equal {
+                return Some(format!(
+                    "shape {i} differs:\n  expected: {golden_line}\n  actual:   {line}"
+                ));
+            }
+        }
+        None
+    }
+
+    fn compare_images(&self, golden: &ColorImage, image: &ColorImage) -> Option<String> {
+        if golden.size != image.size {
+            return Some(format!(
+                "image size {:?} instead of {:?}",
+                image.size, golden.size
+            ));
+        }
+        let different_pixels = golden
+            .pixels
+            .iter()
+            .zip(&image.pixels)
+            .filter(|(a, b)| {
+                a.to_array()
+                    .iter()
+                    .zip(b.to_array())
+                    .any(|(a, b)| a.abs_diff(b) > self.channel_tolerance)
+            })
+            .count();
+        (different_pixels > self.max_different_pixels)
+            .then(|| format!("{different_pixels} pixels differ"))
+    }
+}
+
+/// The shapes a snapshot painted, in order and with nested shapes flattened.
+struct Painted(Vec<Shape>);
+
+impl Painted {
+    fn new(clipped: Vec<ClippedShape>) -> Self {
+        fn flatten(shape: Shape, shapes: &mut Vec<Shape>) {
+            match shape {
+                Shape::Vec(nested) => {
+                    for shape in nested {
+                        flatten(shape, shapes);
+                    }
+                }
+                shape => shapes.push(shape),
+            }
+        }
+        let mut shapes = Vec::new();
+        for ClippedShape { shape, .. } in clipped {
+            flatten(shape, &mut shapes);
+        }
+        Self(shapes)
+    }
+
+    fn texts(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .filter_map(|shape| match shape {
+                Shape::Text(text) => Some(text.galley.text().to_owned()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn rects(&self) -> Vec<Rect> {
+        self.0
+            .iter()
+            .filter_map(|shape| match shape {
+                Shape::Rect(rect) => Some(rect.rect),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn segments(&self) -> Vec<[Pos2; 2]> {
+        self.0
+            .iter()
+            .filter_map(|shape| match shape {
+                Shape::LineSegment { points, .. } => Some(*points),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn paths(&self) -> Vec<&[Pos2]> {
+        self.0
+            .iter()
+            .filter_map(|shape| match shape {
+                Shape::Path(path) => Some(&path.points[..]),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn meshes(&self) -> Vec<&Mesh> {
+        self.0
+            .iter()
+            .filter_map(|shape| match shape {
+                Shape::Mesh(mesh) => Some(mesh),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+fn assert_near(a: f32, b: f32) {
+    assert!((a - b).abs() < 0.01, "{a} != {b}");
+}
+
+/// One line per shape, with coordinates rounded to a tenth of a point.
+fn describe_shapes(shapes: &[ClippedShape]) -> String {
+    let mut out = String::new();
+    for clipped in shapes {
+        describe_shape(&mut out, &clipped.shape);
+    }
+    out
+}
+
+fn describe_shape(out: &mut String, shape: &Shape) {
+    let color = |c: Color32| {
+        let [r, g, b, a] = c.to_array();
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    };
+    let stroke = |s: Stroke| format!("stroke {:.1} {}", s.width, color(s.color));
+    let pos = |p: egui::Pos2| format!("{:.1} {:.1}", p.x, p.y);
+
+    let _ = match shape {
+        Shape::Noop => Ok(()),
+        Shape::Vec(shapes) => {
+            for shape in shapes {
+                describe_shape(out, shape);
+            }
+            Ok(())
+        }
+        Shape::Circle(c) => writeln!(
+            out,
+            "circle {} {:.1} fill {} {}",
+            pos(c.center),
+            c.radius,
+            color(c.fill),
+            stroke(c.stroke)
+        ),
+        Shape::Ellipse(e) => writeln!(
+            out,
+            "ellipse {} {:.1} {:.1} fill {} {}",
+            pos(e.center),
+            e.radius.x,
+            e.radius.y,
+            color(e.fill),
+            stroke(e.stroke)
+        ),
+        Shape::LineSegment { points, stroke: s } => writeln!(
+            out,
+            "segment {} {} {}",
+            pos(points[0]),
+            pos(points[1]),
+            stroke(*s)
+        ),
+        Shape::Path(p) => {
+            let points: Vec<_> = p.points.iter().map(|&p| pos(p)).collect();
+            writeln!(
+                out,
+                "path closed {} fill {} {} points {}",
+                p.closed,
+                color(p.fill),
+                stroke(p.stroke),
+                points.join(" ")
+            )
+        }
+        Shape::Rect(r) => writeln!(
+            out,
+            "rect {} {} fill {} {}",
+            pos(r.rect.min),
+            pos(r.rect.max),
+            color(r.fill),
+            stroke(r.stroke)
+        ),
+        Shape::Text(t) => writeln!(
+            out,
+            "text {} {:?} color {}",
+            pos(t.pos),
+            t.galley.text(),
+            color(t.override_text_color.unwrap_or(t.fallback_color))
+        ),
+        Shape::Mesh(m) => writeln!(
+            out,
+            "mesh {:?} vertices {} indices {}",
+            m.texture_id,
+            m.vertices.len(),
+            m.indices.len()
+        ),
+        Shape::QuadraticBezier(b) => writeln!(
+            out,
+            "quadratic {} {} {} {}",
+            pos(b.points[0]),
+            pos(b.points[1]),
+            pos(b.points[2]),
+            stroke(b.stroke)
+        ),
+        Shape::CubicBezier(b) => writeln!(
+            out,
+            "cubic {} {} {} {} {}",
+            pos(b.points[0]),
+            pos(b.points[1]),
+            pos(b.points[2]),
+            pos(b.points[3]),
+            stroke(b.stroke)
+        ),
+        Shape::Callback(_) => writeln!(out, "callback"),
+    };
+}
+
+fn write_png(path: &PathBuf, image: &ColorImage) {
+    let file = fs::File::create(path).unwrap();
+    let mut encoder = png::Encoder::new(file, image.size[0] as u32, image.size[1] as u32);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let data: Vec<u8> = image
+        .pixels
+        .iter()
+        .flat_map(|c| c.to_srgba_unmultiplied())
+        .collect();
+    encoder
+        .write_header()
+        .unwrap()
+        .write_image_data(&data)
+        .unwrap();
+}
+
+fn read_png(path: &PathBuf) -> Result<ColorImage, png::DecodingError> {
+    let mut reader = png::Decoder::new(fs::File::open(path)?).read_info()?;
+    let mut data = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut data)?;
+    let pixels = data[..info.buffer_size()]
+        .chunks_exact(4)
+        .map(|p| Color32::from_rgba_unmultiplied(p[0], p[1], p[2], p[3]))
+        .collect();
+    Ok(ColorImage {
+        size: [info.width as usize, info.height as usize],
+        pixels,
+    })
+}
+
+// ----------------------------------------------------------------------------
+
+fn wave(phase: f64) -> PlotPoints {
+    (0..=64)
+        .map(|i| {
+            let x = i as f64 / 8.0;
+            [x, (x + phase).sin()]
+        })
+        .collect()
+}
+
+#[test]
+fn axes() {
+    Snapshot::new("axes").check_plot(
+        |plot| {
+            plot.custom_x_axes(vec![AxisHints::new_x().label("time")])
+                .custom_y_axes(vec![
+                    AxisHints::new_y().label("value"),
+                    AxisHints::new_y()
+                        .label("mirrored")
+                        .placement(egui_plot::HPlacement::Right),
+                ])
+                .y_highlights(vec![(0.5, Color32::DARK_GREEN)])
+        },
+        |plot_ui| plot_ui.line(Line::new(wave(0.0))),
+    );
+}
+
+#[test]
+fn line_styles() {
+    Snapshot::new("line_styles").check_plot(
+        |plot| plot.show_axes(false).legend(Legend::default()),
+        |plot_ui| {
+            plot_ui.line(Line::new(wave(0.0)).name("solid").width(2.0));
+            plot_ui.line(
+                Line::new(wave(1.0))
+                    .name("dashed")
+                    .style(LineStyle::dashed_loose()),
+            );
+            plot_ui.line(
+                Line::new(wave(2.0))
+                    .name("dotted")
+                    .style(LineStyle::dotted_dense()),
+            );
+            plot_ui.points(
+                Points::new(wave(3.0))
+                    .name("points")
+                    .shape(MarkerShape::Diamond)
+                    .radius(3.0),
+            );
+            plot_ui
+                .polygon(Polygon::new(vec![[1.0, -0.5], [2.0, 0.5], [3.0, -0.5]]).name("polygon"));
+        },
+    );
+}
+
+#[test]
+fn linked_y_items() {
+    Snapshot::new("linked_y_items").check_plot(
+        |plot| plot,
+        |plot_ui| {
+            plot_ui.line(Line::new(wave(0.0)));
+            plot_ui.hray(HRay::new([2.0, 0.5]).style(LineStyle::dashed_dense()));
+            plot_ui.linked_y_hray(LinkedYHRay::new(-0.5, 40.0));
+            plot_ui.linked_y_text(
+                LinkedYText::new(-0.5, 60.0, WidgetText::from("order")).color(Color32::BLUE),
+            );
+            plot_ui.linked_y_polygon(
+                LinkedYPolygon::new(
+                    vec![pos2(-30.0, 0.0), pos2(-20.0, -6.0), pos2(-20.0, 6.0)],
+                    0.25,
+                )
+                .stroke(Stroke::new(1.0, Color32::RED)),
+            );
+        },
+    );
+}
+
+#[test]
+fn band() {
+    let (painted, _) = Snapshot::new("band").check_plot(
+        |plot| plot,
+        |plot_ui| {
+            plot_ui.band(
+                Band::new(wave(0.0), wave(1.5))
+                    .color(Color32::DARK_GREEN)
+                    .crossed_color(Color32::RED),
+            );
+        },
+    );
+
+    // Two triangles between each pair of the 65 points, also where the series cross.
+    let [mesh] = painted.meshes()[..] else {
+        panic!("the band is one mesh");
+    };
+    assert_eq!(mesh.indices.len(), 64 * 2 * 3);
+    // The series cross, so both colors are used.
+    let colors: HashSet<Color32> = mesh.vertices.iter().map(|vertex| vertex.color).collect();
+    assert_eq!(colors.len(), 2);
+    assert!(colors.iter().any(|color| color.g() > color.r()));
+    assert!(colors.iter().any(|color| color.r() > color.g()));
+}
+
+#[test]
+fn volume_profile() {
+    let (painted, transform) = Snapshot::new("volume_profile").check_plot(
+        |plot| plot,
+        |plot_ui| {
+            plot_ui.line(Line::new(wave(0.0)));
+            let levels = (0..10).map(|i| {
+                let price = -0.9 + i as f64 * 0.2;
+                let volume = 10.0 - (i as f64 - 6.0).abs() * 2.0;
+                VolumeLevel::with_split(price, volume * 0.6, volume * 0.4 + 1.0)
+            });
+            plot_ui.volume_profile(VolumeProfile::new(levels.collect()).max_width(80.0));
+            plot_ui.volume_profile(
+                VolumeProfile::new(vec![
+                    VolumeLevel::new(-0.5, 3.0),
+                    VolumeLevel::new(0.0, 5.0),
+                ])
+                .placement(egui_plot::HPlacement::Left)
+                .bucket_size(0.4),
+            );
+        },
+    );
+
+    let frame = transform.frame();
+    let bars: Vec<Rect> = painted
+        .rects()
+        .into_iter()
+        .filter(|rect| rect.height() < frame.height())
+        .collect();
+    // The bars of the first profile grow from the right edge, split into sell and buy volume,
+    // and the largest one reaches the maximum width.
+    let right_width = bars
+        .iter()
+        .filter(|rect| rect.center().x > frame.center().x)
+        .map(|rect| frame.right() - rect.min.x)
+        .fold(0.0, f32::max);
+    assert_near(right_width, 80.0);
+    // The bars of the second one grow from the left edge, in proportion to their volume.
+    let left: Vec<Rect> = bars
+        .into_iter()
+        .filter(|rect| rect.min.x == frame.left())
+        .collect();
+    assert_eq!(left.len(), 2);
+    assert_near(left[0].width() / left[1].width(), 3.0 / 5.0);
+    assert_near(left[0].center().y, transform.position_from_point_y(-0.5));
+
+    // Each point of control is marked across the plot.
+    let poc_lines = painted
+        .segments()
+        .into_iter()
+        .filter(|[a, b]| a.x == frame.left() && b.x == frame.right() && a.y == b.y)
+        .count();
+    assert!(poc_lines >= 2, "{poc_lines} point of control lines");
+}
+
+#[test]
+fn streaming() {
+    let mut candles: RingBuffer<Candle> = RingBuffer::new(50);
+    for i in 0..80 {
+        let close = (i as f64 * 0.3).sin();
+        candles.push(Candle::new(
+            i as f64,
+            close - 0.2,
+            close + 0.3,
+            close - 0.4,
+            close,
+        ));
+    }
+    if let Some(last) = candles.last_mut() {
+        last.close = 1.0;
+    }
+
+    let (_, transform) = Snapshot::new("streaming").check_plot(
+        |plot| {
+            plot.auto_bounds([false, true].into())
+                .include_x(0.0)
+                .include_x(20.0)
+                .follow_latest(true)
+        },
+        |plot_ui| plot_ui.candlesticks(Candlestick::streaming(candles.clone())),
+    );
+
+    // The view keeps its width and scrolls to the latest candle.
+    let bounds = transform.bounds();
+    assert!((79.0..81.0).contains(&bounds.max()[0]), "{bounds:?}");
+    assert!((19.0..23.0).contains(&bounds.width()), "{bounds:?}");
+    // The edited last candle is in view.
+    assert!(bounds.max()[1] >= 1.0, "{bounds:?}");
+}
+
+#[test]
+fn y_scale_mode() {
+    let candles: Vec<Candle> = (0..40)
+        .map(|i| {
+            let close = 50.0 + 10.0 * (i as f64 * 0.2).sin() + i as f64 * 0.5;
+            Candle::new(i as f64, close - 1.0, close + 1.5, close - 2.0, close)
+        })
+        .collect();
+
+    let (painted, _) = Snapshot::new("y_scale_mode").check_plot(
+        |plot| {
+            plot.include_x(10.0)
+                .include_x(39.0)
+                .auto_bounds([false, true].into())
+                .y_scale_mode(YScaleMode::Percent)
+                .y_highlights(vec![(60.0, Color32::from_rgb(38, 166, 154))])
+        },
+        |plot_ui| plot_ui.candlesticks(Candlestick::new(candles.clone())),
+    );
+
+    // The Y axis and the highlight badge show percent changes.
+    let percents: Vec<String> = painted
+        .texts()
+        .into_iter()
+        .filter(|text| text.ends_with('%'))
+        .collect();
+    for tick in ["-20%", "-10%", "0%", "10%", "20%"] {
+        assert!(percents.iter().any(|text| text == tick), "{percents:?}");
+    }
+    assert!(
+        percents.iter().any(|text| text.contains('.')),
+        "{percents:?}"
+    );
+}
+
+#[test]
+fn comparison() {
+    let series = |start: f64, drift: f64| -> PlotPoints {
+        (0..60)
+            .map(|i| {
+                let x = i as f64;
+                [x, start * (1.0 + drift * x + 0.02 * (x * 0.3).sin())]
+            })
+            .collect()
+    };
+
+    let (painted, transform) = Snapshot::new("comparison").check_plot(
+        |plot| {
+            plot.legend(Legend::default())
+                .y_axis_formatter(|mark, _, _| format!("{:+.0}%", mark.value))
+        },
+        |plot_ui| {
+            plot_ui.comparison(
+                Comparison::new()
+                    .anchor(10.0)
+                    .series(Line::new(series(4800.0, 0.002)).name("SPX"))
+                    .series(Line::new(series(42_000.0, 0.006)).name("BTC"))
+                    .series(Line::new(series(1.1, -0.001)).name("EURUSD")),
+            );
+        },
+    );
+
+    // All series go through 0% at the anchor, whatever their price.
+    let anchor = transform.position_from_point(&[10.0, 0.0].into());
+    let paths = painted.paths();
+    assert_eq!(paths.len(), 3);
+    for points in paths {
+        assert_near(points[10].x, anchor.x);
+        assert_near(points[10].y, anchor.y);
+    }
+    let texts = painted.texts();
+    for name in ["SPX", "BTC", "EURUSD", "+0%"] {
+        assert!(texts.iter().any(|text| text == name), "{texts:?}");
+    }
+}
+
+#[test]
+fn fib_retracement() {
+    let prices: Vec<[f64; 2]> = (0..60)
+        .map(|i| {
+            let x = i as f64;
+            [
+                x,
+                100.0 + 60.0 * (x / 30.0).min(1.0) - 25.0 * ((x - 30.0) / 30.0).max(0.0),
+            ]
+        })
+        .collect();
+
+    let (painted, transform) = Snapshot::new("fib_retracement").check_plot(
+        |plot| plot,
+        |plot_ui| {
+            plot_ui.line(Line::new(PlotPoints::from(prices.clone())).name("price"));
+            plot_ui.fib_retracement(
+                FibRetracement::new([0.0, 100.0], [30.0, 160.0])
+                    .name("fib")
+                    .fill_zones(true)
+                    .extensions(true)
+                    .extension_levels(vec![1.272])
+                    .level_colors(vec![
+                        Color32::GRAY,
+                        Color32::RED,
+                        Color32::GOLD,
+                        Color32::GREEN,
+                        Color32::LIGHT_BLUE,
+                        Color32::BLUE,
+                        Color32::GRAY,
+                    ]),
+            );
+        },
+    );
+
+    // Each level is labeled with its ratio and price, the extension below the start.
+    let labels: Vec<String> = painted
+        .texts()
+        .into_iter()
+        .filter(|text| text.contains('('))
+        .collect();
+    assert_eq!(
+        labels,
+        [
+            "0 (160.0)",
+            "0.236 (145.8)",
+            "0.382 (137.1)",
+            "0.5 (130.0)",
+            "0.618 (122.9)",
+            "0.786 (112.8)",
+            "1 (100.0)",
+            "1.272 (83.7)",
+        ]
+    );
+    // The level lines span the move.
+    let (left, right) = (
+        transform.position_from_point_x(0.0),
+        transform.position_from_point_x(30.0),
+    );
+    let level_y = transform.position_from_point_y(130.0);
+    assert!(painted.paths().iter().any(|points| {
+        points.len() == 2
+            && (points[0].x - left).abs() < 0.01
+            && (points[1].x - right).abs() < 0.01
+            && (points[0].y - level_y).abs() < 0.01
+    }));
+}
+
+#[test]
+fn spans() {
+    let (painted, transform) = Snapshot::new("spans").check_plot(
+        |plot| plot.legend(Legend::default()).include_y(0.0),
+        |plot_ui| {
+            plot_ui.line(Line::new(PlotPoints::from_explicit_callback(
+                |x| 50.0 + 40.0 * (x / 3.0).sin(),
+                0.0..=20.0,
+                200,
+            )));
+            plot_ui.vspan(
+                VSpan::new(6.0, 11.0)
+                    .name("Session")
+                    .label("Session")
+                    .style(LineStyle::dashed_dense()),
+            );
+            plot_ui.hspan(
+                HSpan::new(70.0, f64::INFINITY)
+                    .name("Overbought")
+                    .label("Overbought")
+                    .label_anchor(Align2::CENTER_BOTTOM)
+                    .color(Color32::RED),
+            );
+        },
+    );
+
+    let frame = transform.frame();
+    let rects = painted.rects();
+    // The vertical span covers its X range at full height.
+    let session = Rect::from_x_y_ranges(
+        transform.position_from_point_x(6.0)..=transform.position_from_point_x(11.0),
+        frame.y_range(),
+    );
+    // The horizontal span reaches from its value to the top, since it is unbounded above.
+    let overbought = Rect::from_x_y_ranges(
+        frame.x_range(),
+        frame.top()..=transform.position_from_point_y(70.0),
+    );
+    for expected in [session, overbought] {
+        assert!(
+            rects.iter().any(|rect| {
+                (rect.min - expected.min).length() < 0.01
+                    && (rect.max - expected.max).length() < 0.01
+            }),
+            "{expected:?} not in {rects:?}"
+        );
+    }
+    // Labels in the plot and entries in the legend.
+    let texts = painted.texts();
+    for name in ["Session", "Overbought"] {
+        assert_eq!(texts.iter().filter(|text| *text == name).count(), 2);
+    }
+}
+
+#[test]
+fn heatmap() {
+    let (rows, cols) = (8, 12);
+    let values: Vec<f64> = (0..rows * cols)
+        .map(|i| {
+            let (row, col) = ((i / cols) as f64, (i % cols) as f64);
+            if row == 3.0 && col == 5.0 {
+                f64::NAN
+            } else {
+                (col / 2.0).sin() * (row / 3.0).cos()
+            }
+        })
+        .collect();
+    let grid = Grid::new(values, cols)
+        .x_range(0.0..=24.0)
+        .y_range(100.0..=108.0);
+    let heatmap = Heatmap::new(grid).colormap(Colormap::Magma);
+
+    let painted = Snapshot::new("heatmap").check(|ui| {
+        ui.horizontal(|ui| {
+            ui.add(heatmap.colorbar().height(190.0));
+            Plot::new("heatmap").height(190.0).show(ui, |plot_ui| {
+                plot_ui.heatmap(heatmap.clone());
+            });
+        });
+    });
+
+    let [colorbar, cells] = painted.meshes()[..] else {
+        panic!("a mesh for the colorbar and one for the cells");
+    };
+    assert_eq!(colorbar.vertices.len(), 64 * 4);
+    // A quad per cell, except the missing one.
+    assert_eq!(cells.vertices.len(), (rows * cols - 1) * 4);
+
+    // The colorbar is labeled from the smallest to the largest value in even steps.
+    let labels: Vec<f64> = painted.texts()[..5]
+        .iter()
+        .map(|text| text.parse().unwrap())
+        .collect();
+    assert_eq!(labels.first(), Some(&-0.98));
+    assert_eq!(labels.last(), Some(&1.0));
+    for step in labels.windows(2) {
+        assert!((step[1] - step[0] - 0.495).abs() < 0.01, "{labels:?}");
+    }
+}
+
+#[test]
+fn contour() {
+    let grid = Grid::from_fn(30, 40, |row, col| {
+        let (x, y) = (col as f64 / 10.0 - 2.0, row as f64 / 10.0 - 1.5);
+        (x * x - y * y) * 50.0 + 20.0 * (-(x - 1.0).powi(2) - y * y).exp()
+    })
+    .x_range(-2.0..=2.0)
+    .y_range(-1.5..=1.5);
+
+    let (painted, _) = Snapshot::new("contour").check_plot(
+        |plot| plot,
+        |plot_ui| {
+            plot_ui.heatmap(Heatmap::new(grid.clone()).colormap(Colormap::Diverging));
+            plot_ui.contour(
+                Contour::new(grid.clone())
+                    .level_count(6)
+                    .labels(true)
+                    .color(Color32::BLACK),
+            );
+        },
+    );
+
+    // The automatic levels are round values, each labeled on its lines.
+    let mut levels: Vec<i32> = painted
+        .texts()
+        .iter()
+        .skip_while(|text| *text != "-50")
+        .map(|text| text.parse().unwrap())
+        .collect();
+    levels.dedup();
+    assert_eq!(levels, [-50, 0, 50, 100, 150]);
+    assert!(painted.paths().len() >= levels.len());
+}
+
+#[test]
+fn contour_fill() {
+    let grid = Grid::from_fn(20, 20, |row, col| {
+        let (x, y) = (col as f64 - 9.5, row as f64 - 9.5);
+        100.0 - x * x - 2.0 * y * y
+    });
+
+    let (painted, _) = Snapshot::new("contour_fill").check_plot(
+        |plot| plot,
+        |plot_ui| {
+            plot_ui.contour(
+                Contour::new(grid.clone())
+                    .levels(vec![-50.0, 0.0, 50.0, 80.0])
+                    .fill(true)
+                    .colormap(Colormap::Viridis)
+                    .style(LineStyle::dashed_dense()),
+            );
+        },
+    );
+
+    // Each of the five bands between and beyond the levels has its own color.
+    let [fill] = painted.meshes()[..] else {
+        panic!("the bands are one mesh");
+    };
+    let colors: HashSet<Color32> = fill.vertices.iter().map(|vertex| vertex.color).collect();
+    assert_eq!(colors.len(), 5);
+    // The lines are dashed.
+    assert!(painted.paths().is_empty());
+    assert!(painted.segments().len() > 100);
+}
+
+#[test]
+fn error_bars() {
+    let returns = vec![[1.0, 0.8], [2.0, 1.4], [3.0, 1.1], [4.0, 1.9]];
+    let drawdowns = vec![[1.0, -0.4], [2.0, -0.9], [3.0, -0.6], [4.0, -1.2]];
+
+    let (painted, transform) = Snapshot::new("error_bars").check_plot(
+        |plot| plot,
+        |plot_ui| {
+            plot_ui.points(
+                Points::new(returns.clone())
+                    .radius(3.0)
+                    .color(Color32::BLUE)
+                    .error_bars(
+                        ErrorBars::new()
+                            .y_asymmetric(vec![0.2, 0.3, 0.1, 0.4], vec![0.4, 0.2, 0.3, 0.6])
+                            .x(vec![0.2, 0.1, 0.3, 0.2]),
+                    ),
+            );
+            plot_ui.line(
+                Line::new(drawdowns.clone())
+                    .color(Color32::RED)
+                    .error_bars(ErrorBars::new().y(vec![0.3, 0.2, 0.4, 0.3]).cap_width(10.0)),
+            );
+        },
+    );
+
+    let segments = painted.segments();
+    let has_segment = |a: [f64; 2], b: [f64; 2]| {
+        let (a, b) = (
+            transform.position_from_point(&a.into()),
+            transform.position_from_point(&b.into()),
+        );
+        segments
+            .iter()
+            .any(|segment| (segment[0] - a).length() < 0.01 && (segment[1] - b).length() < 0.01)
+    };
+    // Asymmetric Y and symmetric X errors of the first point.
+    assert!(has_segment([1.0, 0.6], [1.0, 1.2]));
+    assert!(has_segment([0.8, 0.8], [1.2, 0.8]));
+    // The last bar of the line, with caps of the given width.
+    assert!(has_segment([4.0, -1.5], [4.0, -0.9]));
+    let cap = transform.position_from_point(&[4.0, -1.5].into());
+    assert!(segments.iter().any(|segment| {
+        segment[0].y == cap.y
+            && (segment[0].x - (cap.x - 5.0)).abs() < 0.01
+            && (segment[1].x - (cap.x + 5.0)).abs() < 0.01
+    }));
+}
+
+#[test]
+fn log_scale() {
+    Snapshot::new("log_scale").check_plot(
+        |plot| plot.y_axis_scale(AxisScale::log10()),
+        |plot_ui| {
+            plot_ui.line(Line::new(PlotPoints::from_explicit_callback(
+                |x| 10f64.powf(x),
+                0.0..=1.5,
+                100,
+            )));
+        },
+    );
+}
+
+#[test]
+fn drawing_tools_legend() {
+    Snapshot::new("drawing_tools_legend").check_plot(
+        |plot| {
+            plot.legend(Legend::default())
+                .include_x(0.0)
+                .include_x(10.0)
+                .include_y(0.0)
+                .include_y(10.0)
+        },
+        |plot_ui| {
+            plot_ui.trend_line(
+                TrendLine::new(egui::Id::new("trend"), [1.0, 2.0], [8.0, 7.0]).name("trend"),
+            );
+            plot_ui.draggable_hline(DraggableHLine::new(egui::Id::new("order"), 4.0).name("order"));
+        },
+    );
+}
@@ -0,0 +1,95 @@
+//! Tests of the SVG export of a whole plot.
+
+use egui::{Color32, Context, Pos2, RawInput, Rect, Ui, Vec2};
+use egui_plot::{Line, Plot, PlotBounds, PlotPoint, PlotPoints, PlotResponse, Polygon, Text};
+
+/// Show a plot for one frame of a 400x300 screen.
+fn show_plot(show: impl FnOnce(&mut Ui) -> PlotResponse<()>) -> PlotResponse<()> {
+    let ctx = Context::default();
+    let input = RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(400.0, 300.0))),
+        ..Default::default()
+    };
+    let mut response = None;
+    let _ = ctx.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| response = Some(show(ui)));
+    });
+    response.expect("the panel is shown")
+}
+
+/// The contents of all groups clipped by the clip path with the given id.
+fn clipped_by(svg: &str, clip: usize) -> String {
+    let start = format!(r#"<g clip-path="url(#clip{clip})">"#);
+    svg.match_indices(&start)
+        .map(|(index, _)| {
+            let group = &svg[index + start.len()..];
+            &group[..group.find("</g>").expect("groups are closed")]
+        })
+        .collect()
+}
+
+#[test]
+fn export_svg_writes_the_items_clipped_to_the_frame() {
+    // Reaches beyond the right edge of the plot.
+    let corners = [[50.0, 2.0], [150.0, 2.0], [150.0, 8.0], [50.0, 8.0]];
+    let response = show_plot(|ui| {
+        Plot::new("svg").export_svg(true).show(ui, |plot_ui| {
+            plot_ui.set_plot_bounds(PlotBounds::from_min_max([0.0, 0.0], [100.0, 10.0]));
+            plot_ui.line(
+                Line::new(PlotPoints::from_iter(
+                    (0..=100).map(|i| [i as f64, i as f64 / 10.0]),
+                ))
+                .color(Color32::RED),
+            );
+            plot_ui.polygon(Polygon::new(PlotPoints::new(corners.to_vec())));
+            plot_ui.text(Text::new(PlotPoint::new(20.0, 5.0), r#"a < b & "c""#));
+        })
+    });
+    let svg = response.svg.expect("the export was requested");
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.ends_with("</svg>\n"));
+
+    // The items are clipped to the frame of the plot.
+    let frame = response.transform.frame();
+    let clip_rect = format!(
+        r#"<rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+        frame.left(),
+        frame.top(),
+        frame.width(),
+        frame.height()
+    );
+    let clip = svg
+        .lines()
+        .filter(|line| line.starts_with("<clipPath"))
+        .position(|line| line.ends_with(&clip_rect))
+        .expect("a clip path for the frame");
+    let items = clipped_by(&svg, clip);
+    let points = |points: &[[f64; 2]]| {
+        let positions: Vec<String> = points
+            .iter()
+            .map(|&point| {
+                let pos = response.transform.position_from_point(&point.into());
+                format!("{},{}", pos.x, pos.y)
+            })
+            .collect();
+        positions.join(" ")
+    };
+    let line_start = format!(r#"<polyline points="{} "#, points(&[[0.0, 0.0]]));
+    assert!(items.contains(&line_start), "{items}");
+    assert!(items.contains(r##"stroke="#ff0000""##));
+    let polygon = format!(r#"<polygon points="{}""#, points(&corners));
+    assert!(items.contains(&polygon), "{items}");
+
+    // Text is escaped.
+    assert!(
+        items.contains(">a &lt; b &amp; &quot;c&quot;</text>"),
+        "{items}"
+    );
+    assert!(!svg.contains("a < b"));
+}
+
+#[test]
+fn export_svg_is_opt_in() {
+    let response = show_plot(|ui| Plot::new("svg").show(ui, |_| {}));
+    assert!(response.svg.is_none());
+}